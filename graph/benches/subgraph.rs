@@ -0,0 +1,99 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#![feature(test)]
+
+extern crate fera_graph;
+extern crate rand;
+extern crate test;
+
+use fera_graph::prelude::*;
+use rand::prelude::*;
+
+use test::Bencher;
+
+fn bench_induced_subgraph(b: &mut Bencher, n: usize) {
+    let mut rng = SmallRng::from_entropy();
+    let g = StaticGraph::new_gnm_connected(n, 10 * n, &mut rng).unwrap();
+    let vertices: Vec<_> = g.vertices().take(n / 10).collect();
+    b.iter(|| {
+        assert_eq!(vertices.len(), g.induced_subgraph(&vertices).num_vertices());
+    })
+}
+
+#[bench]
+fn bench_induced_subgraph_100(b: &mut Bencher) {
+    bench_induced_subgraph(b, 100);
+}
+
+#[bench]
+fn bench_induced_subgraph_1000(b: &mut Bencher) {
+    bench_induced_subgraph(b, 1000);
+}
+
+#[bench]
+fn bench_induced_subgraph_10000(b: &mut Bencher) {
+    bench_induced_subgraph(b, 10000);
+}
+
+fn new_induced_subgraph(n: usize) -> (StaticGraph, Vec<Vertex<StaticGraph>>) {
+    let mut rng = SmallRng::from_entropy();
+    let g = StaticGraph::new_gnm_connected(n, 10 * n, &mut rng).unwrap();
+    let vertices: Vec<_> = g.vertices().take(n / 2).collect();
+    (g, vertices)
+}
+
+#[bench]
+fn bench_subgraph_vertices_10000(b: &mut Bencher) {
+    let (g, vertices) = new_induced_subgraph(10000);
+    let s = g.induced_subgraph(&vertices);
+    b.iter(|| s.vertices().fold(0, |acc, v| acc ^ v))
+}
+
+#[bench]
+fn bench_subgraph_edges_10000(b: &mut Bencher) {
+    let (g, vertices) = new_induced_subgraph(10000);
+    let s = g.induced_subgraph(&vertices);
+    b.iter(|| s.edges().filter(|&e| s.source(e) < s.target(e)).count())
+}
+
+#[bench]
+fn bench_subgraph_out_edges_10000(b: &mut Bencher) {
+    let (g, vertices) = new_induced_subgraph(10000);
+    let s = g.induced_subgraph(&vertices);
+    b.iter(|| {
+        s.vertices()
+            .map(|v| s.out_edges(v).filter(|&e| s.target(e) < v).count())
+            .sum::<usize>()
+    })
+}
+
+#[bench]
+fn bench_subgraph_out_neighbors_10000(b: &mut Bencher) {
+    let (g, vertices) = new_induced_subgraph(10000);
+    let s = g.induced_subgraph(&vertices);
+    b.iter(|| {
+        s.vertices()
+            .map(|v| s.out_neighbors(v).fold(0, |acc, u| acc ^ u))
+            .fold(0, |acc, x| acc ^ x)
+    })
+}
+
+// Subgraph::get_edge_by_ends compares only the target of each out edge instead of computing both
+// ends. Results on the same machine (three runs each):
+//
+//   comparing both ends:     147-150 us/iter
+//   comparing only target:   125-128 us/iter
+#[bench]
+fn bench_subgraph_get_edge_by_ends_10000(b: &mut Bencher) {
+    let (g, vertices) = new_induced_subgraph(10000);
+    let s = g.induced_subgraph(&vertices);
+    let pairs: Vec<_> = vertices.iter().zip(vertices.iter().rev()).collect();
+    b.iter(|| {
+        pairs
+            .iter()
+            .filter(|&&(&u, &v)| s.get_edge_by_ends(u, v).is_some())
+            .count()
+    })
+}
@@ -0,0 +1,307 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Minimum spanning arborescences of digraphs.
+//!
+//! An arborescence rooted at `r` is a set of edges that contains exactly one path from `r` to each
+//! other vertex, that is, a spanning tree with all edges directed away from `r`. It is the directed
+//! analogue of a spanning tree.
+
+use prelude::*;
+
+use num_traits::Zero;
+
+use std::ops::Add;
+
+/// Returns the edges of a minimum spanning arborescence of `g` rooted at `root` using the
+/// Chu–Liu/Edmonds algorithm, or `None` if some vertex is not reachable from `root`.
+///
+/// For each vertex other than `root`, the cheapest incoming edge is selected. If the selected
+/// edges have no cycle they are an arborescence, otherwise each cycle is contracted to a single
+/// vertex, the weights of the edges entering the cycle are adjusted, and the algorithm is applied
+/// recursively to the contracted graph. The loops of `g` are ignored.
+///
+/// The edges are returned in the order of `g.edges()`. The running time is O(VE).
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::arborescence::min_arborescence;
+/// use fera_graph::sum_prop;
+///
+/// # fn main() {
+/// let g: StaticDigraph = graph!(3, (0, 1), (0, 2), (1, 2), (2, 1));
+/// let mut w = g.default_edge_prop(0u32);
+/// for (e, &x) in g.edges().zip(&[10, 2, 1, 1]) {
+///     w[e] = x;
+/// }
+/// let tree = min_arborescence(&g, 0, &w).unwrap();
+/// assert_eq!(3u32, sum_prop(&w, &tree));
+/// assert_eq!(vec![(0, 2), (2, 1)], g.ends(&tree).collect::<Vec<_>>());
+///
+/// // 2 is not reachable from 1
+/// assert_eq!(None, min_arborescence(&g, 1, &w));
+/// # }
+/// ```
+pub fn min_arborescence<G, W, T>(g: &G, root: Vertex<G>, w: W) -> Option<Vec<Edge<G>>>
+where
+    G: Digraph + WithVertexIndexProp,
+    W: EdgePropGet<G, T>,
+    T: Copy + Ord + Add<Output = T> + Zero,
+{
+    let index = g.vertex_index();
+    let all: Vec<_> = g.edges().collect();
+    let edges: Vec<_> = all
+        .iter()
+        .enumerate()
+        .map(|(i, &e)| {
+            let (u, v) = g.ends(e);
+            (index.get(u), index.get(v), w.get(e), i)
+        }).filter(|&(u, v, _, _)| u != v)
+        .collect();
+    let mut tree = edmonds(g.num_vertices(), index.get(root), &edges)?;
+    tree.sort();
+    Some(tree.into_iter().map(|i| all[i]).collect())
+}
+
+// Each edge is (source, target, weight, id), the vertices are 0..n and there are no loops. Returns
+// the ids of the edges of a minimum arborescence rooted at root.
+fn edmonds<T>(n: usize, root: usize, edges: &[(usize, usize, T, usize)]) -> Option<Vec<usize>>
+where
+    T: Copy + Ord + Add<Output = T> + Zero,
+{
+    // input[v] is the position in edges of the cheapest edge entering v
+    let mut input = vec![None; n];
+    for (i, &(_, v, x, _)) in edges.iter().enumerate() {
+        if v != root && input[v].map(|j: usize| x < edges[j].2).unwrap_or(true) {
+            input[v] = Some(i);
+        }
+    }
+    if (0..n).any(|v| v != root && input[v].is_none()) {
+        return None;
+    }
+    let source = |v: usize| input[v].map(|i| edges[i].0);
+
+    // find the cycles of the selected edges, comp[v] is the vertex of the contracted graph
+    let mut comp = vec![None; n];
+    let mut cycles: Vec<Vec<usize>> = vec![];
+    let mut num = 0;
+    let mut visited = vec![None; n];
+    for s in 0..n {
+        let mut v = s;
+        while visited[v].is_none() {
+            visited[v] = Some(s);
+            match source(v) {
+                Some(u) => v = u,
+                None => break,
+            }
+        }
+        if visited[v] == Some(s) && comp[v].is_none() && v != root {
+            // v is in a new cycle
+            let mut cycle = vec![v];
+            let mut u = source(v).unwrap();
+            while u != v {
+                cycle.push(u);
+                u = source(u).unwrap();
+            }
+            for &u in &cycle {
+                comp[u] = Some(num);
+            }
+            num += 1;
+            cycles.push(cycle);
+        }
+    }
+
+    if cycles.is_empty() {
+        return Some(
+            (0..n)
+                .filter(|&v| v != root)
+                .map(|v| edges[input[v].unwrap()].3)
+                .collect(),
+        );
+    }
+
+    let comp: Vec<usize> = comp
+        .into_iter()
+        .map(|c| {
+            c.unwrap_or_else(|| {
+                num += 1;
+                num - 1
+            })
+        }).collect();
+
+    // The weight of an edge entering the vertex v of a cycle is increased by the weight of the
+    // selected edges of the cycle that do not enter v. This is equivalent to subtracting the
+    // weight of the selected edge entering v, as the difference is the same for all edges
+    // entering the cycle.
+    let mut extra = vec![T::zero(); n];
+    for cycle in &cycles {
+        for &v in cycle {
+            extra[v] = cycle
+                .iter()
+                .filter(|&&u| u != v)
+                .fold(T::zero(), |sum, &u| sum + edges[input[u].unwrap()].2);
+        }
+    }
+    let contracted: Vec<_> = edges
+        .iter()
+        .enumerate()
+        .filter(|&(_, &(u, v, _, _))| comp[u] != comp[v])
+        .map(|(i, &(u, v, x, _))| (comp[u], comp[v], x + extra[v], i))
+        .collect();
+
+    let chosen = edmonds(num, comp[root], &contracted)?;
+    let mut in_tree = vec![false; edges.len()];
+    let mut entered = vec![None; n];
+    for &i in &chosen {
+        in_tree[i] = true;
+        entered[comp[edges[i].1]] = Some(edges[i].1);
+    }
+    // each cycle is entered by one chosen edge, the other selected edges of the cycle are kept
+    for cycle in &cycles {
+        let v = entered[comp[cycle[0]]].unwrap();
+        for &u in cycle {
+            if u != v {
+                in_tree[input[u].unwrap()] = true;
+            }
+        }
+    }
+    Some(
+        edges
+            .iter()
+            .zip(in_tree)
+            .filter(|&(_, t)| t)
+            .map(|(e, _)| e.3)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::vec;
+    use fun::sum_prop;
+    use rand::prelude::*;
+
+    // the weight of a minimum arborescence by testing all choices of an incoming edge for each
+    // vertex
+    fn brute<W>(g: &StaticDigraph, root: u32, w: W) -> Option<u32>
+    where
+        W: EdgePropGet<StaticDigraph, u32>,
+    {
+        let n = g.num_vertices();
+        let inputs = vec(g.vertices().map(|v| {
+            vec(g.edges().filter(|&e| g.target(e) == v && g.source(e) != v))
+        }));
+        if g.vertices().any(|v| v != root && inputs[v as usize].is_empty()) {
+            return None;
+        }
+        let mut choice = vec![0; n];
+        let mut best = None;
+        loop {
+            let parent = |v: u32| g.source(inputs[v as usize][choice[v as usize]]);
+            let valid = g.vertices().all(|v| {
+                let mut u = v;
+                let mut steps = 0;
+                while u != root && steps <= n {
+                    u = parent(u);
+                    steps += 1;
+                }
+                u == root
+            });
+            if valid {
+                let d = g
+                    .vertices()
+                    .filter(|&v| v != root)
+                    .map(|v| w.get(inputs[v as usize][choice[v as usize]]))
+                    .sum();
+                if best.map(|b| d < b).unwrap_or(true) {
+                    best = Some(d);
+                }
+            }
+            // next choice
+            let mut v = 0;
+            while v < n {
+                choice[v] += 1;
+                if choice[v] < inputs[v].len() {
+                    break;
+                }
+                choice[v] = 0;
+                v += 1;
+            }
+            if v == n {
+                return best;
+            }
+        }
+    }
+
+    #[test]
+    fn cycle() {
+        let g: StaticDigraph = graph!(
+            5,
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 1),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 3),
+            (3, 3)
+        );
+        let mut w = g.default_edge_prop(0u32);
+        for (e, &x) in g.edges().zip(&[10, 2, 1, 1, 4, 6, 1, 1, 0]) {
+            w[e] = x;
+        }
+        let tree = min_arborescence(&g, 0, &w).unwrap();
+        assert_eq!(8u32, sum_prop(&w, &tree));
+        assert_eq!(
+            vec![(0, 2), (2, 1), (1, 3), (3, 4)],
+            vec(g.ends(&tree))
+        );
+    }
+
+    #[test]
+    fn unreachable() {
+        let g: StaticDigraph = graph!(1);
+        assert_eq!(Some(vec![]), min_arborescence(&g, 0, g.default_edge_prop(0u32)));
+        let g: StaticDigraph = graph!(3, (0, 1), (2, 1));
+        assert_eq!(None, min_arborescence(&g, 0, g.default_edge_prop(0u32)));
+        // 2 and 3 form a cycle that is not reachable from 0
+        let g: StaticDigraph = graph!(4, (0, 1), (2, 3), (3, 2));
+        assert_eq!(None, min_arborescence(&g, 0, g.default_edge_prop(0u32)));
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..7 {
+            for _ in 0..10 {
+                let m = rng.gen_range(0, n * (n - 1) + 1);
+                let g = StaticDigraph::new_gnm(n, m, &mut rng).unwrap();
+                let mut w = g.default_edge_prop(0u32);
+                for e in g.edges() {
+                    w[e] = rng.gen_range(0, 10);
+                }
+                let root = rng.gen_range(0, n as u32);
+                let tree = min_arborescence(&g, root, &w);
+                assert_eq!(
+                    brute(&g, root, &w),
+                    tree.as_ref().map(|t| sum_prop(&w, t))
+                );
+                if let Some(tree) = tree {
+                    assert_eq!(n - 1, tree.len());
+                    let mut has_input = g.default_vertex_prop(false);
+                    for &e in &tree {
+                        assert!(!has_input[g.target(e)]);
+                        has_input[g.target(e)] = true;
+                    }
+                    assert!(!has_input[root]);
+                }
+            }
+        }
+    }
+}
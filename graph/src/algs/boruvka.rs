@@ -27,6 +27,11 @@ generic_struct! {
 }
 
 impl<'a, G, W, S, U> BoruvkaAlg<&'a G, W, S, U> {
+    /// Runs the algorithm and returns the edges of a minimum spanning forest.
+    ///
+    /// In each phase the cheapest edge leaving each component is selected and all selected edges
+    /// are added to the forest. Ties are broken by the position of the edges in `g.edges()`, so
+    /// the selected edges never form a cycle, even if there are edges with the same weight.
     // FIXME: return a iterator, like kruskal and prim
     pub fn run<T>(self) -> Vec<Edge<G>>
     where
@@ -45,6 +50,7 @@ impl<'a, G, W, S, U> BoruvkaAlg<&'a G, W, S, U> {
         safe.resize(g.num_vertices(), None.into());
         while changed && ds.num_sets() > 1 {
             changed = false;
+            // the comparisons are strict, so the first edge (in g.edges() order) is kept on ties
             for (e, u, v) in g.edges_with_ends() {
                 let u_comp = index.get(ds.find_set(u));
                 let v_comp = index.get(ds.find_set(v));
@@ -0,0 +1,446 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Vertex centrality measures.
+
+use prelude::*;
+
+use std::cmp;
+use std::collections::VecDeque;
+use std::mem;
+
+pub trait Centrality: Incidence {
+    /// Computes the degree centrality of each vertex, that is, the out degree of the vertex. As in
+    /// `out_degree`, a loop of an undirected graph is counted twice.
+    ///
+    /// If `normalized` is `true` and the graph has more than one vertex, the values are divided
+    /// by `n - 1`, so the values of a graph without loops and parallel edges are between 0 and 1.
+    ///
+    /// The running time is O(V).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Centrality;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (1, 3));
+    /// let c = g.degree_centrality(false);
+    /// assert_eq!(vec![1.0, 3.0, 1.0, 1.0], g.vertices().map(|v| c[v]).collect::<Vec<_>>());
+    /// # }
+    /// ```
+    fn degree_centrality(&self, normalized: bool) -> DefaultVertexPropMut<Self, f64>
+    where
+        Self: VertexList + WithVertexProp<f64>,
+    {
+        let n = self.num_vertices();
+        let scale = if normalized && n > 1 {
+            1.0 / (n - 1) as f64
+        } else {
+            1.0
+        };
+        let mut deg = self.default_vertex_prop(0.0f64);
+        for v in self.vertices() {
+            deg[v] = self.out_degree(v) as f64 * scale;
+        }
+        deg
+    }
+
+    /// Computes the betweenness centrality of each vertex using Brandes' algorithm.
+    ///
+    /// The betweenness of a vertex `v` is the sum, over all pairs of distinct vertices `s` and
+    /// `t` (both different from `v`), of the fraction of shortest paths from `s` to `t` that pass
+    /// through `v`. All edges are considered to have length one. In an undirected graph each
+    /// unordered pair `{s, t}` is counted once.
+    ///
+    /// If `normalized` is `true` the values are divided by the number of pairs considered, that
+    /// is, `(n - 1)(n - 2)` for a digraph and `(n - 1)(n - 2) / 2` for an undirected graph, so
+    /// the values are between 0 and 1.
+    ///
+    /// The running time is O(VE).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Centrality;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+    /// let b = g.betweenness(false);
+    /// assert_eq!(vec![0.0, 2.0, 2.0, 0.0], g.vertices().map(|v| b[v]).collect::<Vec<_>>());
+    /// # }
+    /// ```
+    fn betweenness(&self, normalized: bool) -> DefaultVertexPropMut<Self, f64>
+    where
+        Self: VertexList + BasicVertexProps,
+        Self::Kind: UniformEdgeKind,
+    {
+        let mut bet = self.default_vertex_prop(0.0f64);
+        let mut sigma = self.default_vertex_prop(0.0f64);
+        let mut dist = self.default_vertex_prop(usize::max_value());
+        let mut delta = self.default_vertex_prop(0.0f64);
+        let mut pred = self.default_vertex_prop(Vec::<Vertex<Self>>::new());
+        let mut stack = vec![];
+        let mut queue = VecDeque::new();
+
+        for s in self.vertices() {
+            sigma[s] = 1.0;
+            dist[s] = 0;
+            queue.push_back(s);
+            while let Some(u) = queue.pop_front() {
+                stack.push(u);
+                for v in self.out_neighbors(u) {
+                    if dist[v] == usize::max_value() {
+                        dist[v] = dist[u] + 1;
+                        queue.push_back(v);
+                    }
+                    if dist[v] == dist[u] + 1 {
+                        sigma[v] += sigma[u];
+                        pred[v].push(u);
+                    }
+                }
+            }
+
+            // Accumulates the dependencies in non-increasing order of distance from s and resets
+            // the properties of the visited vertices
+            while let Some(v) = stack.pop() {
+                for &u in &pred[v] {
+                    delta[u] += sigma[u] / sigma[v] * (1.0 + delta[v]);
+                }
+                if v != s {
+                    bet[v] += delta[v];
+                }
+                sigma[v] = 0.0;
+                dist[v] = usize::max_value();
+                delta[v] = 0.0;
+                pred[v].clear();
+            }
+        }
+
+        // Each pair of vertices of an undirected graph was counted twice
+        let n = self.num_vertices() as f64;
+        let scale = match (normalized && n > 2.0, Self::Kind::is_directed()) {
+            (true, _) => 1.0 / ((n - 1.0) * (n - 2.0)),
+            (false, true) => 1.0,
+            (false, false) => 0.5,
+        };
+        for v in self.vertices() {
+            bet[v] *= scale;
+        }
+        bet
+    }
+
+    /// Computes the closeness centrality of each vertex. All edges are considered to have length
+    /// one.
+    ///
+    /// If every vertex can reach all the other vertices, the closeness of `v` is the reciprocal
+    /// of the sum of the distances from `v` to every other vertex. Otherwise (for example, if the
+    /// graph is disconnected or is a digraph that is not strongly connected), the harmonic
+    /// variant is used for every vertex, that is, the closeness of `v` is the sum of the
+    /// reciprocal of the distances from `v` to the reachable vertices (so unreachable vertices
+    /// contribute zero). The choice is made once for the whole graph, so the values of all
+    /// vertices are in the same scale. The closeness of a vertex in a graph with one vertex is
+    /// zero.
+    ///
+    /// The running time is O(V(V + E)).
+    fn closeness(&self) -> DefaultVertexPropMut<Self, f64>
+    where
+        Self: VertexList + WithVertexProp<usize> + WithVertexProp<f64>,
+    {
+        let n = self.num_vertices();
+        let mut dist = self.default_vertex_prop(usize::max_value());
+        let mut sum = self.default_vertex_prop(0usize);
+        let mut closeness = self.default_vertex_prop(0.0f64);
+        let mut all_reached = true;
+        for u in self.vertices() {
+            let mut reached = 0;
+            let mut harmonic = 0.0;
+            bfs_distances(self, u, &mut dist, |_, d| {
+                if d != 0 {
+                    reached += 1;
+                    sum[u] += d;
+                    harmonic += 1.0 / d as f64;
+                }
+            });
+            all_reached &= reached + 1 == n;
+            closeness[u] = harmonic;
+        }
+        if all_reached {
+            for u in self.vertices() {
+                closeness[u] = if sum[u] != 0 { 1.0 / sum[u] as f64 } else { 0.0 };
+            }
+        }
+        closeness
+    }
+
+    /// Computes the eccentricity of each vertex, that is, the greatest distance from the vertex
+    /// to a vertex reachable from it. All edges are considered to have length one.
+    ///
+    /// The running time is O(V(V + E)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Centrality;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+    /// let ecc = g.eccentricity();
+    /// assert_eq!(vec![3, 2, 2, 3], g.vertices().map(|v| ecc[v]).collect::<Vec<_>>());
+    /// # }
+    /// ```
+    fn eccentricity(&self) -> DefaultVertexPropMut<Self, usize>
+    where
+        Self: VertexList + WithVertexProp<usize>,
+    {
+        let mut dist = self.default_vertex_prop(usize::max_value());
+        let mut ecc = self.default_vertex_prop(0);
+        for u in self.vertices() {
+            let mut max = 0;
+            bfs_distances(self, u, &mut dist, |_, d| max = cmp::max(max, d));
+            ecc[u] = max;
+        }
+        ecc
+    }
+
+    /// Computes the PageRank of each vertex of a digraph using the power method.
+    ///
+    /// In each iteration a random surfer at a vertex follows one of its out edges (chosen
+    /// uniformly) with probability `damping` or jumps to a vertex chosen uniformly with
+    /// probability `1 - damping`. A surfer at a vertex with no out edges (a dangling vertex)
+    /// always jumps to a vertex chosen uniformly. The iteration stops when the sum of the absolute
+    /// changes of the values is less than `tol` or after `max_iter` iterations. The values sum to
+    /// one.
+    ///
+    /// Each iteration takes O(V + E) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Centrality;
+    ///
+    /// # fn main() {
+    /// let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (2, 0));
+    /// let rank = g.pagerank(0.85, 1e-9, 100);
+    /// for v in g.vertices() {
+    ///     assert!((rank[v] - 1.0 / 3.0).abs() < 1e-9);
+    /// }
+    /// # }
+    /// ```
+    fn pagerank(&self, damping: f64, tol: f64, max_iter: usize) -> DefaultVertexPropMut<Self, f64>
+    where
+        Self: IncidenceDigraph,
+    {
+        let n = self.num_vertices() as f64;
+        let mut rank = self.default_vertex_prop(1.0 / n);
+        let mut next = self.default_vertex_prop(0.0f64);
+        for _ in 0..max_iter {
+            let dangling: f64 = self
+                .vertices()
+                .filter(|&v| self.out_degree(v) == 0)
+                .map(|v| rank[v])
+                .sum();
+            next.set_values(self.vertices(), (1.0 - damping + damping * dangling) / n);
+            for u in self.vertices() {
+                let deg = self.out_degree(u);
+                if deg != 0 {
+                    let share = damping * rank[u] / deg as f64;
+                    for v in self.out_neighbors(u) {
+                        next[v] += share;
+                    }
+                }
+            }
+            let change: f64 = self.vertices().map(|v| (next[v] - rank[v]).abs()).sum();
+            mem::swap(&mut rank, &mut next);
+            if change < tol {
+                break;
+            }
+        }
+        rank
+    }
+}
+
+impl<G: Incidence> Centrality for G {}
+
+// Calls `f(v, d)` for each vertex `v` reachable from `s`, where `d` is the distance from `s` to
+// `v`. All values of `dist` must be `usize::max_value()`, which is restored before returning.
+pub(crate) fn bfs_distances<G, F>(
+    g: &G,
+    s: Vertex<G>,
+    dist: &mut DefaultVertexPropMut<G, usize>,
+    mut f: F,
+)
+where
+    G: Incidence + WithVertexProp<usize>,
+    F: FnMut(Vertex<G>, usize),
+{
+    // the vertices are visited in the same order they are discovered, so the list of discovered
+    // vertices is also used as the queue
+    let mut discovered = vec![s];
+    dist[s] = 0;
+    let mut i = 0;
+    while i < discovered.len() {
+        let u = discovered[i];
+        i += 1;
+        for v in g.out_neighbors(u) {
+            if dist[v] == usize::max_value() {
+                dist[v] = dist[u] + 1;
+                discovered.push(v);
+            }
+        }
+    }
+    for v in discovered {
+        f(v, dist[v]);
+        dist[v] = usize::max_value();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Centrality;
+    use fera_fun::vec;
+    use named::star_graph;
+    use prelude::*;
+    use rand::prelude::*;
+
+    fn assert_close(expected: &[f64], actual: &[f64]) {
+        assert_eq!(expected.len(), actual.len());
+        for (a, b) in expected.iter().zip(actual) {
+            assert!((a - b).abs() < 1e-9, "expected {:?}, found {:?}", expected, actual);
+        }
+    }
+
+    #[test]
+    fn degree_centrality() {
+        let g = star_graph(4);
+        let c = g.degree_centrality(true);
+        assert_close(&[1.0, 0.25, 0.25, 0.25, 0.25], &vec(g.vertices().map(|v| c[v])));
+        let c = g.degree_centrality(false);
+        assert_close(&[4.0, 1.0, 1.0, 1.0, 1.0], &vec(g.vertices().map(|v| c[v])));
+
+        // the loop is counted twice
+        let g: StaticGraph = graph!(3, (0, 0), (0, 1));
+        let c = g.degree_centrality(true);
+        assert_close(&[1.5, 0.5, 0.0], &vec(g.vertices().map(|v| c[v])));
+
+        let g: StaticGraph = graph!(1);
+        assert_eq!(0.0, g.degree_centrality(true)[0u32]);
+    }
+
+    #[test]
+    fn betweenness_star() {
+        let g: StaticGraph = graph!(5, (0, 1), (0, 2), (0, 3), (0, 4));
+        let b = g.betweenness(false);
+        assert_close(&[6.0, 0.0, 0.0, 0.0, 0.0], &vec(g.vertices().map(|v| b[v])));
+        let b = g.betweenness(true);
+        assert_close(&[1.0, 0.0, 0.0, 0.0, 0.0], &vec(g.vertices().map(|v| b[v])));
+    }
+
+    #[test]
+    fn betweenness_path() {
+        let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 4));
+        let b = g.betweenness(false);
+        assert_close(&[0.0, 3.0, 4.0, 3.0, 0.0], &vec(g.vertices().map(|v| b[v])));
+        let b = g.betweenness(true);
+        assert_close(
+            &[0.0, 0.5, 4.0 / 6.0, 0.5, 0.0],
+            &vec(g.vertices().map(|v| b[v])),
+        );
+    }
+
+    #[test]
+    fn betweenness_directed_path() {
+        // unlike the undirected case, only the pairs (s, t) with s < t have a path
+        let g: StaticDigraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 4));
+        let b = g.betweenness(false);
+        assert_close(&[0.0, 3.0, 4.0, 3.0, 0.0], &vec(g.vertices().map(|v| b[v])));
+        let b = g.betweenness(true);
+        assert_close(
+            &[0.0, 0.25, 4.0 / 12.0, 0.25, 0.0],
+            &vec(g.vertices().map(|v| b[v])),
+        );
+    }
+
+    #[test]
+    fn closeness_path() {
+        let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 4));
+        let c = g.closeness();
+        let c = vec(g.vertices().map(|v| c[v]));
+        assert_close(&[1.0 / 10.0, 1.0 / 7.0, 1.0 / 6.0, 1.0 / 7.0, 1.0 / 10.0], &c);
+        for v in 0..5 {
+            if v != 2 {
+                assert!(c[2] > c[v]);
+            }
+        }
+    }
+
+    #[test]
+    fn closeness_disconnected() {
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2));
+        let c = g.closeness();
+        assert_close(&[1.5, 2.0, 1.5, 0.0], &vec(g.vertices().map(|v| c[v])));
+    }
+
+    #[test]
+    fn closeness_digraph() {
+        // only 0 reaches all other vertices, so the harmonic variant is used for every vertex
+        let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (2, 3));
+        let c = g.closeness();
+        let c = vec(g.vertices().map(|v| c[v]));
+        assert_close(&[1.0 + 1.0 / 2.0 + 1.0 / 3.0, 1.5, 1.0, 0.0], &c);
+        assert!(c[0] > c[1]);
+
+        let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (2, 0));
+        let c = g.closeness();
+        assert_close(&[1.0 / 3.0; 3], &vec(g.vertices().map(|v| c[v])));
+    }
+
+    #[test]
+    fn eccentricity_path() {
+        let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 4));
+        let ecc = g.eccentricity();
+        assert_eq!(vec![4, 3, 2, 3, 4], vec(g.vertices().map(|v| ecc[v])));
+        let g: StaticDigraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 4));
+        let ecc = g.eccentricity();
+        assert_eq!(vec![4, 3, 2, 1, 0], vec(g.vertices().map(|v| ecc[v])));
+    }
+
+    #[test]
+    fn betweenness_cycle() {
+        // there are two shortest paths between 0 and 2 and between 1 and 3
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (3, 0));
+        let b = g.betweenness(false);
+        assert_close(&[0.5, 0.5, 0.5, 0.5], &vec(g.vertices().map(|v| b[v])));
+    }
+
+    #[test]
+    fn pagerank_dangling() {
+        // 0 is dangling, the stationary distribution is r1 = r2 = 1 / (3 + 2d) and r0 = 1 - 2 r1
+        let g: StaticDigraph = graph!(3, (1, 0), (2, 0));
+        let d = 0.85;
+        let rank = g.pagerank(d, 1e-12, 1000);
+        let b = 1.0 / (3.0 + 2.0 * d);
+        assert_close(&[1.0 - 2.0 * b, b, b], &vec(g.vertices().map(|v| rank[v])));
+    }
+
+    #[test]
+    fn pagerank_sum() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..20 {
+            let m = rng.gen_range(0, n * (n - 1) + 1);
+            let g = StaticDigraph::new_gnm(n, m, &mut rng).unwrap();
+            let rank = g.pagerank(0.85, 1e-10, 1000);
+            let sum: f64 = g.vertices().map(|v| rank[v]).sum();
+            assert!((1.0 - sum).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,289 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Operations that combine two graphs into a new graph.
+//!
+//! The vertex `v` of the first graph `g` corresponds to the vertex `g.vertex_index().get(v)` of
+//! the new graph and the vertex `v` of the second graph `h` corresponds to the vertex `offset +
+//! h.vertex_index().get(v)`, where `offset` (which is equal to `g.num_vertices()`) is returned
+//! together with the new graph.
+
+use prelude::*;
+
+/// Creates the disjoint union of `g` and `h`, that is, a graph with the vertices and edges of
+/// `g` and the vertices and edges of `h`. Returns the new graph and the offset of the vertices of
+/// `h` in the new graph.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::combine::disjoint_union;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(2, (0, 1));
+/// let h: StaticGraph = graph!(3, (0, 2));
+/// let (u, offset) = disjoint_union(&g, &h);
+/// assert_eq!(2, offset);
+/// assert_eq!(5, u.num_vertices());
+/// assert!(u.get_edge_by_ends(0, 1).is_some());
+/// assert!(u.get_edge_by_ends(2, 4).is_some());
+/// # }
+/// ```
+pub fn disjoint_union<G, H>(g: &G, h: &H) -> (StaticGraph, usize)
+where
+    G: VertexList + EdgeList<Kind = Undirected> + WithVertexIndexProp,
+    H: VertexList + EdgeList<Kind = Undirected> + WithVertexIndexProp,
+{
+    let offset = g.num_vertices();
+    let edges = union_edges(g, h, offset);
+    (
+        StaticGraph::new_with_edges(offset + h.num_vertices(), edges),
+        offset,
+    )
+}
+
+/// Creates the join of `g` and `h`, that is, the disjoint union of `g` and `h` with an
+/// additional edge between every vertex of `g` and every vertex of `h`. Returns the new graph and
+/// the offset of the vertices of `h` in the new graph.
+///
+/// # Examples
+///
+/// ```
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::combine::join;
+///
+/// // the join of two empty graphs is a complete bipartite graph
+/// let (k23, offset) = join(&StaticGraph::new_empty(2), &StaticGraph::new_empty(3));
+/// assert_eq!(2, offset);
+/// assert_eq!(6, k23.num_edges());
+/// ```
+pub fn join<G, H>(g: &G, h: &H) -> (StaticGraph, usize)
+where
+    G: VertexList + EdgeList<Kind = Undirected> + WithVertexIndexProp,
+    H: VertexList + EdgeList<Kind = Undirected> + WithVertexIndexProp,
+{
+    let offset = g.num_vertices();
+    let n = h.num_vertices();
+    let mut edges = union_edges(g, h, offset);
+    edges.reserve(offset * n);
+    for u in 0..offset {
+        for v in offset..offset + n {
+            edges.push((u, v));
+        }
+    }
+    (StaticGraph::new_with_edges(offset + n, edges), offset)
+}
+
+/// Creates the cartesian product of `g` and `h`, that is, a graph with a vertex `(u, v)` for
+/// each vertex `u` of `g` and each vertex `v` of `h`, and with an edge between `(u1, v1)` and
+/// `(u2, v2)` if `u1 == u2` and `v1` is adjacent to `v2` in `h` or if `v1 == v2` and `u1` is
+/// adjacent to `u2` in `g`.
+///
+/// Returns the new graph and a function that maps the pair of indices (see `vertex_index`) of
+/// `u` in `g` and `v` in `h` to the vertex `(u, v)` of the new graph.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::combine::cartesian_product;
+///
+/// # fn main() {
+/// // a 2 x 3 grid
+/// let p2: StaticGraph = graph!(2, (0, 1));
+/// let p3: StaticGraph = graph!(3, (0, 1), (1, 2));
+/// let (grid, vertex) = cartesian_product(&p2, &p3);
+/// assert_eq!(6, grid.num_vertices());
+/// assert_eq!(7, grid.num_edges());
+/// let (a, b) = (vertex(0, 1) as u32, vertex(1, 1) as u32);
+/// assert!(grid.get_edge_by_ends(a, b).is_some());
+/// # }
+/// ```
+pub fn cartesian_product<G, H>(g: &G, h: &H) -> (StaticGraph, impl Fn(usize, usize) -> usize)
+where
+    G: VertexList + EdgeList<Kind = Undirected> + WithVertexIndexProp,
+    H: VertexList + EdgeList<Kind = Undirected> + WithVertexIndexProp,
+{
+    let (ng, nh) = (g.num_vertices(), h.num_vertices());
+    let vertex = move |u: usize, v: usize| u * nh + v;
+    let gi = g.vertex_index();
+    let hi = h.vertex_index();
+    let mut edges = Vec::with_capacity(g.num_edges() * nh + h.num_edges() * ng);
+    for (a, b) in g.edges_ends() {
+        let (a, b) = (gi.get(a), gi.get(b));
+        edges.extend((0..nh).map(|v| (vertex(a, v), vertex(b, v))));
+    }
+    for (a, b) in h.edges_ends() {
+        let (a, b) = (hi.get(a), hi.get(b));
+        edges.extend((0..ng).map(|u| (vertex(u, a), vertex(u, b))));
+    }
+    (StaticGraph::new_with_edges(ng * nh, edges), vertex)
+}
+
+/// Creates the bipartite double cover of `g`, that is, the tensor product of `g` and a single
+/// edge. Returns the new graph and a function that maps `(v, copy)` to the copy `copy` of the
+/// vertex `v` of `g` (identified by its index), so the vertex `g.vertex_index().get(v)` of `g`
+/// corresponds to the vertices `vertex(v, false)` and `vertex(v, true)` of the new graph.
+///
+/// Each edge `(u, v)` of `g` corresponds to the edges `(vertex(u, false), vertex(v, true))` and
+/// `(vertex(u, true), vertex(v, false))`, except that a loop corresponds to a single edge. The
+/// double cover is bipartite, and is connected if and only if `g` is connected and not
+/// bipartite.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::Cycles;
+/// use fera_graph::algs::combine::bipartite_double_cover;
+///
+/// # fn main() {
+/// let c3: StaticGraph = graph!(3, (0, 1), (1, 2), (2, 0));
+/// let (c6, vertex) = bipartite_double_cover(&c3);
+/// assert_eq!(6, c6.num_vertices());
+/// assert!(c6.is_cycle_graph());
+/// let (a, b) = (vertex(0, false) as u32, vertex(1, true) as u32);
+/// assert!(c6.get_edge_by_ends(a, b).is_some());
+/// # }
+/// ```
+pub fn bipartite_double_cover<G>(g: &G) -> (StaticGraph, impl Fn(usize, bool) -> usize)
+where
+    G: VertexList + EdgeList<Kind = Undirected> + WithVertexIndexProp,
+{
+    let n = g.num_vertices();
+    let vertex = move |v: usize, copy: bool| if copy { n + v } else { v };
+    let index = g.vertex_index();
+    let mut edges = Vec::with_capacity(2 * g.num_edges());
+    for (u, v) in g.edges_ends() {
+        let (u, v) = (index.get(u), index.get(v));
+        edges.push((vertex(u, false), vertex(v, true)));
+        if u != v {
+            edges.push((vertex(u, true), vertex(v, false)));
+        }
+    }
+    (StaticGraph::new_with_edges(2 * n, edges), vertex)
+}
+
+fn union_edges<G, H>(g: &G, h: &H, offset: usize) -> Vec<(usize, usize)>
+where
+    G: EdgeList + WithVertexIndexProp,
+    H: EdgeList + WithVertexIndexProp,
+{
+    let gi = g.vertex_index();
+    let hi = h.vertex_index();
+    let mut edges = Vec::with_capacity(g.num_edges() + h.num_edges());
+    edges.extend(g.edges_ends().map(|(u, v)| (gi.get(u), gi.get(v))));
+    edges.extend(
+        h.edges_ends()
+            .map(|(u, v)| (offset + hi.get(u), offset + hi.get(v))),
+    );
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algs::Components;
+    use fera_fun::vec;
+    use rand::prelude::*;
+
+    #[test]
+    fn disjoint_union_and_join() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 0..6 {
+            for m in 0..6 {
+                let g = StaticGraph::new_gn(n, &mut rng);
+                let h = StaticGraph::new_gn(m, &mut rng);
+                let (u, offset) = disjoint_union(&g, &h);
+                assert_eq!(n, offset);
+                assert_eq!(n + m, u.num_vertices());
+                assert_eq!(g.num_edges() + h.num_edges(), u.num_edges());
+                for (a, b) in h.edges_ends() {
+                    let o = offset as u32;
+                    assert!(u.get_edge_by_ends(a + o, b + o).is_some());
+                }
+
+                let (j, offset) = join(&g, &h);
+                assert_eq!(n, offset);
+                assert_eq!(n + m, j.num_vertices());
+                assert_eq!(g.num_edges() + h.num_edges() + n * m, j.num_edges());
+            }
+        }
+    }
+
+    #[test]
+    fn cartesian_product_p2_p2() {
+        let p2: StaticGraph = graph!(2, (0, 1));
+        let (g, vertex) = cartesian_product(&p2, &p2);
+        assert_eq!(4, g.num_vertices());
+        assert_eq!(4, g.num_edges());
+        assert!(g.vertices().all(|v| g.out_degree(v) == 2));
+        let cycle = [(0, 0), (0, 1), (1, 1), (1, 0), (0, 0)];
+        for w in cycle.windows(2) {
+            let u = vertex(w[0].0, w[0].1) as u32;
+            let v = vertex(w[1].0, w[1].1) as u32;
+            assert!(g.get_edge_by_ends(u, v).is_some());
+        }
+    }
+
+    #[test]
+    fn bipartite_double_cover_random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..10 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            let (d, vertex) = bipartite_double_cover(&g);
+            assert_eq!(2 * n, d.num_vertices());
+            assert_eq!(2 * m, d.num_edges());
+            assert!(d.bipartite_classes().is_some());
+            for (u, v) in g.edges_ends() {
+                let (u, v) = (u as usize, v as usize);
+                for &c in &[false, true] {
+                    let (a, b) = (vertex(u, c) as u32, vertex(v, !c) as u32);
+                    assert!(d.get_edge_by_ends(a, b).is_some());
+                }
+            }
+            let connected = g.is_connected() && g.bipartite_classes().is_none();
+            assert_eq!(connected, d.is_connected());
+        }
+
+        let g: StaticGraph = graph!(2, (0, 0), (0, 1));
+        let (d, _) = bipartite_double_cover(&g);
+        assert_eq!(3, d.num_edges());
+    }
+
+    #[test]
+    fn cartesian_product_random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 0..5 {
+            for m in 0..5 {
+                let g = StaticGraph::new_gn(n, &mut rng);
+                let h = StaticGraph::new_gn(m, &mut rng);
+                let (p, vertex) = cartesian_product(&g, &h);
+                assert_eq!(n * m, p.num_vertices());
+                assert_eq!(g.num_edges() * m + h.num_edges() * n, p.num_edges());
+                let pairs = vec(g.vertices().flat_map(|u| h.vertices().map(move |v| (u, v))));
+                for &(u1, v1) in &pairs {
+                    for &(u2, v2) in &pairs {
+                        let adj = u1 == u2 && h.get_edge_by_ends(v1, v2).is_some()
+                            || v1 == v2 && g.get_edge_by_ends(u1, u2).is_some();
+                        let a = vertex(u1 as usize, v1 as usize) as u32;
+                        let b = vertex(u2 as usize, v2 as usize) as u32;
+                        assert_eq!(adj, p.get_edge_by_ends(a, b).is_some());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn join_complete() {
+        let (g, _) = join(&CompleteGraph::new(3), &CompleteGraph::new(4));
+        assert_eq!(7 * 6 / 2, g.num_edges());
+    }
+}
@@ -0,0 +1,113 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Community structure of graphs.
+//!
+//! A community is a set of vertices that are densely connected to each other and sparsely
+//! connected to the rest of the graph. A partition of the vertices in communities is represented
+//! by a vertex property that maps each vertex to its community, numbered from zero.
+
+use prelude::*;
+
+/// Computes Newman's modularity of `partition`, that is, the fraction of the edges that are inside
+/// the communities minus the expected fraction if the edges were distributed at random preserving
+/// the degrees of the vertices.
+///
+/// The modularity is `sum(l_c / m - (d_c / 2m)²)`, where the sum is over the communities `c`,
+/// `l_c` is the number of edges with both ends in `c`, `d_c` is the sum of the degrees of the
+/// vertices in `c` and `m` is the number of edges. A loop is inside the community of its vertex
+/// and is counted twice in the degree. The modularity is at most 1, and is 0 if all vertices are
+/// in the same community. If the graph has no edges, the modularity is undefined and `NaN` is
+/// returned.
+///
+/// The running time is O(V + E).
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::community::modularity;
+/// use fera_graph::props::FnProp;
+///
+/// # fn main() {
+/// // two triangles connected by an edge
+/// let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3));
+/// let q = modularity(&g, FnProp(|v| if v < 3 { 0usize } else { 1 }));
+/// assert!((q - 5.0 / 14.0).abs() < 1e-9);
+/// assert_eq!(0.0, modularity(&g, FnProp(|_| 0usize)));
+/// # }
+/// ```
+pub fn modularity<G, P>(g: &G, partition: P) -> f64
+where
+    G: AdjacencyGraph,
+    P: VertexPropGet<G, usize>,
+{
+    let k = g.vertices().map(|v| partition.get(v) + 1).max().unwrap_or(0);
+    let mut inside = vec![0usize; k];
+    let mut degree = vec![0usize; k];
+    for v in g.vertices() {
+        degree[partition.get(v)] += g.out_degree(v);
+    }
+    for (u, v) in g.edges_ends() {
+        let c = partition.get(u);
+        if c == partition.get(v) {
+            inside[c] += 1;
+        }
+    }
+    let m = g.num_edges() as f64;
+    inside
+        .into_iter()
+        .zip(degree)
+        .map(|(l, d)| {
+            let d = d as f64 / (2.0 * m);
+            l as f64 / m - d * d
+        }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use props::FnProp;
+
+    #[test]
+    fn two_cliques() {
+        // two K4 connected by an edge
+        let mut edges = vec![];
+        for &s in &[0usize, 4] {
+            for u in s..s + 4 {
+                for v in u + 1..s + 4 {
+                    edges.push((u, v));
+                }
+            }
+        }
+        edges.push((3, 4));
+        let g = StaticGraph::new_with_edges(8, edges);
+        let mut comp = g.default_vertex_prop(0usize);
+        for v in 4..8u32 {
+            comp[v] = 1;
+        }
+        // l = 6 and d = 13 for each community, m = 13
+        let q = modularity(&g, &comp);
+        assert!((q - (12.0 / 13.0 - 0.5)).abs() < 1e-9);
+        assert!(q > 0.4);
+
+        assert_eq!(0.0, modularity(&g, FnProp(|_| 0usize)));
+        assert_eq!(0.0, modularity(&g, FnProp(|_| 3usize)));
+
+        // each vertex in its own community
+        let q = modularity(&g, FnProp(|v| v as usize));
+        assert!(q < 0.0);
+    }
+
+    #[test]
+    fn loops() {
+        let g: StaticGraph = graph!(2, (0, 0), (1, 1), (0, 1));
+        // l = 1 and d = 3 for each community, m = 3
+        let q = modularity(&g, FnProp(|v| v as usize));
+        assert!((q - (2.0 / 3.0 - 0.5)).abs() < 1e-9);
+        let g: StaticGraph = graph!(2);
+        assert!(modularity(&g, FnProp(|v| v as usize)).is_nan());
+    }
+}
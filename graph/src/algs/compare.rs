@@ -0,0 +1,123 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Structural comparison of graphs.
+
+use prelude::*;
+
+/// Returns `true` if `g` and `h` have the same structure, that is, the same kind (directed or
+/// undirected), the same number of vertices and the same multiset of edges, where each edge is
+/// identified by the indices (given by `vertex_index`) of its ends. The ends of undirected edges
+/// are compared without regard to order.
+///
+/// This is weaker than isomorphism, the vertex with index `i` of `g` must correspond to the vertex
+/// with index `i` of `h`. It is useful to check that a transformation (like a conversion between
+/// graph types) preserves a graph.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::compare::same_structure;
+/// use fera_graph::graphs::adjmatrix::AdjMatrixGraph;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(3, (0, 1), (1, 2));
+/// let h: AdjMatrixGraph = graph!(3, (2, 1), (1, 0));
+/// assert!(same_structure(&g, &h));
+///
+/// let h: AdjMatrixGraph = graph!(3, (0, 1), (0, 2));
+/// assert!(!same_structure(&g, &h));
+///
+/// let h: StaticDigraph = graph!(3, (0, 1), (1, 2));
+/// assert!(!same_structure(&g, &h));
+/// # }
+/// ```
+pub fn same_structure<G, H>(g: &G, h: &H) -> bool
+where
+    G: VertexList + EdgeList + WithVertexIndexProp,
+    H: VertexList + EdgeList + WithVertexIndexProp,
+    G::Kind: UniformEdgeKind,
+    H::Kind: UniformEdgeKind,
+{
+    G::Kind::orientation() == H::Kind::orientation()
+        && g.num_vertices() == h.num_vertices()
+        && g.num_edges() == h.num_edges()
+        && sorted_ends(g) == sorted_ends(h)
+}
+
+fn sorted_ends<G>(g: &G) -> Vec<(usize, usize)>
+where
+    G: EdgeList + WithVertexIndexProp,
+    G::Kind: UniformEdgeKind,
+{
+    let index = g.vertex_index();
+    let mut ends: Vec<_> = g
+        .edges_ends()
+        .map(|(u, v)| {
+            let (u, v) = (index.get(u), index.get(v));
+            if G::Kind::is_undirected() && v < u {
+                (v, u)
+            } else {
+                (u, v)
+            }
+        }).collect();
+    ends.sort();
+    ends
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use graphs::adjmatrix::{AdjMatrixDigraph, AdjMatrixGraph};
+    use rand::prelude::*;
+
+    // creates a new graph with the ends of each edge swapped
+    fn rebuild<G: VertexList + EdgeList + WithVertexIndexProp>(g: &G) -> StaticGraph {
+        let index = g.vertex_index();
+        let edges = g
+            .edges_ends()
+            .map(|(u, v)| (index.get(v), index.get(u)))
+            .collect::<Vec<_>>();
+        StaticGraph::new_with_edges(g.num_vertices(), edges)
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..20 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            assert!(same_structure(&g, &g));
+            assert!(same_structure(&g, &rebuild(&g)));
+            assert!(same_structure(&g, &AdjMatrixGraph::from_graph(&g)));
+
+            let d = StaticDigraph::new_gnm(n, m, &mut rng).unwrap();
+            assert!(same_structure(&d, &AdjMatrixDigraph::from_graph(&d)));
+        }
+    }
+
+    #[test]
+    fn different() {
+        let g: StaticGraph = graph!(3, (0, 1), (1, 2));
+        assert!(!same_structure(&g, &StaticGraph::new_with_edges(4, vec![(0, 1), (1, 2)])));
+        assert!(!same_structure(&g, &StaticGraph::new_with_edges(3, vec![(0, 1), (0, 2)])));
+
+        // multisets of edges
+        let g: StaticGraph = graph!(3, (0, 1), (0, 1), (1, 2));
+        let h: StaticGraph = graph!(3, (0, 1), (1, 2), (1, 2));
+        assert!(!same_structure(&g, &h));
+
+        // kinds
+        let g: StaticGraph = graph!(2);
+        let h: StaticDigraph = graph!(2);
+        assert!(!same_structure(&g, &h));
+
+        // directed edges are not reversed
+        let g: StaticDigraph = graph!(2, (0, 1));
+        let h: StaticDigraph = graph!(2, (1, 0));
+        assert!(!same_structure(&g, &h));
+    }
+}
@@ -0,0 +1,114 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Complement of graphs and digraphs.
+
+use prelude::*;
+
+use fera_fun::vec;
+
+pub trait Complement: EdgeList {
+    /// Creates the complement of this graph, that is, a graph with the same number of vertices
+    /// and with an edge `(u, v)` for each pair of distinct non adjacent vertices `u` and `v`. The
+    /// vertex `v` of this graph corresponds to the vertex `vertex_index().get(v)` of the
+    /// complement.
+    ///
+    /// This takes O(V^2) time (assuming `get_edge_by_ends` takes constant time) and is intended
+    /// for small and medium graphs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Complement;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+    /// let c = g.complement();
+    /// assert_eq!(3, c.num_edges());
+    /// assert!(c.get_edge_by_ends(0, 2).is_some());
+    /// assert!(c.get_edge_by_ends(0, 3).is_some());
+    /// assert!(c.get_edge_by_ends(1, 3).is_some());
+    /// # }
+    /// ```
+    fn complement(&self) -> StaticGraph
+    where
+        Self: VertexList + EdgeList<Kind = Undirected> + WithVertexIndexProp,
+    {
+        let index = self.vertex_index();
+        let vertices = vec(self.vertices());
+        let mut edges = vec![];
+        for (i, &u) in vertices.iter().enumerate() {
+            for &v in &vertices[i + 1..] {
+                if self.get_edge_by_ends(u, v).is_none() {
+                    edges.push((index.get(u), index.get(v)));
+                }
+            }
+        }
+        StaticGraph::new_with_edges(vertices.len(), edges)
+    }
+
+    /// Creates the complement of this digraph, that is, a digraph with the same number of
+    /// vertices and with an edge `(u, v)` for each pair of distinct vertices `u` and `v` such that
+    /// this digraph has no edge `(u, v)`. The vertex `v` of this digraph corresponds to the vertex
+    /// `vertex_index().get(v)` of the complement.
+    ///
+    /// This takes O(V^2) time (assuming `get_edge_by_ends` takes constant time) and is intended
+    /// for small and medium digraphs.
+    fn complement_digraph(&self) -> StaticDigraph
+    where
+        Self: VertexList + EdgeList<Kind = Directed> + WithVertexIndexProp,
+    {
+        let index = self.vertex_index();
+        let mut edges = vec![];
+        for u in self.vertices() {
+            for v in self.vertices() {
+                if u != v && self.get_edge_by_ends(u, v).is_none() {
+                    edges.push((index.get(u), index.get(v)));
+                }
+            }
+        }
+        StaticDigraph::new_with_edges(self.num_vertices(), edges)
+    }
+}
+
+impl<G: EdgeList> Complement for G {}
+
+#[cfg(test)]
+mod tests {
+    use super::Complement;
+    use prelude::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn complement_of_complete() {
+        for n in 1..6 {
+            let g = CompleteGraph::new(n as u32);
+            assert_eq!(0, g.complement().num_edges());
+            let g = StaticGraph::new_empty(n);
+            assert_eq!(n * (n - 1) / 2, g.complement().num_edges());
+        }
+    }
+
+    #[test]
+    fn complement_random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..10 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            let c = g.complement();
+            assert_eq!(n * (n - 1) / 2 - m, c.num_edges());
+            for (u, v) in g.edges_ends() {
+                assert!(c.get_edge_by_ends(u, v).is_none());
+            }
+            let g = StaticDigraph::new_gnm(n, m, &mut rng).unwrap();
+            let c = g.complement_digraph();
+            assert_eq!(n * (n - 1) - m, c.num_edges());
+            for (u, v) in g.edges_ends() {
+                assert!(c.get_edge_by_ends(u, v).is_none());
+            }
+        }
+    }
+}
@@ -7,6 +7,7 @@
 use prelude::*;
 use props::Color;
 use traverse::*;
+use unionfind::{UnionFind, WithUnionFind};
 
 use fera_fun::{first, vec};
 use num_traits::{zero, Zero};
@@ -34,6 +35,41 @@ pub trait Components: Incidence {
         cc
     }
 
+    /// Returns an iterator that yields the vertices of each connected component, one component at
+    /// a time.
+    ///
+    /// The components are found lazily by a breadth-first search from the first vertex (in the
+    /// order of `self.vertices()`) not yet visited, so only the visited marks and the current
+    /// component are kept in memory. The vertices of each component are in the order they are
+    /// discovered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Components;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(6, (0, 4), (2, 3), (4, 1));
+    /// let mut iter = g.connected_components_iter();
+    /// assert_eq!(Some(vec![0, 4, 1]), iter.next());
+    /// assert_eq!(Some(vec![2, 3]), iter.next());
+    /// assert_eq!(Some(vec![5]), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// # }
+    /// ```
+    fn connected_components_iter<'a>(&'a self) -> ComponentsIter<'a, Self>
+    where
+        Self: IncidenceGraph,
+    {
+        ComponentsIter {
+            g: self,
+            vertices: self.vertices(),
+            visited: self.default_vertex_prop(false),
+        }
+    }
+
     fn is_connected(&self) -> bool
     where
         Self: VertexList + WithVertexProp<Color>,
@@ -43,6 +79,62 @@ pub trait Components: Incidence {
         con
     }
 
+    /// Returns the number of vertices in each connected component, sorted in non-increasing
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Components;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(6, (0, 1), (2, 3), (3, 4));
+    /// assert_eq!(vec![3, 2, 1], g.component_sizes());
+    /// # }
+    /// ```
+    fn component_sizes(&self) -> Vec<usize>
+    where
+        Self: VertexList + WithVertexProp<Color> + WithVertexProp<usize>,
+    {
+        let mut sizes = sizes_by_component(self, &self.connected_components());
+        sizes.sort_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    /// Returns the subgraph induced by the vertices of the connected component with the most
+    /// vertices. If there is more than one such component, the one with the vertex that comes
+    /// first in `self.vertices()` is returned. This is useful to discard small disconnected parts
+    /// of a graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Components;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(6, (0, 1), (2, 3), (3, 4));
+    /// let sub = g.largest_component();
+    /// assert_eq!(vec![2, 3, 4], sub.vertices().collect::<Vec<_>>());
+    /// assert_eq!(2, sub.num_edges());
+    /// # }
+    /// ```
+    fn largest_component<'a>(&'a self) -> Subgraph<'a, Self>
+    where
+        Self: IncidenceGraph,
+    {
+        let cc = self.connected_components();
+        let sizes = sizes_by_component(self, &cc);
+        // the components are numbered in the order their first vertex is found, so the first
+        // component with maximum size is the one with the first vertex
+        let max = sizes.iter().cloned().max().unwrap_or(0);
+        let largest = sizes.iter().position(|&s| s == max).unwrap_or(0);
+        self.induced_subgraph(self.vertices().filter(|&v| cc.component(v) == largest))
+    }
+
     fn cut_vertices(&self) -> Vec<Vertex<Self>>
     where
         Self: Graph,
@@ -75,10 +167,74 @@ pub trait Components: Incidence {
         self.dfs(&mut vis).run();
         vis.cuts
     }
+
+    /// Returns the two classes of a bipartition of the vertices of this graph, that is, two
+    /// independent sets whose union is the set of all vertices, or `None` if the graph is not
+    /// bipartite (has an odd cycle).
+    ///
+    /// The classes are found by a breadth-first search that alternates the class of the vertices.
+    /// For a disconnected graph, the root of each component is in the first class. The vertices
+    /// of each class are in the order of `self.vertices()`. The running time is O(V + E).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Components;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(5, (0, 1), (1, 2), (3, 4));
+    /// assert_eq!(Some((vec![0, 2, 3], vec![1, 4])), g.bipartite_classes());
+    ///
+    /// let g: StaticGraph = graph!(3, (0, 1), (1, 2), (2, 0));
+    /// assert_eq!(None, g.bipartite_classes());
+    /// # }
+    /// ```
+    fn bipartite_classes(&self) -> Option<(Vec<Vertex<Self>>, Vec<Vertex<Self>>)>
+    where
+        Self: IncidenceGraph,
+    {
+        let mut second = self.default_vertex_prop(false);
+        self.bfs(OnDiscoverTreeEdge(|e| {
+            second[self.target(e)] = !second[self.source(e)]
+        })).run();
+        if self.edges_ends().any(|(u, v)| second[u] == second[v]) {
+            return None;
+        }
+        let (b, a) = self.vertices().partition(|&v| second[v]);
+        Some((a, b))
+    }
+
+    /// Creates a [`ConnectivityTracker`] for this graph, with no edges added.
+    ///
+    /// [`ConnectivityTracker`]: struct.ConnectivityTracker.html
+    fn connectivity_tracker<'a>(&'a self) -> ConnectivityTracker<'a, Self>
+    where
+        Self: Graph,
+    {
+        ConnectivityTracker {
+            g: self,
+            ds: self.new_unionfind(),
+        }
+    }
 }
 
 impl<G: Incidence> Components for G {}
 
+// Returns the number of vertices of each component, indexed by the component number.
+fn sizes_by_component<G, V>(g: &G, cc: &ConnectedComponents<G, V>) -> Vec<usize>
+where
+    G: VertexList + WithEdge,
+    V: VertexPropMut<G, usize>,
+{
+    let mut sizes = vec![0; cc.num_components()];
+    for v in g.vertices() {
+        sizes[cc.component(v)] += 1;
+    }
+    sizes
+}
+
 pub struct IsConnected<'a> {
     connected: &'a mut bool,
     saw_root: bool,
@@ -173,6 +329,111 @@ where
     }
 }
 
+/// An iterator over the vertices of each connected component of a graph.
+///
+/// This `struct` is created by [`Components::connected_components_iter`].
+///
+/// [`Components::connected_components_iter`]:
+/// trait.Components.html#method.connected_components_iter
+pub struct ComponentsIter<'a, G>
+where
+    G: 'a + Adjacency + WithVertexProp<bool>,
+{
+    g: &'a G,
+    vertices: VertexIter<'a, G>,
+    visited: DefaultVertexPropMut<G, bool>,
+}
+
+impl<'a, G> Iterator for ComponentsIter<'a, G>
+where
+    G: 'a + Adjacency + WithVertexProp<bool>,
+{
+    type Item = Vec<Vertex<G>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = {
+            let visited = &self.visited;
+            self.vertices.by_ref().find(|&v| !visited[v])?
+        };
+        self.visited[start] = true;
+        // the component is also the queue of the breadth-first search
+        let mut comp = vec![start];
+        let mut i = 0;
+        while i < comp.len() {
+            for u in self.g.out_neighbors(comp[i]) {
+                if !self.visited[u] {
+                    self.visited[u] = true;
+                    comp.push(u);
+                }
+            }
+            i += 1;
+        }
+        Some(comp)
+    }
+}
+
+/// Keeps track of the connected components of a graph as its edges are added one at a time
+/// (this is what Kruskal's algorithm does). Initially each vertex is in its own component.
+///
+/// This is created by [`Components::connectivity_tracker`] and is backed by a [`UnionFind`].
+///
+/// # Panics
+///
+/// If the graph has no vertices.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::Components;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(4, (0, 1), (2, 3), (1, 0));
+/// let e = g.edges().collect::<Vec<_>>();
+/// let mut tracker = g.connectivity_tracker();
+/// assert_eq!(4, tracker.num_components());
+/// assert!(tracker.add_edge(e[0]));
+/// assert!(tracker.add_edge(e[1]));
+/// assert!(!tracker.add_edge(e[2]));
+/// assert_eq!(2, tracker.num_components());
+/// assert!(tracker.is_connected(1, 0));
+/// assert!(!tracker.is_connected(1, 2));
+/// # }
+/// ```
+///
+/// [`Components::connectivity_tracker`]: trait.Components.html#method.connectivity_tracker
+/// [`UnionFind`]: ../../unionfind/struct.UnionFind.html
+pub struct ConnectivityTracker<'a, G: 'a + Graph> {
+    g: &'a G,
+    ds: UnionFind<G>,
+}
+
+impl<'a, G: 'a + Graph> ConnectivityTracker<'a, G> {
+    /// Adds the edge `e`, merging the components of its ends. Returns `true` if the ends were in
+    /// different components, otherwise `false`.
+    pub fn add_edge(&mut self, e: Edge<G>) -> bool {
+        let (u, v) = self.g.ends(e);
+        if self.ds.in_same_set(u, v) {
+            false
+        } else {
+            self.ds.union(u, v);
+            true
+        }
+    }
+
+    /// Returns `true` if `u` and `v` are in the same component considering the edges added so
+    /// far, otherwise `false`.
+    pub fn is_connected(&mut self, u: Vertex<G>, v: Vertex<G>) -> bool {
+        self.ds.in_same_set(u, v)
+    }
+
+    /// Returns the number of components considering the edges added so far.
+    pub fn num_components(&self) -> usize {
+        self.ds.num_sets()
+    }
+}
+
 pub struct FindCutVertices<G: Graph> {
     time: u64,
     discover: DefaultVertexPropMut<G, u64>,
@@ -286,7 +547,113 @@ fn is_cut_edge_naive<G: IncidenceGraph>(g: &G, e: Edge<G>) -> bool {
 #[cfg(test)]
 mod tests {
     use super::{cut_edges_naive, cut_vertices_naive, Components};
+    use algs::Sets;
+    use fera_fun::vec;
+    use named::complete_bipartite;
     use prelude::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn connectivity_tracker_path() {
+        let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 4));
+        let mut tracker = g.connectivity_tracker();
+        assert_eq!(5, tracker.num_components());
+        for (i, e) in g.edges().enumerate() {
+            assert!(tracker.add_edge(e));
+            assert_eq!(4 - i, tracker.num_components());
+            assert!(tracker.is_connected(0, i as u32 + 1));
+        }
+        for e in g.edges() {
+            assert!(!tracker.add_edge(e));
+        }
+        assert_eq!(1, tracker.num_components());
+    }
+
+    #[test]
+    fn connectivity_tracker_random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..20 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            let mut tracker = g.connectivity_tracker();
+            let merges = vec(g.edges()).into_iter().filter(|&e| tracker.add_edge(e)).count();
+            assert_eq!(n - merges, tracker.num_components());
+            let cc = g.connected_components();
+            assert_eq!(cc.num_components(), tracker.num_components());
+            for u in g.vertices() {
+                for v in g.vertices() {
+                    assert_eq!(cc.is_connected(u, v), tracker.is_connected(u, v));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn connected_components_iter() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 0..20usize {
+            let m = rng.gen_range(0, n * n.saturating_sub(1) / 2 + 1);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            let cc = g.connected_components();
+            let comps = vec(g.connected_components_iter());
+            assert_eq!(cc.num_components(), comps.len());
+            assert_eq!(n, comps.iter().map(|c| c.len()).sum::<usize>());
+            // the components are found in the same order by both methods
+            for (i, comp) in comps.iter().enumerate() {
+                assert!(comp.iter().all(|&v| cc.component(v) == i));
+            }
+        }
+    }
+
+    #[test]
+    fn largest_component() {
+        let g: StaticGraph = graph!(
+            9,
+            (8, 8),
+            (0, 1),
+            (0, 2),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (6, 7),
+            (7, 3),
+            (4, 6)
+        );
+        assert_eq!(vec![5, 3, 1], g.component_sizes());
+        let sub = g.largest_component();
+        assert_eq!(vec![3, 4, 5, 6, 7], vec(sub.vertices()));
+        assert_eq!(6, sub.num_edges());
+        assert!(sub.is_connected());
+
+        // ties are broken by the first vertex
+        let g: StaticGraph = graph!(5, (1, 2), (3, 4), (0, 0));
+        assert_eq!(vec![2, 2, 1], g.component_sizes());
+        assert_eq!(vec![1, 2], vec(g.largest_component().vertices()));
+
+        let g: StaticGraph = graph!(0);
+        assert!(g.component_sizes().is_empty());
+        assert_eq!(0, g.largest_component().num_vertices());
+    }
+
+    #[test]
+    fn bipartite_classes() {
+        let g = complete_bipartite(2, 3);
+        let (a, b) = g.bipartite_classes().unwrap();
+        assert_eq!(vec![0, 1], a);
+        assert_eq!(vec![2, 3, 4], b);
+
+        let g: StaticGraph = graph!(4, (0, 0), (1, 2));
+        assert_eq!(None, g.bipartite_classes());
+
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..10 {
+            let g = StaticGraph::new_random_tree(n, &mut rng);
+            let (a, b) = g.bipartite_classes().unwrap();
+            assert_eq!(n, a.len() + b.len());
+            assert!(g.is_independent_vertex_set(&a));
+            assert!(g.is_independent_vertex_set(&b));
+        }
+    }
 
     #[test]
     fn cut_vertices() {
@@ -0,0 +1,237 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Edge contraction and quotient graphs.
+
+use prelude::*;
+
+use std::collections::HashSet;
+
+/// Creates a new graph by contracting the edge `e` of `g`, that is, by merging the ends of `e`
+/// into a single vertex.
+///
+/// The edges incident to the ends of `e` become incident to the merged vertex. The loops on the
+/// merged vertex (including `e` and the edges parallel to `e`) are removed. If `keep_parallel` is
+/// `false`, at most one edge is kept between each pair of vertices.
+///
+/// Returns the new graph and a property that maps each vertex of `g` to the corresponding vertex
+/// of the new graph. The relative order of the vertices is preserved, that is, if `u` comes before
+/// `v` in `g.vertices()`, then `map[u] <= map[v]`.
+///
+/// # Panics
+///
+/// If `e` is a loop.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::contract::contract_edge;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 0), (2, 3));
+/// let e = g.edge_by_ends(0, 1);
+///
+/// let (h, map) = contract_edge(&g, e, true);
+/// assert_eq!(3, h.num_vertices());
+/// assert_eq!(3, h.num_edges());
+/// let (u, v) = g.ends(e);
+/// assert_eq!(map[u], map[v]);
+/// assert_eq!(2, h.out_degree(map[u]));
+///
+/// let (h, _) = contract_edge(&g, e, false);
+/// assert_eq!(2, h.num_edges());
+/// # }
+/// ```
+pub fn contract_edge<G>(
+    g: &G,
+    e: Edge<G>,
+    keep_parallel: bool,
+) -> (StaticGraph, DefaultVertexPropMut<G, Vertex<StaticGraph>>)
+where
+    G: Graph,
+{
+    let (u, v) = g.ends(e);
+    assert!(u != v, "cannot contract a loop");
+    let mut map = g.default_vertex_prop(0u32);
+    let mut n = 0;
+    for x in g.vertices() {
+        if x == v {
+            continue;
+        }
+        map[x] = n;
+        n += 1;
+        if x == u {
+            map[v] = map[u];
+        }
+    }
+    let w = map[u];
+    let mut seen = HashSet::new();
+    let mut edges = Vec::with_capacity(g.num_edges());
+    for (a, b) in g.edges_ends() {
+        let (a, b) = (map[a], map[b]);
+        if a == w && b == w {
+            continue;
+        }
+        if keep_parallel || seen.insert((a.min(b), a.max(b))) {
+            edges.push((a as usize, b as usize));
+        }
+    }
+    (StaticGraph::new_with_edges(n as usize, edges), map)
+}
+
+/// Creates the quotient graph of `g` with respect to `partition`, that is, a graph with one vertex
+/// for each block of the partition and an edge between two blocks for each edge of `g` between
+/// vertices of the blocks.
+///
+/// The block of a vertex `v` is `partition.get(v)` and is also the vertex that represents the
+/// block in the new graph, so the new graph has `k + 1` vertices, where `k` is the maximum block.
+/// The edges inside a block are removed, unless `keep_loops` is `true`, in which case they become
+/// loops. If `keep_parallel` is `false`, at most one edge is kept between each pair of vertices.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::contract::quotient_graph;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 3), (1, 3), (3, 4));
+/// let mut blocks = g.default_vertex_prop(0usize);
+/// for v in g.vertices().skip(3) {
+///     blocks[v] = 1;
+/// }
+///
+/// let h = quotient_graph(&g, &blocks, true, false);
+/// assert_eq!(2, h.num_vertices());
+/// assert_eq!(2, h.num_edges());
+///
+/// let h = quotient_graph(&g, &blocks, false, false);
+/// assert_eq!(1, h.num_edges());
+///
+/// let h = quotient_graph(&g, &blocks, false, true);
+/// assert_eq!(3, h.num_edges());
+/// # }
+/// ```
+pub fn quotient_graph<G, P>(
+    g: &G,
+    partition: &P,
+    keep_parallel: bool,
+    keep_loops: bool,
+) -> StaticGraph
+where
+    G: Graph,
+    P: VertexPropGet<G, usize>,
+{
+    let n = g.vertices().map(|v| partition.get(v) + 1).max().unwrap_or(0);
+    let mut seen = HashSet::new();
+    let mut edges = Vec::with_capacity(g.num_edges());
+    for (a, b) in g.edges_ends() {
+        let (a, b) = (partition.get(a), partition.get(b));
+        if a == b && !keep_loops {
+            continue;
+        }
+        if keep_parallel || seen.insert((a.min(b), a.max(b))) {
+            edges.push((a, b));
+        }
+    }
+    StaticGraph::new_with_edges(n, edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algs::Components;
+    use fera_fun::vec;
+    use props::FnProp;
+    use rand::prelude::*;
+
+    #[test]
+    fn triangle() {
+        let g: StaticGraph = graph!(3, (0, 1), (1, 2), (2, 0));
+        let e = g.edge_by_ends(1, 2);
+
+        let (h, map) = contract_edge(&g, e, true);
+        assert_eq!(2, h.num_vertices());
+        assert_eq!(2, h.num_edges());
+        assert_eq!(vec![0, 1, 1], vec(g.vertices().map(|v| map[v])));
+        assert!(h.edges_ends().all(|(a, b)| a != b));
+
+        let (h, _) = contract_edge(&g, e, false);
+        assert_eq!(2, h.num_vertices());
+        assert_eq!(1, h.num_edges());
+    }
+
+    #[test]
+    fn loops_and_parallel_edges() {
+        let g: StaticGraph = graph!(4, (0, 1), (1, 0), (1, 1), (2, 2), (1, 3), (3, 0));
+        let e = g.edge_by_ends(0, 1);
+        let (h, map) = contract_edge(&g, e, true);
+        assert_eq!(vec![0, 0, 1, 2], vec(g.vertices().map(|v| map[v])));
+        assert_eq!(3, h.num_edges());
+        assert!(h.get_edge_by_ends(1, 1).is_some());
+        assert_eq!(2, h.out_degree(0));
+
+        let (h, _) = contract_edge(&g, e, false);
+        assert_eq!(2, h.num_edges());
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 2..10 {
+            let m = (n + 3).min(n * (n - 1) / 2);
+            let g = StaticGraph::new_gnm_connected(n, m, &mut rng).unwrap();
+            for e in g.edges() {
+                let (u, v) = g.ends(e);
+                let (h, map) = contract_edge(&g, e, true);
+                assert_eq!(n - 1, h.num_vertices());
+                let between = g
+                    .edges_ends()
+                    .filter(|&(a, b)| (a, b) == (u, v) || (a, b) == (v, u))
+                    .count();
+                assert_eq!(g.num_edges() - between, h.num_edges());
+                for (a, b) in g.edges_ends() {
+                    if (a, b) != (u, v) && (a, b) != (v, u) {
+                        assert!(h.get_edge_by_ends(map[a], map[b]).is_some());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn quotient_of_components() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..20 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            let cc = g.connected_components();
+            let mut comp = g.default_vertex_prop(0);
+            for v in g.vertices() {
+                comp[v] = cc.component(v);
+            }
+            let h = quotient_graph(&g, &comp, true, false);
+            assert_eq!(cc.num_components(), h.num_vertices());
+            assert_eq!(0, h.num_edges());
+            let h = quotient_graph(&g, &comp, true, true);
+            assert_eq!(g.num_edges(), h.num_edges());
+        }
+    }
+
+    #[test]
+    fn quotient_blocks() {
+        let g: StaticGraph = graph!(4, (0, 1), (0, 2), (2, 1), (2, 3), (3, 2));
+        let blocks = FnProp(|v| v as usize / 2);
+        let h = quotient_graph(&g, &blocks, true, false);
+        assert_eq!(2, h.num_vertices());
+        assert_eq!(2, h.num_edges());
+        let h = quotient_graph(&g, &blocks, false, true);
+        assert_eq!(3, h.num_edges());
+        assert!(h.get_edge_by_ends(0, 0).is_some());
+        assert!(h.get_edge_by_ends(1, 1).is_some());
+    }
+}
@@ -0,0 +1,237 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Vertex covers and independent sets.
+//!
+//! A vertex cover is a set of vertices such that each edge has at least one end in the set. The
+//! complement of a vertex cover is an independent set, so the complement of a minimum vertex cover
+//! is a maximum independent set.
+
+use prelude::*;
+
+/// Returns a vertex cover of `g` with at most twice the size of a minimum vertex cover.
+///
+/// For each edge with no end in the cover, both ends are added to the cover. The ends of these
+/// edges form a matching, and each vertex cover must contain at least one end of each edge in the
+/// matching, which gives the approximation ratio. The running time is O(V + E).
+///
+/// The vertices are returned in the order they are added to the cover.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::cover::greedy_vertex_cover;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+/// assert_eq!(vec![0, 1, 2, 3], greedy_vertex_cover(&g));
+/// # }
+/// ```
+pub fn greedy_vertex_cover<G>(g: &G) -> Vec<Vertex<G>>
+where
+    G: Graph,
+{
+    let mut in_cover = g.default_vertex_prop(false);
+    let mut cover = vec![];
+    for (u, v) in g.edges_ends() {
+        if in_cover[u] || in_cover[v] {
+            continue;
+        }
+        in_cover[u] = true;
+        cover.push(u);
+        if u != v {
+            in_cover[v] = true;
+            cover.push(v);
+        }
+    }
+    debug_assert!(g.edges_ends().all(|(u, v)| in_cover[u] || in_cover[v]));
+    cover
+}
+
+/// Returns a minimum vertex cover of `g`.
+///
+/// This uses a branch and bound algorithm that, for an uncovered edge `(u, v)`, tries to add `u`
+/// or `v` to the cover, and discards the partial covers that are not smaller than the best cover
+/// found so far. The running time is exponential in the size of the cover, so this is suitable
+/// only for small graphs.
+///
+/// The vertices are returned in the order of `g.vertices()`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::cover::min_vertex_cover_exact;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+/// assert_eq!(2, min_vertex_cover_exact(&g).len());
+/// let g: StaticGraph = graph!(5, (0, 1), (0, 2), (0, 3), (0, 4));
+/// assert_eq!(vec![0], min_vertex_cover_exact(&g));
+/// # }
+/// ```
+pub fn min_vertex_cover_exact<G>(g: &G) -> Vec<Vertex<G>>
+where
+    G: Graph + WithVertexIndexProp,
+{
+    let index = g.vertex_index();
+    let vertices: Vec<_> = g.vertices().collect();
+    let edges: Vec<_> = g
+        .edges_ends()
+        .map(|(u, v)| (index.get(u), index.get(v)))
+        .collect();
+    let mut best = vec![false; vertices.len()];
+    for v in greedy_vertex_cover(g) {
+        best[index.get(v)] = true;
+    }
+    let mut best_size = best.iter().filter(|&&x| x).count();
+    let mut cover = vec![false; vertices.len()];
+    branch(&edges, 0, &mut cover, 0, &mut best, &mut best_size);
+    vertices
+        .into_iter()
+        .zip(best)
+        .filter(|&(_, x)| x)
+        .map(|(v, _)| v)
+        .collect()
+}
+
+// Extends `cover` (with `size` vertices) to cover `edges[start..]`, the previous edges are already
+// covered.
+fn branch(
+    edges: &[(usize, usize)],
+    start: usize,
+    cover: &mut Vec<bool>,
+    size: usize,
+    best: &mut Vec<bool>,
+    best_size: &mut usize,
+) {
+    let i = match (start..edges.len()).find(|&i| !cover[edges[i].0] && !cover[edges[i].1]) {
+        Some(i) => i,
+        None => {
+            if size < *best_size {
+                best.clone_from(cover);
+                *best_size = size;
+            }
+            return;
+        }
+    };
+    if size + 1 >= *best_size {
+        return;
+    }
+    let (u, v) = edges[i];
+    cover[u] = true;
+    branch(edges, i + 1, cover, size + 1, best, best_size);
+    cover[u] = false;
+    if u != v {
+        cover[v] = true;
+        branch(edges, i + 1, cover, size + 1, best, best_size);
+        cover[v] = false;
+    }
+}
+
+/// Returns a maximal independent set of `g`, that is, an independent set that is not a subset of
+/// another independent set.
+///
+/// The set is the complement of a minimal vertex cover, which is obtained by removing the
+/// redundant vertices of [`greedy_vertex_cover`]. A vertex with a loop is never in an independent
+/// set. To find a maximum independent set, use the complement of [`min_vertex_cover_exact`].
+///
+/// The vertices are returned in the order of `g.vertices()`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::cover::maximal_independent_set;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(5, (0, 1), (0, 2), (0, 3), (0, 4));
+/// assert_eq!(vec![1, 2, 3, 4], maximal_independent_set(&g));
+/// # }
+/// ```
+///
+/// [`greedy_vertex_cover`]: fn.greedy_vertex_cover.html
+/// [`min_vertex_cover_exact`]: fn.min_vertex_cover_exact.html
+pub fn maximal_independent_set<G>(g: &G) -> Vec<Vertex<G>>
+where
+    G: IncidenceGraph,
+{
+    let mut in_cover = g.default_vertex_prop(false);
+    let cover = greedy_vertex_cover(g);
+    in_cover.set_values(&cover, true);
+    for v in cover {
+        if g.out_neighbors(v).all(|u| u != v && in_cover[u]) {
+            in_cover[v] = false;
+        }
+    }
+    g.vertices().filter(|&v| !in_cover[v]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algs::Sets;
+    use rand::prelude::*;
+
+    fn is_vertex_cover(g: &StaticGraph, cover: &[Vertex<StaticGraph>]) -> bool {
+        let mut in_cover = g.default_vertex_prop(false);
+        for &v in cover {
+            in_cover[v] = true;
+        }
+        g.edges_ends().all(|(u, v)| in_cover[u] || in_cover[v])
+    }
+
+    // the size of a minimum vertex cover by testing all subsets of vertices
+    fn min_vertex_cover_size(g: &StaticGraph) -> usize {
+        let n = g.num_vertices();
+        (0u32..1 << n)
+            .filter(|s| {
+                g.edges_ends()
+                    .all(|(u, v)| s & (1 << u) != 0 || s & (1 << v) != 0)
+            }).map(|s| s.count_ones() as usize)
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn loops() {
+        let g: StaticGraph = graph!(3, (0, 0), (1, 2));
+        assert_eq!(vec![0, 1, 2], greedy_vertex_cover(&g));
+        assert_eq!(2, min_vertex_cover_exact(&g).len());
+        assert!(min_vertex_cover_exact(&g).contains(&0));
+        assert_eq!(vec![1], maximal_independent_set(&g));
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..11 {
+            for _ in 0..5 {
+                let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+                let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+                let min = min_vertex_cover_size(&g);
+
+                let greedy = greedy_vertex_cover(&g);
+                assert!(is_vertex_cover(&g, &greedy));
+                assert!(greedy.len() <= 2 * min);
+
+                let exact = min_vertex_cover_exact(&g);
+                assert!(is_vertex_cover(&g, &exact));
+                assert_eq!(min, exact.len());
+
+                let ind = maximal_independent_set(&g);
+                assert!(g.is_independent_vertex_set(&ind));
+                for v in g.vertices_complement(&ind) {
+                    let mut bigger = ind.clone();
+                    bigger.push(v);
+                    assert!(!g.is_independent_vertex_set(&bigger));
+                }
+            }
+        }
+    }
+}
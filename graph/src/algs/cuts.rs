@@ -0,0 +1,311 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Minimum cuts.
+
+use prelude::*;
+use unionfind::WithUnionFind;
+
+use num_traits::Zero;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use std::ops::Add;
+
+/// Finds a minimum cut of `g` using [Karger]'s randomized contraction algorithm. Returns the size
+/// of the smallest cut found and the edges of the cut.
+///
+/// Each iteration contracts random edges (using a union find over the vertices of `g`) until only
+/// two vertices remain and takes the edges between them as a cut. Contracting the edges in the
+/// order of a random permutation is equivalent to contracting a random edge of the contracted
+/// multigraph at each step. A single iteration finds a specific minimum cut with probability at
+/// least `2 / (n (n - 1))`, so about `n^2 ln n` iterations are needed to find a minimum cut with
+/// high probability. Each iteration takes `O(m α(n))` time.
+///
+/// If `g` is disconnected, a cut with zero edges is returned.
+///
+/// # Panics
+///
+/// If `g` has less than two vertices or if `iterations` is zero.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// extern crate rand;
+///
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::cuts::karger_min_cut;
+/// use rand::prelude::*;
+///
+/// # fn main() {
+/// // two triangles joined by the edge (2, 3)
+/// let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3));
+/// let mut rng = SmallRng::seed_from_u64(5);
+/// let (size, cut) = karger_min_cut(&g, &mut rng, 50);
+/// assert_eq!(1, size);
+/// assert_eq!(vec![g.edge_by_ends(2, 3)], cut);
+/// # }
+/// ```
+///
+/// [Karger]: https://en.wikipedia.org/wiki/Karger%27s_algorithm
+pub fn karger_min_cut<G, R>(g: &G, rng: &mut R, iterations: usize) -> (usize, Vec<Edge<G>>)
+where
+    G: Graph,
+    R: Rng,
+{
+    assert!(g.num_vertices() >= 2, "graph with less than two vertices");
+    assert!(iterations > 0, "zero iterations");
+    let mut edges: Vec<_> = g.edges().collect();
+    let mut best: Option<Vec<Edge<G>>> = None;
+    for _ in 0..iterations {
+        let mut ds = g.new_unionfind();
+        edges.shuffle(rng);
+        for &e in &edges {
+            if ds.num_sets() <= 2 {
+                break;
+            }
+            let (u, v) = g.ends(e);
+            if !ds.in_same_set(u, v) {
+                ds.union(u, v);
+            }
+        }
+        let cut: Vec<_> = edges
+            .iter()
+            .cloned()
+            .filter(|&e| {
+                let (u, v) = g.ends(e);
+                !ds.in_same_set(u, v)
+            })
+            .collect();
+        if best.as_ref().map(|b| cut.len() < b.len()).unwrap_or(true) {
+            best = Some(cut);
+        }
+        if best.as_ref().map(Vec::is_empty) == Some(true) {
+            break;
+        }
+    }
+    let best = best.unwrap();
+    (best.len(), best)
+}
+
+/// Finds a minimum cut of `g` using the [Stoer–Wagner] algorithm. Returns the weight of the cut
+/// and the vertices of one of its sides, in the order of `g.vertices()`. The weight of a cut is
+/// the sum of the weights of the edges with one end in each side. Edge weights must be
+/// non-negative.
+///
+/// The algorithm executes `n - 1` phases. Each phase computes a maximum adjacency order of the
+/// vertices, that is, the next vertex is the one most tightly connected to the previous ones. The
+/// edges between the last vertex and the others form a cut, which is a candidate for the minimum
+/// cut, and then the last two vertices are merged. Unlike [`karger_min_cut`], the result is
+/// always a minimum cut. The running time is O(V^3).
+///
+/// The loops of `g` are ignored. If `g` is disconnected, the weight of the cut is zero.
+///
+/// # Panics
+///
+/// If `g` has less than two vertices.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::cuts::global_min_cut;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (3, 0), (0, 2));
+/// let mut w = g.default_edge_prop(3u32);
+/// w[g.edge_by_ends(0, 2)] = 1;
+/// w[g.edge_by_ends(2, 3)] = 1;
+/// assert_eq!((4, vec![3]), global_min_cut(&g, &w));
+/// # }
+/// ```
+///
+/// [Stoer–Wagner]: https://en.wikipedia.org/wiki/Stoer%E2%80%93Wagner_algorithm
+/// [`karger_min_cut`]: fn.karger_min_cut.html
+pub fn global_min_cut<G, W, T>(g: &G, w: W) -> (T, Vec<Vertex<G>>)
+where
+    G: Graph,
+    W: EdgePropGet<G, T>,
+    T: Copy + Ord + Add<Output = T> + Zero,
+{
+    let n = g.num_vertices();
+    assert!(n >= 2, "graph with less than two vertices");
+    let vertices: Vec<_> = g.vertices().collect();
+    let mut pos = g.default_vertex_prop(0usize);
+    for (i, &v) in vertices.iter().enumerate() {
+        pos[v] = i;
+    }
+    // adj[i][j] is the sum of the weights of the edges between the merged vertices i and j
+    let mut adj = vec![vec![T::zero(); n]; n];
+    for (e, u, v) in g.edges_with_ends() {
+        let (i, j) = (pos[u], pos[v]);
+        if i != j {
+            adj[i][j] = adj[i][j] + w.get(e);
+            adj[j][i] = adj[i][j];
+        }
+    }
+    // merged[i] is the vertices of g merged into i
+    let mut merged: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+    let mut active: Vec<usize> = (0..n).collect();
+    let mut best: Option<(T, Vec<usize>)> = None;
+    while active.len() > 1 {
+        // maximum adjacency order, conn[k] is the connection of active[k] to the added vertices
+        let mut added = vec![false; active.len()];
+        let mut conn = vec![T::zero(); active.len()];
+        let mut prev = 0;
+        let mut last = 0;
+        for _ in 0..active.len() {
+            let next = (0..active.len())
+                .filter(|&k| !added[k])
+                .max_by(|&a, &b| conn[a].cmp(&conn[b]).then(b.cmp(&a)))
+                .unwrap();
+            added[next] = true;
+            prev = last;
+            last = next;
+            for k in 0..active.len() {
+                if !added[k] {
+                    conn[k] = conn[k] + adj[active[next]][active[k]];
+                }
+            }
+        }
+        let cut = conn[last];
+        if best.as_ref().map(|b| cut < b.0).unwrap_or(true) {
+            best = Some((cut, merged[active[last]].clone()));
+        }
+        // merge last into prev
+        let (s, t) = (active[prev], active[last]);
+        for &k in &active {
+            if k != s && k != t {
+                adj[s][k] = adj[s][k] + adj[t][k];
+                adj[k][s] = adj[s][k];
+            }
+        }
+        let vs = merged[t].split_off(0);
+        merged[s].extend(vs);
+        active.remove(last);
+    }
+    let (cut, mut side) = best.unwrap();
+    side.sort();
+    (cut, side.into_iter().map(|i| vertices[i]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algs::combine::disjoint_union;
+    use algs::Components;
+    use fera_fun::set;
+    use named::cycle_graph;
+    use rand::prelude::*;
+
+    fn is_cut(g: &StaticGraph, cut: &[Edge<StaticGraph>]) -> bool {
+        let cut = set(cut.iter().cloned());
+        let rest: Vec<_> = g
+            .edges_ends()
+            .zip(g.edges())
+            .filter(|&(_, e)| !cut.contains(&e))
+            .map(|((u, v), _)| (u as usize, v as usize))
+            .collect();
+        !StaticGraph::new_with_edges(g.num_vertices(), rest).is_connected()
+    }
+
+    #[test]
+    fn cycle() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let g = cycle_graph(8);
+        let (size, cut) = karger_min_cut(&g, &mut rng, 1);
+        assert_eq!(2, size);
+        assert!(is_cut(&g, &cut));
+    }
+
+    #[test]
+    fn complete_graphs_bridged() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let (g, offset) = disjoint_union(&CompleteGraph::new(5), &CompleteGraph::new(5));
+        let mut edges: Vec<_> = g.edges_ends().map(|(u, v)| (u as usize, v as usize)).collect();
+        edges.push((0, offset));
+        edges.push((1, offset + 1));
+        let g = StaticGraph::new_with_edges(g.num_vertices(), edges);
+        let (size, cut) = karger_min_cut(&g, &mut rng, 200);
+        assert_eq!(2, size);
+        assert!(is_cut(&g, &cut));
+        assert!(cut.iter().all(|&e| {
+            let (u, v) = g.ends(e);
+            (u as usize) < offset && (v as usize) >= offset
+                || (v as usize) < offset && (u as usize) >= offset
+        }));
+    }
+
+    fn cut_weight(g: &StaticGraph, w: &DefaultEdgePropMut<StaticGraph, u32>, side: &[u32]) -> u32 {
+        let side = set(side.iter().cloned());
+        g.edges()
+            .filter(|&e| {
+                let (u, v) = g.ends(e);
+                side.contains(&u) != side.contains(&v)
+            })
+            .map(|e| w[e])
+            .sum()
+    }
+
+    #[test]
+    fn global_min_cut_weighted() {
+        // the example of Stoer and Wagner's paper, with the vertices numbered from 0
+        let g: StaticGraph = graph!(
+            8,
+            (0, 1),
+            (0, 4),
+            (1, 2),
+            (1, 4),
+            (1, 5),
+            (2, 3),
+            (2, 6),
+            (3, 6),
+            (3, 7),
+            (4, 5),
+            (5, 6),
+            (6, 7)
+        );
+        let mut w = g.default_edge_prop(0u32);
+        for (e, &x) in g.edges().zip(&[2, 3, 3, 2, 2, 4, 2, 2, 2, 3, 1, 3]) {
+            w[e] = x;
+        }
+        let (cut, side) = global_min_cut(&g, &w);
+        assert_eq!(4, cut);
+        assert_eq!(4, cut_weight(&g, &w, &side));
+        assert_eq!(set(vec![2, 3, 6, 7]), set(side.iter().cloned()));
+    }
+
+    #[test]
+    fn global_min_cut_disconnected() {
+        let (g, _) = disjoint_union(&cycle_graph(3), &cycle_graph(4));
+        let (cut, side) = global_min_cut(&g, g.default_edge_prop(1u32));
+        assert_eq!(0, cut);
+        assert!(side == vec![0, 1, 2] || side == vec![3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn global_min_cut_karger() {
+        let mut rng = SmallRng::seed_from_u64(11);
+        for n in 2..9 {
+            for _ in 0..10 {
+                let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+                let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+                let w = g.default_edge_prop(1u32);
+                let (cut, side) = global_min_cut(&g, &w);
+                assert!(!side.is_empty() && side.len() < n);
+                assert_eq!(cut, cut_weight(&g, &w, &side));
+                assert_eq!(karger_min_cut(&g, &mut rng, 200).0, cut as usize);
+            }
+        }
+    }
+
+    #[test]
+    fn disconnected() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        let (g, _) = disjoint_union(&cycle_graph(3), &cycle_graph(4));
+        assert_eq!((0, vec![]), karger_min_cut(&g, &mut rng, 10));
+    }
+}
@@ -27,6 +27,42 @@ pub trait Cycles: Incidence {
         dag
     }
 
+    /// Returns the vertices of the graph in a topological order, that is, for each edge `(u, v)`,
+    /// `u` comes before `v`, or `None` if the graph is not a DAG.
+    ///
+    /// The running time is O(V + E).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Cycles;
+    ///
+    /// # fn main() {
+    /// let g: StaticDigraph = graph!(4, (2, 1), (1, 0), (2, 3), (3, 0));
+    /// assert_eq!(Some(vec![2, 3, 1, 0]), g.topological_sort());
+    ///
+    /// let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (2, 0));
+    /// assert_eq!(None, g.topological_sort());
+    /// # }
+    /// ```
+    fn topological_sort(&self) -> Option<Vec<Vertex<Self>>>
+    where
+        Self: VertexList + WithVertexProp<Color>,
+    {
+        let mut dag = true;
+        let mut order = Vec::with_capacity(self.num_vertices());
+        self.dfs((IsDag(&mut dag), OnFinishVertex(|v| order.push(v))))
+            .run();
+        if dag {
+            order.reverse();
+            Some(order)
+        } else {
+            None
+        }
+    }
+
     fn is_cycle_graph(&self) -> bool
     where
         Self: VertexList + EdgeList + WithVertexProp<Color>,
@@ -103,4 +139,21 @@ mod tests {
         let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (1, 3));
         assert!(!g.is_cycle_graph());
     }
+
+    #[test]
+    fn topological_sort() {
+        let g: StaticDigraph = graph!(6, (5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1));
+        let order = g.topological_sort().unwrap();
+        assert_eq!(6, order.len());
+        let mut pos = g.default_vertex_prop(0);
+        for (i, &v) in order.iter().enumerate() {
+            pos[v] = i;
+        }
+        assert!(g.edges_ends().all(|(u, v)| pos[u] < pos[v]));
+
+        let g: StaticDigraph = graph!(3, (0, 1), (1, 1));
+        assert_eq!(None, g.topological_sort());
+        let g: StaticGraph = graph!(2, (0, 1));
+        assert_eq!(None, g.topological_sort());
+    }
 }
@@ -7,6 +7,10 @@
 use params::IntoOwned;
 use prelude::*;
 
+use num_traits::Zero;
+
+use std::ops::Add;
+
 pub trait Degrees: Adjacency {
     fn degree_spanning_subgraph<I>(&self, edges: I) -> DefaultVertexPropMut<Self, u32>
     where
@@ -33,6 +37,52 @@ pub trait Degrees: Adjacency {
         }
     }
 
+    /// Returns the weighted degree (also known as strength) of `v`, that is, the sum of the
+    /// weights of the out edges of `v`.
+    ///
+    /// The edges are the ones returned by `out_edges`, so the weighted degree is consistent with
+    /// `out_degree`. For example, in an undirected `StaticGraph` a loop is counted twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Degrees;
+    ///
+    /// # fn main() {
+    /// let (g, w): (StaticGraph, _) = graph!(3, (0, 1) -> 2u32, (0, 2) -> 5, (2, 2) -> 1);
+    /// assert_eq!(7, g.weighted_degree(0, &w));
+    /// assert_eq!(2, g.weighted_degree(1, &w));
+    /// assert_eq!(7, g.weighted_degree(2, &w));
+    /// # }
+    /// ```
+    fn weighted_degree<W, T>(&self, v: Vertex<Self>, w: W) -> T
+    where
+        Self: Incidence,
+        W: EdgePropGet<Self, T>,
+        T: Add<Output = T> + Zero,
+    {
+        self.out_edges(v).fold(T::zero(), |acc, e| acc + w.get(e))
+    }
+
+    /// Returns a vertex property with the weighted degree of each vertex. See
+    /// [`weighted_degree`].
+    ///
+    /// [`weighted_degree`]: #method.weighted_degree
+    fn weighted_degrees<W, T>(&self, w: W) -> DefaultVertexPropMut<Self, T>
+    where
+        Self: Incidence + VertexList + WithVertexProp<T>,
+        W: EdgePropGet<Self, T>,
+        T: Clone + Add<Output = T> + Zero,
+    {
+        let mut degrees = self.default_vertex_prop(T::zero());
+        for v in self.vertices() {
+            degrees[v] = self.weighted_degree(v, &w);
+        }
+        degrees
+    }
+
     fn is_k_regular(&self, k: usize) -> bool
     where
         Self: WithEdge<Kind = Undirected> + VertexList,
@@ -68,6 +118,52 @@ pub trait Degrees: Adjacency {
         self.vertices().map(|v| self.out_degree(v)).min()
     }
 
+    /// Returns the degree assortativity coefficient of the graph, that is, the Pearson
+    /// correlation coefficient of the degrees of the ends of the edges. Each edge `(u, v)` is
+    /// considered in both directions, and loops are ignored (but they are counted in the degrees,
+    /// which are the ones returned by `out_degree`).
+    ///
+    /// A positive value indicates that vertices with high degree tend to be adjacent to vertices
+    /// with high degree, and a negative value indicates that vertices with high degree tend to be
+    /// adjacent to vertices with low degree. If the variance of the degrees is zero (for example,
+    /// in a regular graph or in a graph without edges) the coefficient is undefined and `NaN` is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Degrees;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (0, 2), (0, 3));
+    /// assert_eq!(-1.0, g.degree_assortativity());
+    /// let g: StaticGraph = graph!(3, (0, 1), (1, 2), (2, 0));
+    /// assert!(g.degree_assortativity().is_nan());
+    /// # }
+    /// ```
+    fn degree_assortativity(&self) -> f64
+    where
+        Self: Graph,
+    {
+        let (mut m, mut sum, mut sum_sq, mut sum_prod) = (0.0, 0.0, 0.0, 0.0);
+        for (u, v) in self.edges_ends().filter(|&(u, v)| u != v) {
+            let (x, y) = (self.out_degree(u) as f64, self.out_degree(v) as f64);
+            m += 1.0;
+            sum += x + y;
+            sum_sq += x * x + y * y;
+            sum_prod += x * y;
+        }
+        let mean = sum / (2.0 * m);
+        let cov = sum_prod / m - mean * mean;
+        let var = sum_sq / (2.0 * m) - mean * mean;
+        if var.abs() < 1e-12 {
+            return f64::NAN;
+        }
+        cov / var
+    }
+
     fn is_isolated(&self, v: Vertex<Self>) -> bool
     where
         Self: WithEdge<Kind = Undirected>,
@@ -84,3 +180,45 @@ pub trait Degrees: Adjacency {
 }
 
 impl<G: Adjacency> Degrees for G {}
+
+#[cfg(test)]
+mod tests {
+    use super::Degrees;
+    use fera_fun::vec;
+    use named::star_graph;
+    use prelude::*;
+
+    #[test]
+    fn weighted_degrees() {
+        let g = star_graph(4);
+        let mut w = g.default_edge_prop(0u32);
+        for (i, e) in g.edges().enumerate() {
+            w[e] = i as u32 + 1;
+        }
+        let center = g.vertices().next().unwrap();
+        assert_eq!(10, g.weighted_degree(center, &w));
+        let degrees = g.weighted_degrees(&w);
+        assert_eq!(vec![10, 1, 2, 3, 4], vec(g.vertices().map(|v| degrees[v])));
+
+        let (g, w): (StaticDigraph, _) = graph!(3, (0, 1) -> 2u32, (1, 0) -> 3, (1, 1) -> 4);
+        let degrees = g.weighted_degrees(&w);
+        assert_eq!(vec![2, 7, 0], vec(g.vertices().map(|v| degrees[v])));
+    }
+
+    #[test]
+    fn degree_assortativity() {
+        let g = star_graph(5);
+        assert!((g.degree_assortativity() + 1.0).abs() < 1e-9);
+
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (3, 0), (0, 0));
+        let r = g.degree_assortativity();
+        assert!(r < 0.0);
+        // 0 has degree 4 (the loop is counted twice)
+        let expected = -1.0 / 3.0;
+        assert!((r - expected).abs() < 1e-9, "{}", r);
+
+        let g: StaticGraph = graph!(3);
+        assert!(g.degree_assortativity().is_nan());
+        assert!(CompleteGraph::new(5).degree_assortativity().is_nan());
+    }
+}
@@ -2,26 +2,536 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use fun::max_prop;
+use algs::centrality::bfs_distances;
+use algs::Centrality;
 use prelude::*;
-use props::{Color, FnProp};
+use props::Color;
 use traverse::*;
 
+use num_traits::Zero;
+
+use std::ops::{Add, Index};
+
 pub trait Distances: Incidence {
+    /// Returns the radius of the graph, that is, the minimum eccentricity of the vertices. See
+    /// [`Centrality::eccentricity`].
+    ///
+    /// [`Centrality::eccentricity`]: ../centrality/trait.Centrality.html#method.eccentricity
+    fn radius(&self) -> usize
+    where
+        Self: VertexList + WithVertexProp<usize>,
+    {
+        let ecc = self.eccentricity();
+        self.vertices().map(|v| ecc[v]).min().unwrap_or(0)
+    }
+
+    /// Returns the diameter of the graph, that is, the maximum eccentricity of the vertices. See
+    /// [`Centrality::eccentricity`].
+    ///
+    /// [`Centrality::eccentricity`]: ../centrality/trait.Centrality.html#method.eccentricity
     fn diameter(&self) -> usize
     where
         Self: VertexList + WithVertexProp<usize> + WithVertexProp<Color>,
     {
-        let mut dist = self.default_vertex_prop(0);
-        self.vertices()
-            .map(|v| {
-                dist.set_values(self.vertices(), usize::max_value());
-                self.dfs(RecordDistance(&mut dist)).root(v).run();
-                max_prop(FnProp(|x| dist[x]), self.vertices()).unwrap()
-            })
-            .max()
-            .unwrap_or(0)
+        let ecc = self.eccentricity();
+        self.vertices().map(|v| ecc[v]).max().unwrap_or(0)
+    }
+
+    /// Returns the center of the graph, that is, the vertices with eccentricity equal to the
+    /// radius. See [`Centrality::eccentricity`].
+    ///
+    /// The eccentricity of a vertex considers only the vertices reachable from it, so in a
+    /// disconnected graph it is computed within the vertex's component and the center may contain
+    /// vertices of a single component (an isolated vertex has eccentricity zero, so it is in the
+    /// center).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Distances;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+    /// assert_eq!(vec![1, 2], g.center());
+    /// assert_eq!(vec![0, 3], g.periphery());
+    /// # }
+    /// ```
+    ///
+    /// [`Centrality::eccentricity`]: ../centrality/trait.Centrality.html#method.eccentricity
+    fn center(&self) -> Vec<Vertex<Self>>
+    where
+        Self: VertexList + WithVertexProp<usize>,
+    {
+        let ecc = self.eccentricity();
+        let radius = self.vertices().map(|v| ecc[v]).min().unwrap_or(0);
+        self.vertices().filter(|&v| ecc[v] == radius).collect()
+    }
+
+    /// Returns the periphery of the graph, that is, the vertices with eccentricity equal to the
+    /// diameter. Like in [`center`], the eccentricity of a vertex is computed within its
+    /// component.
+    ///
+    /// [`center`]: #method.center
+    fn periphery(&self) -> Vec<Vertex<Self>>
+    where
+        Self: VertexList + WithVertexProp<usize>,
+    {
+        let ecc = self.eccentricity();
+        let diameter = self.vertices().map(|v| ecc[v]).max().unwrap_or(0);
+        self.vertices().filter(|&v| ecc[v] == diameter).collect()
+    }
+
+    /// Finds the shortest path distances between every pair of vertices using the Floyd-Warshall
+    /// algorithm. Edge weights can be negative, but the distances are meaningless if the graph has
+    /// a negative cycle (in this case `dist(v, v)` is negative for some vertex `v`) and
+    /// [`AllPairsDist::path`] may return `None` for reachable vertices. Note that in an undirected
+    /// graph each negative edge forms a negative cycle.
+    ///
+    /// The running time is O(V^3) and the memory used is O(V^2).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Distances;
+    /// use fera_graph::props::FnProp;
+    ///
+    /// let g = CompleteGraph::new(4);
+    /// let w = FnProp(|e| {
+    ///     let (u, v) = g.ends(e);
+    ///     if u + 1 == v || v + 1 == u { 1 } else { 5 }
+    /// });
+    /// let d = g.floyd_warshall(w);
+    /// assert_eq!(Some(3), d.dist(0, 3));
+    /// assert_eq!(Some(vec![0, 1, 2, 3]), d.path(0, 3));
+    /// ```
+    ///
+    /// [`AllPairsDist::path`]: struct.AllPairsDist.html#method.path
+    fn floyd_warshall<P, W>(&self, w: P) -> AllPairsDist<Self, W>
+    where
+        Self: VertexList + WithVertexIndexProp,
+        P: EdgePropGet<Self, W>,
+        W: Clone + Ord + Add<Output = W> + Zero,
+    {
+        let mut apd = AllPairsDist::new(self);
+        let n = apd.n;
+        for u in self.vertices() {
+            apd.set(u, u, W::zero(), u);
+            for e in self.out_edges(u) {
+                let v = self.target(e);
+                let d = w.get(e);
+                if apd[(u, v)].as_ref().map(|dv| d < *dv).unwrap_or(true) {
+                    apd.set(u, v, d, v);
+                }
+            }
+        }
+
+        for k in 0..n {
+            for i in 0..n {
+                let dik = match apd.dist[i * n + k] {
+                    Some(ref d) => d.clone(),
+                    None => continue,
+                };
+                for j in 0..n {
+                    let d = match apd.dist[k * n + j] {
+                        Some(ref dkj) => dik.clone() + dkj.clone(),
+                        None => continue,
+                    };
+                    let ij = i * n + j;
+                    if apd.dist[ij].as_ref().map(|dij| d < *dij).unwrap_or(true) {
+                        apd.dist[ij] = Some(d);
+                        apd.next[ij] = apd.next[i * n + k];
+                    }
+                }
+            }
+        }
+
+        apd
+    }
+
+    /// Finds the number of edges in a shortest path between every pair of vertices by running a
+    /// breadth-first search from each vertex.
+    ///
+    /// The running time is O(V(V + E)) and the memory used is O(V^2).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Distances;
+    ///
+    /// # fn main() {
+    /// let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (0, 2), (2, 3));
+    /// let d = g.all_pairs_bfs();
+    /// assert_eq!(Some(2), d.dist(0, 3));
+    /// assert_eq!(Some(vec![0, 2, 3]), d.path(0, 3));
+    /// assert_eq!(None, d.dist(3, 0));
+    /// # }
+    /// ```
+    fn all_pairs_bfs(&self) -> AllPairsDist<Self, usize>
+    where
+        Self: VertexList + WithVertexIndexProp + WithVertexProp<Color>,
+    {
+        let mut apd = AllPairsDist::new(self);
+        for u in self.vertices() {
+            apd.set(u, u, 0, u);
+            self.bfs(OnDiscoverTreeEdge(|e| {
+                let (p, v) = self.ends(e);
+                let (d, next) = if p == u {
+                    (1, v)
+                } else {
+                    let i = apd.pos(u, p);
+                    (apd.dist[i].unwrap() + 1, apd.next[i].into_option().unwrap())
+                };
+                apd.set(u, v, d, next);
+            }))
+            .root(u)
+            .run();
+        }
+        apd
+    }
+
+    /// Returns the vertices reachable from `source` grouped by their distance (number of edges)
+    /// from `source`, that is, the position `d` of the returned vector contains the vertices at
+    /// distance `d` from `source`, in the order they are discovered by a breadth-first search.
+    /// The first layer contains only `source` and the unreachable vertices are omitted.
+    ///
+    /// The running time is O(V + E).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Distances;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(6, (0, 1), (0, 2), (1, 3), (2, 3), (3, 4));
+    /// assert_eq!(vec![vec![0], vec![1, 2], vec![3], vec![4]], g.bfs_layers(0));
+    /// assert_eq!(vec![vec![5]], g.bfs_layers(5));
+    /// # }
+    /// ```
+    fn bfs_layers(&self, source: Vertex<Self>) -> Vec<Vec<Vertex<Self>>>
+    where
+        Self: VertexList + WithVertexProp<usize>,
+    {
+        let mut dist = self.default_vertex_prop(usize::max_value());
+        let mut layers: Vec<Vec<_>> = vec![];
+        // the vertices are produced in non-decreasing order of distance
+        bfs_distances(self, source, &mut dist, |v, d| {
+            if d == layers.len() {
+                layers.push(vec![]);
+            }
+            layers[d].push(v);
+        });
+        layers
     }
 }
 
 impl<G: Incidence> Distances for G {}
+
+/// The distances (and the shortest paths) between every pair of vertices of a graph.
+///
+/// This is created by [`Distances::floyd_warshall`] and [`Distances::all_pairs_bfs`]. The
+/// distance from `u` to `v` can be obtained with `dist(u, v)` or by indexing with `(u, v)`, which
+/// is `None` if `v` is not reachable from `u`.
+///
+/// It is also used by [`all_pairs_neighbor_similarity`] to store a similarity matrix.
+///
+/// [`Distances::floyd_warshall`]: trait.Distances.html#method.floyd_warshall
+/// [`Distances::all_pairs_bfs`]: trait.Distances.html#method.all_pairs_bfs
+/// [`all_pairs_neighbor_similarity`]: ../neighbors/fn.all_pairs_neighbor_similarity.html
+pub struct AllPairsDist<G, W>
+where
+    G: WithVertexIndexProp,
+{
+    index: VertexIndexProp<G>,
+    n: usize,
+    dist: Vec<Option<W>>,
+    // next[u][v] is the vertex after u in a shortest path from u to v
+    next: Vec<OptionVertex<G>>,
+}
+
+impl<G, W> AllPairsDist<G, W>
+where
+    G: VertexList + WithVertexIndexProp,
+{
+    pub(crate) fn new(g: &G) -> Self {
+        let n = g.num_vertices();
+        AllPairsDist {
+            index: g.vertex_index(),
+            n,
+            dist: (0..n * n).map(|_| None).collect(),
+            next: vec![G::vertex_none(); n * n],
+        }
+    }
+}
+
+impl<G, W> AllPairsDist<G, W>
+where
+    G: WithVertexIndexProp,
+{
+    fn pos(&self, u: Vertex<G>, v: Vertex<G>) -> usize {
+        self.index.get(u) * self.n + self.index.get(v)
+    }
+
+    pub(crate) fn set(&mut self, u: Vertex<G>, v: Vertex<G>, d: W, next: Vertex<G>) {
+        let i = self.pos(u, v);
+        self.dist[i] = Some(d);
+        self.next[i] = G::vertex_some(next);
+    }
+
+    /// Returns the length of a shortest path from `u` to `v` or `None` if `v` is not reachable
+    /// from `u`.
+    pub fn dist(&self, u: Vertex<G>, v: Vertex<G>) -> Option<W>
+    where
+        W: Clone,
+    {
+        self[(u, v)].clone()
+    }
+
+    /// Returns the vertices of a shortest path from `u` to `v` (including `u` and `v`) or `None`
+    /// if `v` is not reachable from `u`. If the distances were computed in a graph with a
+    /// negative cycle, the walk from `u` to `v` may go around the cycle, in this case `None` is
+    /// returned.
+    pub fn path(&self, u: Vertex<G>, v: Vertex<G>) -> Option<Vec<Vertex<G>>> {
+        self[(u, v)].as_ref()?;
+        let mut path = vec![u];
+        let mut cur = u;
+        while cur != v {
+            // a shortest path has at most n vertices, a longer walk repeats a negative cycle
+            if path.len() == self.n {
+                return None;
+            }
+            cur = self.next[self.pos(cur, v)].into_option()?;
+            path.push(cur);
+        }
+        Some(path)
+    }
+}
+
+impl<G, W> Index<(Vertex<G>, Vertex<G>)> for AllPairsDist<G, W>
+where
+    G: WithVertexIndexProp,
+{
+    type Output = Option<W>;
+
+    fn index(&self, (u, v): (Vertex<G>, Vertex<G>)) -> &Option<W> {
+        &self.dist[self.pos(u, v)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Distances;
+    use algs::Paths;
+    use fera_fun::vec;
+    use prelude::*;
+    use props::FnProp;
+    use rand::prelude::*;
+
+    use std::cmp::{max, min};
+
+    #[test]
+    fn radius_and_diameter() {
+        let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 4));
+        assert_eq!(2, g.radius());
+        assert_eq!(4, g.diameter());
+        let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 4), (4, 0));
+        assert_eq!(2, g.radius());
+        assert_eq!(2, g.diameter());
+        let g: StaticGraph = graph!(0);
+        assert_eq!(0, g.radius());
+        assert_eq!(0, g.diameter());
+    }
+
+    #[test]
+    fn center_and_periphery() {
+        let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 4));
+        assert_eq!(vec![2], g.center());
+        assert_eq!(vec![0, 4], g.periphery());
+        let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 3), (3, 4), (4, 5));
+        assert_eq!(vec![2, 3], g.center());
+        assert_eq!(vec![0, 5], g.periphery());
+        let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 4), (4, 0));
+        assert_eq!(vec![0, 1, 2, 3, 4], g.center());
+        assert_eq!(vec![0, 1, 2, 3, 4], g.periphery());
+        let g: StaticGraph = graph!(0);
+        assert!(g.center().is_empty());
+        assert!(g.periphery().is_empty());
+    }
+
+    #[test]
+    fn center_and_periphery_disconnected() {
+        // the eccentricity is computed within each component
+        let g: StaticGraph = graph!(6, (0, 1), (1, 2), (3, 4));
+        assert_eq!(vec![5], g.center());
+        assert_eq!(vec![0, 2], g.periphery());
+    }
+
+    #[test]
+    fn bfs_layers() {
+        // a complete ternary tree with 4 levels
+        let n = 1 + 3 + 9 + 27;
+        let g = StaticGraph::new_with_edges(n, (1..n).map(|v| ((v - 1) / 3, v)));
+        let layers = g.bfs_layers(0);
+        assert_eq!(vec![1, 3, 9, 27], vec(layers.iter().map(Vec::len)));
+        for (d, layer) in layers.iter().enumerate() {
+            let first = (3usize.pow(d as u32) - 1) / 2;
+            assert!(layer.iter().all(|&v| first <= v as usize && (v as usize) < 3 * first + 1));
+        }
+        // from a leaf
+        let layers = g.bfs_layers(n as u32 - 1);
+        assert_eq!(vec![1, 1, 3, 3, 8, 6, 18], vec(layers.iter().map(Vec::len)));
+    }
+
+    #[test]
+    fn bfs_layers_unreachable() {
+        let g: StaticDigraph = graph!(5, (0, 1), (1, 2), (3, 1), (2, 0));
+        assert_eq!(vec![vec![0], vec![1], vec![2]], g.bfs_layers(0));
+        assert_eq!(vec![vec![3], vec![1], vec![2], vec![0]], g.bfs_layers(3));
+        assert_eq!(vec![vec![4]], g.bfs_layers(4));
+    }
+
+    #[test]
+    fn floyd_warshall() {
+        let g: StaticDigraph = graph!(5, (0, 1), (0, 2), (1, 3), (2, 1), (3, 2));
+        let e = vec(g.edges());
+        let mut w = g.default_edge_prop(0i32);
+        w[e[0]] = 4;
+        w[e[1]] = 5;
+        w[e[2]] = 3;
+        w[e[3]] = -3;
+        w[e[4]] = 2;
+        let d = g.floyd_warshall(&w);
+        assert_eq!(Some(0), d.dist(0, 0));
+        assert_eq!(Some(2), d[(0, 1)]);
+        assert_eq!(Some(-1), d.dist(3, 1));
+        assert_eq!(None, d.dist(1, 0));
+        assert_eq!(None, d.dist(0, 4));
+        assert_eq!(Some(vec![0, 2, 1, 3]), d.path(0, 3));
+        assert_eq!(Some(vec![4]), d.path(4, 4));
+        assert_eq!(None, d.path(4, 0));
+    }
+
+    #[test]
+    fn floyd_warshall_negative_cycle() {
+        // 1 -> 2 -> 1 is a negative cycle
+        let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (2, 1), (2, 3));
+        let e = vec(g.edges());
+        let mut w = g.default_edge_prop(1i32);
+        w[e[2]] = -3;
+        let d = g.floyd_warshall(&w);
+        assert!(d.dist(1, 1).unwrap() < 0);
+        assert_eq!(None, d.path(0, 3));
+        assert_eq!(Some(vec![3]), d.path(3, 3));
+
+        // in an undirected graph a negative edge is a negative cycle
+        let g: StaticGraph = graph!(3, (0, 1), (1, 2));
+        let e = vec(g.edges());
+        let mut w = g.default_edge_prop(1i32);
+        w[e[0]] = -1;
+        let d = g.floyd_warshall(&w);
+        assert_eq!(None, d.path(0, 2));
+    }
+
+    fn random_weights<G>(g: &G, rng: &mut SmallRng) -> DefaultEdgePropMut<G, u32>
+    where
+        G: EdgeList + BasicEdgeProps,
+    {
+        let mut w = g.default_edge_prop(0u32);
+        for e in g.edges() {
+            w[e] = rng.gen_range(0, 100u32);
+        }
+        w
+    }
+
+    fn check_floyd_warshall<G, P>(g: &G, w: P)
+    where
+        G: VertexList + EdgeList + Incidence + BasicProps + WithVertexIndexProp,
+        G: WithVertexProp<Option<u32>> + WithVertexProp<OptionEdge<G>>,
+        P: EdgePropGet<G, u32> + Copy,
+    {
+        let d = g.floyd_warshall(w);
+        for u in g.vertices() {
+            let (dist, _) = g.bellman_ford(w, u).unwrap();
+            for v in g.vertices() {
+                assert_eq!(dist[v], d.dist(u, v));
+                match d.path(u, v) {
+                    Some(path) => {
+                        assert_eq!(u, path[0]);
+                        assert_eq!(v, *path.last().unwrap());
+                        let len = path
+                            .windows(2)
+                            .map(|p| {
+                                g.out_edges(p[0])
+                                    .filter(|&e| g.target(e) == p[1])
+                                    .map(|e| w.get(e))
+                                    .min()
+                                    .unwrap()
+                            })
+                            .sum::<u32>();
+                        assert_eq!(dist[v], Some(len));
+                    }
+                    None => assert_eq!(None, dist[v]),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn floyd_warshall_random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..15 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticDigraph::new_gnm(n, m, &mut rng).unwrap();
+            check_floyd_warshall(&g, &random_weights(&g, &mut rng));
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            check_floyd_warshall(&g, &random_weights(&g, &mut rng));
+        }
+        let g = CompleteGraph::new(10);
+        check_floyd_warshall(&g, &random_weights(&g, &mut rng));
+    }
+
+    #[test]
+    fn all_pairs_bfs_path() {
+        let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 3), (3, 4), (4, 5));
+        let d = g.all_pairs_bfs();
+        for u in g.vertices() {
+            for v in g.vertices() {
+                let diff = (max(u, v) - min(u, v)) as usize;
+                assert_eq!(Some(diff), d.dist(u, v));
+                assert_eq!(diff + 1, d.path(u, v).unwrap().len());
+            }
+        }
+    }
+
+    #[test]
+    fn all_pairs_bfs_random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..15 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticDigraph::new_gnm(n, m, &mut rng).unwrap();
+            let d = g.all_pairs_bfs();
+            let fw = g.floyd_warshall(FnProp(|_| 1));
+            for u in g.vertices() {
+                assert_eq!(Some(0), d[(u, u)]);
+                for v in g.vertices() {
+                    assert_eq!(fw.dist(u, v), d.dist(u, v));
+                    match d.path(u, v) {
+                        Some(path) => {
+                            assert_eq!(d.dist(u, v), Some(path.len() - 1));
+                            for p in path.windows(2) {
+                                assert!(g.get_edge_by_ends(p[0], p[1]).is_some());
+                            }
+                        }
+                        None => assert_eq!(None, d.dist(u, v)),
+                    }
+                }
+            }
+        }
+    }
+}
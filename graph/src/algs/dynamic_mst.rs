@@ -0,0 +1,253 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Minimum spanning forest maintenance under edge insertions.
+
+use algs::Kruskal;
+use prelude::*;
+use unionfind::{UnionFind, WithUnionFind};
+
+use num_traits::Zero;
+use std::ops::{Add, Sub};
+
+/// A minimum spanning forest that is kept up to date while new edges are inserted.
+///
+/// The forest is stored as a parent-pointer forest (each vertex points to its parent and keeps
+/// the weight of the edge to it) and a [`UnionFind`] is used to decide if two vertices are in
+/// the same tree. Inserting an edge takes O(V) time in the worst case, since the tree path
+/// between the ends is found by walking the parent pointers.
+///
+/// Only the ends and the weights of the edges are kept, so the inserted edges do not need to
+/// exist in the graph.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::dynamic_mst::DynamicMst;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+/// let mut w = g.default_edge_prop(0u32);
+/// for (e, &x) in g.edges().zip(&[2, 3, 4]) {
+///     w[e] = x;
+/// }
+/// let mut mst = DynamicMst::new(&g, &w);
+/// assert_eq!(9, mst.total_weight());
+/// // (0, 3) closes the cycle 0 1 2 3 and replaces (2, 3)
+/// assert!(mst.insert_edge(0, 3, 1));
+/// assert_eq!(6, mst.total_weight());
+/// // (0, 2) closes the cycle 0 1 2 but is not cheaper than any of its edges
+/// assert!(!mst.insert_edge(0, 2, 3));
+/// assert_eq!(6, mst.total_weight());
+/// # }
+/// ```
+///
+/// [`UnionFind`]: ../../unionfind/struct.UnionFind.html
+pub struct DynamicMst<G, T>
+where
+    G: Graph + WithVertexProp<T>,
+{
+    vertices: Vec<Vertex<G>>,
+    ds: UnionFind<G>,
+    parent: DefaultVertexPropMut<G, OptionVertex<G>>,
+    // weight[v] is the weight of the edge (v, parent[v])
+    weight: DefaultVertexPropMut<G, T>,
+    // used to mark the ancestors of a vertex while searching a tree path
+    stamp: DefaultVertexPropMut<G, usize>,
+    cur_stamp: usize,
+    total: T,
+}
+
+impl<G, T> DynamicMst<G, T>
+where
+    G: Graph + WithVertexProp<T>,
+    T: Copy + Ord + Zero + Add<Output = T> + Sub<Output = T>,
+{
+    /// Creates a new `DynamicMst` starting with a minimum spanning forest of `g` using the edge
+    /// weights `w`.
+    ///
+    /// # Panics
+    ///
+    /// If `g` has no vertices.
+    pub fn new<W>(g: &G, w: W) -> Self
+    where
+        W: EdgePropGet<G, T>,
+    {
+        let mut mst = DynamicMst {
+            vertices: g.vertices().collect(),
+            ds: g.new_unionfind(),
+            parent: g.default_vertex_prop(G::vertex_none()),
+            weight: g.default_vertex_prop(T::zero()),
+            stamp: g.default_vertex_prop(0usize),
+            cur_stamp: 0,
+            total: T::zero(),
+        };
+        for e in g.kruskal_mst(&w) {
+            let (u, v) = g.ends(e);
+            mst.ds.union(u, v);
+            mst.link(u, v, w.get(e));
+        }
+        mst
+    }
+
+    /// Inserts the edge `(u, v)` with weight `w`. If `u` and `v` are in different trees, the
+    /// edge links the two trees. Otherwise the edge replaces the maximum weight edge of the tree
+    /// path between `u` and `v` if it is cheaper than that edge.
+    ///
+    /// Returns `true` if the edge was added to the forest, `false` otherwise.
+    pub fn insert_edge(&mut self, u: Vertex<G>, v: Vertex<G>, w: T) -> bool {
+        if u == v {
+            return false;
+        }
+        if !self.ds.in_same_set(u, v) {
+            self.ds.union(u, v);
+            self.link(u, v, w);
+            return true;
+        }
+        let x = self.max_on_path(u, v);
+        if w < self.weight[x] {
+            self.total = self.total - self.weight[x];
+            self.parent[x] = G::vertex_none();
+            self.weight[x] = T::zero();
+            self.link(u, v, w);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the sum of the weights of the forest edges.
+    pub fn total_weight(&self) -> T {
+        self.total
+    }
+
+    /// Returns the edges of the forest as `(u, v, w)` triples, where `w` is the weight of the
+    /// edge `(u, v)`.
+    pub fn tree_edges(&self) -> Vec<(Vertex<G>, Vertex<G>, T)> {
+        self.vertices
+            .iter()
+            .filter_map(|&v| self.parent[v].into_option().map(|p| (v, p, self.weight[v])))
+            .collect()
+    }
+
+    // Adds the edge (u, v) to the forest, u and v must be in different trees (the union-find
+    // is not updated).
+    fn link(&mut self, u: Vertex<G>, v: Vertex<G>, w: T) {
+        self.reroot(u);
+        self.parent[u] = G::vertex_some(v);
+        self.weight[u] = w;
+        self.total = self.total + w;
+    }
+
+    // Makes u the root of its tree by reversing the parent pointers on the path to the root.
+    fn reroot(&mut self, u: Vertex<G>) {
+        let mut prev = G::vertex_none();
+        let mut prev_w = T::zero();
+        let mut cur = G::vertex_some(u);
+        while let Some(x) = cur.into_option() {
+            cur = self.parent[x];
+            let w = self.weight[x];
+            self.parent[x] = prev;
+            self.weight[x] = prev_w;
+            prev = G::vertex_some(x);
+            prev_w = w;
+        }
+    }
+
+    // Returns the vertex x such that (x, parent[x]) is the maximum weight edge on the tree path
+    // between u and v, u and v must be distinct vertices of the same tree.
+    fn max_on_path(&mut self, u: Vertex<G>, v: Vertex<G>) -> Vertex<G> {
+        self.cur_stamp += 1;
+        let mut x = u;
+        loop {
+            self.stamp[x] = self.cur_stamp;
+            match self.parent[x].into_option() {
+                Some(p) => x = p,
+                None => break,
+            }
+        }
+        let mut lca = v;
+        while self.stamp[lca] != self.cur_stamp {
+            lca = self.parent[lca].into_option().unwrap();
+        }
+        let mut max = None;
+        for &start in &[u, v] {
+            let mut x = start;
+            while x != lca {
+                if max.into_iter().all(|m| self.weight[x] > self.weight[m]) {
+                    max = Some(x);
+                }
+                x = self.parent[x].into_option().unwrap();
+            }
+        }
+        max.unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::vec;
+    use fun::sum_prop;
+    use rand::prelude::*;
+
+    #[test]
+    fn cheaper_chord() {
+        // a cycle with 5 vertices, the edge (4, 0) is left out of the forest
+        let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 4), (4, 0));
+        let mut w = g.default_edge_prop(0u32);
+        for (e, &x) in g.edges().zip(&[1, 5, 2, 3, 6]) {
+            w[e] = x;
+        }
+        let mut mst = DynamicMst::new(&g, &w);
+        assert_eq!(11, mst.total_weight());
+        assert_eq!(4, mst.tree_edges().len());
+        assert!(!mst.insert_edge(2, 2, 0));
+        assert!(!mst.insert_edge(0, 2, 5));
+        assert_eq!(11, mst.total_weight());
+        // the path between 0 and 2 is 0 1 2, so (1, 2) is replaced
+        assert!(mst.insert_edge(0, 2, 4));
+        assert_eq!(10, mst.total_weight());
+        // the path between 1 and 4 is 1 0 2 3 4, so (0, 2) is replaced
+        assert!(mst.insert_edge(1, 4, 1));
+        assert_eq!(7, mst.total_weight());
+        let mut edges = vec(mst
+            .tree_edges()
+            .into_iter()
+            .map(|(u, v, w)| (u.min(v), u.max(v), w)));
+        edges.sort();
+        assert_eq!(vec![(0, 1, 1), (1, 4, 1), (2, 3, 2), (3, 4, 3)], edges);
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..15 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            let w = g.default_edge_prop_from_fn(|_| rng.gen_range(0, 10u32));
+            let mut mst = DynamicMst::new(&g, &w);
+            let mut edges = vec(g.edges_ends());
+            let mut weights = vec(g.edges().map(|e| w[e]));
+            for _ in 0..20 {
+                let u = rng.gen_range(0, n as u32);
+                let v = rng.gen_range(0, n as u32);
+                let x = rng.gen_range(0, 10u32);
+                mst.insert_edge(u, v, x);
+                edges.push((u, v));
+                weights.push(x);
+                let h = StaticGraph::new_with_edges(
+                    n,
+                    edges.iter().map(|&(u, v)| (u as usize, v as usize)),
+                );
+                let hw = h.default_edge_prop_from_fn(|e| weights[h.edge_index().get(e)]);
+                let tree = vec(h.kruskal_mst(&hw));
+                assert_eq!(sum_prop::<_, _, u32, _>(&hw, &tree), mst.total_weight());
+                assert_eq!(tree.len(), mst.tree_edges().len());
+            }
+        }
+    }
+}
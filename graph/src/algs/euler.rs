@@ -0,0 +1,342 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Eulerian circuits and the Chinese postman problem.
+
+use algs::matching::min_weight_perfect_matching;
+use algs::Paths;
+use graphs::complete::CVertex;
+use prelude::*;
+use props::FnProp;
+
+use num_traits::{One, Zero};
+
+use std::ops::{Add, Div, Sub};
+
+/// Returns an Eulerian circuit of `g`, that is, a closed walk that traverses each edge exactly
+/// once, or `None` if `g` has no Eulerian circuit. Each edge in the walk is oriented in the
+/// walk direction (see [`WithEdge::reverse`]).
+///
+/// An undirected graph has an Eulerian circuit if and only if all vertices have even degree and
+/// all edges are in the same connected component. If `g` has no edges, the circuit is empty.
+///
+/// The running time is O(V + E).
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::Paths;
+/// use fera_graph::algs::euler::eulerian_circuit;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2));
+/// let walk = eulerian_circuit(&g).unwrap();
+/// assert_eq!(6, walk.len());
+/// assert!(g.is_walk(&walk));
+/// assert_eq!(g.source(walk[0]), g.target(walk[5]));
+///
+/// let g: StaticGraph = graph!(3, (0, 1), (1, 2));
+/// assert_eq!(None, eulerian_circuit(&g));
+/// # }
+/// ```
+///
+/// [`WithEdge::reverse`]: ../../graphs/trait.WithEdge.html#method.reverse
+pub fn eulerian_circuit<G>(g: &G) -> Option<Vec<Edge<G>>>
+where
+    G: IncidenceGraph<Kind = Undirected> + WithVertexIndexProp,
+{
+    if g.vertices().any(|v| g.out_degree(v) % 2 == 1) {
+        return None;
+    }
+    circuit(g, g.default_edge_prop(1usize))
+}
+
+/// Returns an Eulerian circuit of the digraph `g`, that is, a closed walk that traverses each edge
+/// exactly once in its direction, or `None` if `g` has no Eulerian circuit.
+///
+/// A digraph has an Eulerian circuit if and only if the in degree of each vertex is equal to its
+/// out degree and all edges are in the same strongly connected component. If `g` has no edges,
+/// the circuit is empty.
+///
+/// The running time is O(V + E).
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::euler::directed_eulerian_circuit;
+///
+/// # fn main() {
+/// let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (2, 0), (0, 2), (2, 0));
+/// let e = g.edges().collect::<Vec<_>>();
+/// assert_eq!(Some(vec![e[0], e[1], e[2], e[3], e[4]]), directed_eulerian_circuit(&g));
+///
+/// // the edges of a cycle in the wrong direction
+/// let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (0, 2));
+/// assert_eq!(None, directed_eulerian_circuit(&g));
+/// # }
+/// ```
+pub fn directed_eulerian_circuit<G>(g: &G) -> Option<Vec<Edge<G>>>
+where
+    G: IncidenceDigraph + WithVertexIndexProp,
+{
+    let mut balance = g.default_vertex_prop(0isize);
+    for (u, v) in g.edges_ends() {
+        balance[u] += 1;
+        balance[v] -= 1;
+    }
+    if g.vertices().any(|v| balance[v] != 0) {
+        return None;
+    }
+    circuit(g, g.default_edge_prop(1usize))
+}
+
+/// Solves the Chinese postman problem, that is, finds a closed walk with minimum total weight that
+/// traverses each edge of `g` at least once. The weight `w` of each edge must be non-negative.
+///
+/// The vertices with odd degree are paired by a minimum weight perfect matching, where the weight
+/// of a pair is the length of a shortest path between the vertices. The edges of the paths of the
+/// matching are duplicated, which makes all degrees even, and the walk is an Eulerian circuit of
+/// the resulting multigraph.
+///
+/// Returns `Some((d, walk))` where `walk` are the edges of the walk (oriented in the walk
+/// direction) and `d` is its total weight, or `None` if the edges of `g` are not all in the same
+/// connected component.
+///
+/// The shortest paths are found by running Dijkstra's algorithm from each odd degree vertex and
+/// the matching by [`min_weight_perfect_matching`] on the complete graph of the odd degree
+/// vertices. If there are `k` odd degree vertices, the running time is O(k (V + E) log V + k^3).
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::euler::chinese_postman;
+/// use fera_graph::props::FnProp;
+///
+/// # fn main() {
+/// // a path must be traversed twice
+/// let g: StaticGraph = graph!(3, (0, 1), (1, 2));
+/// let (d, walk) = chinese_postman(&g, FnProp(|_| 1u32)).unwrap();
+/// assert_eq!(4, d);
+/// assert_eq!(4, walk.len());
+/// # }
+/// ```
+///
+/// [`min_weight_perfect_matching`]: ../matching/fn.min_weight_perfect_matching.html
+pub fn chinese_postman<G, W, T>(g: &G, w: W) -> Option<(T, Vec<Edge<G>>)>
+where
+    G: IncidenceGraph<Kind = Undirected> + WithVertexIndexProp,
+    G: WithVertexProp<Option<T>> + WithVertexProp<OptionEdge<G>>,
+    W: EdgePropGet<G, T>,
+    T: Copy + Ord + Zero + One + Add<Output = T> + Sub<Output = T> + Div<Output = T>,
+{
+    let odd: Vec<_> = g.vertices().filter(|&v| g.out_degree(v) % 2 == 1).collect();
+    // dist[i][j] is the length of a shortest path between odd[i] and odd[j] and pred[i] are the
+    // predecessor edges of the shortest paths tree rooted at odd[i]
+    let mut dist = Vec::with_capacity(odd.len());
+    let mut pred = Vec::with_capacity(odd.len());
+    for &u in &odd {
+        let (d, p) = g.dijkstra(&w, u, None);
+        // if a vertex is not reachable the edges are not all in the same component
+        dist.push(odd.iter().map(|&v| d[v]).collect::<Option<Vec<_>>>()?);
+        pred.push(p);
+    }
+
+    let h = CompleteGraph::new(odd.len() as CVertex);
+    let matching = min_weight_perfect_matching(
+        &h,
+        FnProp(|e| {
+            let (i, j) = h.ends(e);
+            dist[i as usize][j as usize]
+        }),
+    ).expect("a complete graph with an even number of vertices has a perfect matching");
+
+    let mut count = g.default_edge_prop(1usize);
+    for e in h.edges().filter(|&e| matching[e]) {
+        let (i, j) = h.ends(e);
+        let mut v = odd[j as usize];
+        while let Some(e) = pred[i as usize][v].into_option() {
+            count[e] += 1;
+            v = g.source(e);
+        }
+    }
+
+    let walk = circuit(g, count)?;
+    let d = walk.iter().fold(T::zero(), |d, &e| d + w.get(e));
+    Some((d, walk))
+}
+
+// Finds a closed walk that traverses each edge e exactly count[e] times using Hierholzer's
+// algorithm, or returns None if the edges with count[e] > 0 are not in the same component. All
+// vertices must have even degree (considering the counts), or for digraphs, the same in and out
+// degree.
+fn circuit<G>(g: &G, mut count: DefaultEdgePropMut<G, usize>) -> Option<Vec<Edge<G>>>
+where
+    G: VertexList + EdgeList + Incidence + BasicProps + WithVertexIndexProp,
+{
+    let total: usize = g.edges().map(|e| count[e]).sum();
+    let start = match g.vertices().find(|&v| g.out_degree(v) != 0) {
+        Some(v) => v,
+        None => return Some(vec![]),
+    };
+    let index = g.vertex_index();
+    let out: Vec<Vec<_>> = g.vertices().map(|v| g.out_edges(v).collect()).collect();
+    let mut next = vec![0; out.len()];
+    let mut walk = Vec::with_capacity(total);
+    let mut stack = vec![(start, G::edge_none())];
+    while let Some(&(u, _)) = stack.last() {
+        let i = index.get(u);
+        while next[i] < out[i].len() && count[out[i][next[i]]] == 0 {
+            next[i] += 1;
+        }
+        if let Some(&e) = out[i].get(next[i]) {
+            count[e] -= 1;
+            stack.push((g.target(e), G::edge_some(e)));
+        } else if let Some(e) = stack.pop().unwrap().1.into_option() {
+            walk.push(e);
+        }
+    }
+    if walk.len() == total {
+        walk.reverse();
+        Some(walk)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::{set, vec};
+    use props::FnProp;
+    use rand::prelude::*;
+
+    fn check_closed_walk(g: &StaticGraph, walk: &[Edge<StaticGraph>]) {
+        assert!(g.is_walk(walk));
+        if let (Some(&first), Some(&last)) = (walk.first(), walk.last()) {
+            assert_eq!(g.source(first), g.target(last));
+        }
+    }
+
+    #[test]
+    fn eulerian() {
+        let g: StaticGraph = graph!(0);
+        assert_eq!(Some(vec![]), eulerian_circuit(&g));
+        let g: StaticGraph = graph!(3);
+        assert_eq!(Some(vec![]), eulerian_circuit(&g));
+
+        let g: StaticGraph = graph!(4, (0, 1), (1, 1), (1, 2), (2, 0), (2, 0), (0, 2));
+        let walk = eulerian_circuit(&g).unwrap();
+        assert_eq!(6, walk.len());
+        check_closed_walk(&g, &walk);
+        assert_eq!(set(g.edges()), set(walk.iter().cloned()));
+
+        // two disjoint cycles
+        let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3));
+        assert_eq!(None, eulerian_circuit(&g));
+    }
+
+    #[test]
+    fn directed() {
+        let g: StaticDigraph = graph!(0);
+        assert_eq!(Some(vec![]), directed_eulerian_circuit(&g));
+
+        let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (2, 3), (3, 0));
+        assert_eq!(Some(vec(g.edges())), directed_eulerian_circuit(&g));
+        let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (2, 3), (0, 3));
+        assert_eq!(None, directed_eulerian_circuit(&g));
+
+        // two disjoint cycles
+        let g: StaticDigraph = graph!(4, (0, 1), (1, 0), (2, 3), (3, 2));
+        assert_eq!(None, directed_eulerian_circuit(&g));
+    }
+
+    #[test]
+    fn de_bruijn() {
+        // the de Bruijn graph of order 2 over {0, 1}: the vertex ab has an edge to bc for each c,
+        // and a circuit gives a cyclic sequence with each binary string of length 3 exactly once
+        let mut edges = vec![];
+        for ab in 0..4 {
+            for c in 0..2 {
+                edges.push((ab, (ab << 1 | c) & 3));
+            }
+        }
+        let g = StaticDigraph::new_with_edges(4, edges);
+        let walk = directed_eulerian_circuit(&g).unwrap();
+        assert_eq!(8, walk.len());
+        assert!(g.is_walk(&walk));
+        assert_eq!(g.source(walk[0]), g.target(walk[7]));
+        assert_eq!(set(g.edges()), set(walk.iter().cloned()));
+        let seq = vec(walk.iter().map(|&e| g.target(e) & 1));
+        let strings = set((0..8).map(|i| seq[i] << 2 | seq[(i + 1) % 8] << 1 | seq[(i + 2) % 8]));
+        assert_eq!(set(0..8), strings);
+    }
+
+    #[test]
+    fn postman() {
+        // the odd vertices are 1 and 2, and the shortest path between them is the edge (1, 2)
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (1, 3), (0, 2), (2, 3));
+        let e = vec(g.edges());
+        let mut w = g.default_edge_prop(0u32);
+        for (&e, &x) in e.iter().zip(&[1, 2, 3, 4, 5]) {
+            w[e] = x;
+        }
+        let (d, walk) = chinese_postman(&g, &w).unwrap();
+        assert_eq!(15 + 2, d);
+        assert_eq!(6, walk.len());
+        check_closed_walk(&g, &walk);
+        assert_eq!(2, walk.iter().filter(|&&f| f == e[1]).count());
+
+        // now the shortest path between 1 and 2 is through 0
+        w[e[1]] = 10;
+        let (d, walk) = chinese_postman(&g, &w).unwrap();
+        assert_eq!(23 + 5, d);
+        assert_eq!(7, walk.len());
+        check_closed_walk(&g, &walk);
+    }
+
+    #[test]
+    fn postman_disconnected() {
+        let g: StaticGraph = graph!(4, (0, 1), (2, 3));
+        assert_eq!(None, chinese_postman(&g, FnProp(|_| 1u32)));
+        let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3));
+        assert_eq!(None, chinese_postman(&g, FnProp(|_| 1u32)));
+        let g: StaticGraph = graph!(4);
+        assert_eq!(Some((0, vec![])), chinese_postman(&g, FnProp(|_| 1u32)));
+    }
+
+    #[test]
+    fn postman_many_odd_vertices() {
+        // a star with 40 leaves, each edge must be traversed twice
+        let g = StaticGraph::new_with_edges(41, (1..41).map(|v| (0, v)));
+        let (d, walk) = chinese_postman(&g, FnProp(|_| 1u32)).unwrap();
+        assert_eq!(80, d);
+        check_closed_walk(&g, &walk);
+        for e in g.edges() {
+            assert_eq!(2, walk.iter().filter(|&&f| f == e).count());
+        }
+    }
+
+    #[test]
+    fn postman_random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 2..12 {
+            let m = rng.gen_range(n - 1, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm_connected(n, m, &mut rng).unwrap();
+            let (d, walk) = chinese_postman(&g, FnProp(|_| 1u32)).unwrap();
+            assert_eq!(d as usize, walk.len());
+            assert!(walk.len() >= g.num_edges());
+            check_closed_walk(&g, &walk);
+            assert_eq!(set(g.edges()), set(walk.iter().cloned()));
+            if g.vertices().all(|v| g.out_degree(v) % 2 == 0) {
+                assert_eq!(g.num_edges(), walk.len());
+            }
+        }
+    }
+}
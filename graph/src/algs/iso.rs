@@ -0,0 +1,781 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Graph and subgraph isomorphism using the [VF2] algorithm, and tree isomorphism in almost
+//! linear time (see [`trees_isomorphic`]).
+//!
+//! # Examples
+//!
+//! ```
+//! #[macro_use] extern crate fera_graph;
+//! use fera_graph::prelude::*;
+//! use fera_graph::algs::iso::{is_isomorphic, subgraph_isomorphisms};
+//!
+//! # fn main() {
+//! let triangle: StaticGraph = graph!(3, (0, 1), (1, 2), (2, 0));
+//! let cycle: StaticGraph = graph!(3, (2, 0), (0, 1), (1, 2));
+//! assert!(is_isomorphic(&triangle, &cycle));
+//!
+//! // the triangles of K4 (each one is found 6 times, once for each automorphism)
+//! let k4 = CompleteGraph::new(4);
+//! assert_eq!(24, subgraph_isomorphisms(&k4, &triangle).count());
+//!
+//! // only the first match
+//! let m = subgraph_isomorphisms(&k4, &triangle).next().unwrap();
+//! assert_eq!(vec![(0, 0), (1, 1), (2, 2)], m);
+//! # }
+//! ```
+//!
+//! [VF2]: https://doi.org/10.1109/TPAMI.2004.75
+//! [`trees_isomorphic`]: fn.trees_isomorphic.html
+
+use algs::Trees;
+use prelude::*;
+
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
+
+const NONE: usize = usize::max_value();
+
+/// Returns `true` if `g` and `h` are isomorphic. Loops and parallel edges are taken into
+/// account, that is, each pair of corresponding vertices must be joined by the same number of
+/// edges.
+///
+/// This takes exponential time in the worst case.
+pub fn is_isomorphic<G, H>(g: &G, h: &H) -> bool
+where
+    G: AdjacencyGraph,
+    H: AdjacencyGraph,
+{
+    if g.num_vertices() != h.num_vertices() || g.num_edges() != h.num_edges() {
+        return false;
+    }
+    let mut isos = subgraph_isomorphisms(g, h);
+    isos.same_multiplicity = true;
+    isos.next().is_some()
+}
+
+/// Returns `true` if the trees `g` and `h` are isomorphic.
+///
+/// This uses the Aho, Hopcroft and Ullman algorithm: the trees are rooted at their centers and
+/// each subtree gets a label that identifies its shape, computed from the sorted labels of the
+/// subtrees of its children. The trees are isomorphic if the roots have the same label. If a
+/// tree has two centers, both rootings are tried. The running time is O(V log V).
+///
+/// # Panics
+///
+/// If `g` or `h` is not a tree.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::iso::trees_isomorphic;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(5, (0, 1), (1, 2), (1, 3), (3, 4));
+/// let h: StaticGraph = graph!(5, (4, 3), (3, 0), (0, 2), (0, 1));
+/// assert!(trees_isomorphic(&g, &h));
+///
+/// let path: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+/// let star: StaticGraph = graph!(4, (0, 1), (0, 2), (0, 3));
+/// assert!(!trees_isomorphic(&path, &star));
+/// # }
+/// ```
+pub fn trees_isomorphic<G, H>(g: &G, h: &H) -> bool
+where
+    G: IncidenceGraph,
+    H: IncidenceGraph,
+{
+    assert!(g.is_tree(), "g is not a tree");
+    assert!(h.is_tree(), "h is not a tree");
+    if g.num_vertices() != h.num_vertices() {
+        return false;
+    }
+    if g.num_vertices() == 0 {
+        return true;
+    }
+    let (g_vertices, g_adj, _, _) = adjacency(g);
+    let (h_vertices, h_adj, _, _) = adjacency(h);
+    let g_centers = tree_center_positions(g, &g_vertices);
+    let h_centers = tree_center_positions(h, &h_vertices);
+    if g_centers.len() != h_centers.len() {
+        return false;
+    }
+    // the labels are shared, so equal shapes get the same label in both trees
+    let mut labels = HashMap::new();
+    let label = tree_label(&g_adj, g_centers[0], &mut labels);
+    h_centers
+        .into_iter()
+        .any(|c| tree_label(&h_adj, c, &mut labels) == label)
+}
+
+/// Returns an iterator over the isomorphisms between `h` and the induced subgraphs of `g`.
+///
+/// Each item is a vector of pairs `(u, v)` that maps each vertex `u` of `h` to a distinct vertex
+/// `v` of `g` such that `u` and `w` are adjacent in `h` if and only if the corresponding vertices
+/// are adjacent in `g`. The pairs are in `h.vertices()` order. The existence of loops is taken
+/// into account, but the multiplicity is ignored: parallel edges (and multiple loops) are
+/// considered to be a single edge, unlike in [`is_isomorphic`].
+///
+/// The matches are generated lazily, so taking the first match does not require finding all of
+/// them. The number of matches can be exponential, and so can the time to find the first one.
+/// Use [`vertex_compatibility`] and [`edge_compatibility`] to restrict the matches (for example,
+/// to match labeled graphs).
+///
+/// [`is_isomorphic`]: fn.is_isomorphic.html
+/// [`vertex_compatibility`]: struct.SubgraphIsomorphisms.html#method.vertex_compatibility
+/// [`edge_compatibility`]: struct.SubgraphIsomorphisms.html#method.edge_compatibility
+pub fn subgraph_isomorphisms<'a, G, H>(
+    g: &'a G,
+    h: &'a H,
+) -> SubgraphIsomorphisms<'a, G, H, AnyVertexCompatibility<G, H>, AnyEdgeCompatibility<G, H>>
+where
+    G: AdjacencyGraph,
+    H: AdjacencyGraph,
+{
+    fn any<A, B>(_: A, _: B) -> bool {
+        true
+    }
+
+    let (gv, g_adj, g_mult, g_loop) = adjacency(g);
+    let (hv, h_adj, h_mult, h_loop) = adjacency(h);
+    let (order, parent) = matching_order(&h_adj);
+    SubgraphIsomorphisms {
+        g,
+        h,
+        vertex_compatible: any,
+        edge_compatible: any,
+        core_g: vec![NONE; gv.len()],
+        core_h: vec![NONE; hv.len()],
+        term_g: vec![0; gv.len()],
+        term_h: vec![0; hv.len()],
+        gv,
+        g_adj,
+        g_mult,
+        g_loop,
+        hv,
+        h_adj,
+        h_mult,
+        h_loop,
+        same_multiplicity: false,
+        order,
+        parent,
+        stack: vec![],
+        started: false,
+    }
+}
+
+/// The type of the vertex compatibility predicate used when none is given.
+pub type AnyVertexCompatibility<G, H> = fn(Vertex<H>, Vertex<G>) -> bool;
+
+/// The type of the edge compatibility predicate used when none is given.
+pub type AnyEdgeCompatibility<G, H> = fn(Edge<H>, Edge<G>) -> bool;
+
+/// An iterator over subgraph isomorphisms. See [`subgraph_isomorphisms`].
+///
+/// [`subgraph_isomorphisms`]: fn.subgraph_isomorphisms.html
+pub struct SubgraphIsomorphisms<'a, G, H, VC, EC>
+where
+    G: 'a + WithEdge,
+    H: 'a + WithEdge,
+{
+    g: &'a G,
+    h: &'a H,
+    vertex_compatible: VC,
+    edge_compatible: EC,
+    // the vertices and the sorted adjacency lists (without loops) of each graph, indexed from 0,
+    // the number of edges joining each adjacent pair (mult[u][i] for the pair (u, adj[u][i]))
+    // and the number of loops of each vertex
+    gv: Vec<Vertex<G>>,
+    g_adj: Vec<Vec<usize>>,
+    g_mult: Vec<Vec<usize>>,
+    g_loop: Vec<usize>,
+    hv: Vec<Vertex<H>>,
+    h_adj: Vec<Vec<usize>>,
+    h_mult: Vec<Vec<usize>>,
+    h_loop: Vec<usize>,
+    // if the multiplicities of the matched edges and loops must be equal (used by is_isomorphic)
+    same_multiplicity: bool,
+    // the order in which the vertices of h are matched and a previous neighbor of each one
+    order: Vec<usize>,
+    parent: Vec<usize>,
+    // the current partial mapping
+    core_g: Vec<usize>,
+    core_h: Vec<usize>,
+    // term[v] = d > 0 if v is adjacent to a vertex matched at depth d (the terminal set of VF2)
+    term_g: Vec<usize>,
+    term_h: Vec<usize>,
+    // the candidates for each depth and the position of the next candidate
+    stack: Vec<(Vec<usize>, usize)>,
+    started: bool,
+}
+
+impl<'a, G, H, VC, EC> SubgraphIsomorphisms<'a, G, H, VC, EC>
+where
+    G: AdjacencyGraph,
+    H: AdjacencyGraph,
+    VC: FnMut(Vertex<H>, Vertex<G>) -> bool,
+    EC: FnMut(Edge<H>, Edge<G>) -> bool,
+{
+    /// Restricts the matches to the ones that map each vertex `u` of `h` to a vertex `v` of `g`
+    /// such that `compatible(u, v)` is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::iso::subgraph_isomorphisms;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+    /// let h: StaticGraph = graph!(2, (0, 1));
+    /// assert_eq!(6, subgraph_isomorphisms(&g, &h).count());
+    /// // the vertex 0 of h can only be mapped to the vertex 3 of g
+    /// let m: Vec<_> = subgraph_isomorphisms(&g, &h)
+    ///     .vertex_compatibility(|u, v| u != 0 || v == 3)
+    ///     .collect();
+    /// assert_eq!(vec![vec![(0, 3), (1, 2)]], m);
+    /// # }
+    /// ```
+    pub fn vertex_compatibility<F>(self, compatible: F) -> SubgraphIsomorphisms<'a, G, H, F, EC>
+    where
+        F: FnMut(Vertex<H>, Vertex<G>) -> bool,
+    {
+        SubgraphIsomorphisms {
+            g: self.g,
+            h: self.h,
+            vertex_compatible: compatible,
+            edge_compatible: self.edge_compatible,
+            gv: self.gv,
+            g_adj: self.g_adj,
+            g_mult: self.g_mult,
+            g_loop: self.g_loop,
+            hv: self.hv,
+            h_adj: self.h_adj,
+            h_mult: self.h_mult,
+            h_loop: self.h_loop,
+            same_multiplicity: self.same_multiplicity,
+            order: self.order,
+            parent: self.parent,
+            core_g: self.core_g,
+            core_h: self.core_h,
+            term_g: self.term_g,
+            term_h: self.term_h,
+            stack: self.stack,
+            started: self.started,
+        }
+    }
+
+    /// Restricts the matches to the ones that map each edge `e` of `h` to an edge `f` of `g` such
+    /// that `compatible(e, f)` is `true`. When there are parallel edges, `e` and `f` are the edges
+    /// returned by `get_edge_by_ends`.
+    pub fn edge_compatibility<F>(self, compatible: F) -> SubgraphIsomorphisms<'a, G, H, VC, F>
+    where
+        F: FnMut(Edge<H>, Edge<G>) -> bool,
+    {
+        SubgraphIsomorphisms {
+            g: self.g,
+            h: self.h,
+            vertex_compatible: self.vertex_compatible,
+            edge_compatible: compatible,
+            gv: self.gv,
+            g_adj: self.g_adj,
+            g_mult: self.g_mult,
+            g_loop: self.g_loop,
+            hv: self.hv,
+            h_adj: self.h_adj,
+            h_mult: self.h_mult,
+            h_loop: self.h_loop,
+            same_multiplicity: self.same_multiplicity,
+            order: self.order,
+            parent: self.parent,
+            core_g: self.core_g,
+            core_h: self.core_h,
+            term_g: self.term_g,
+            term_h: self.term_h,
+            stack: self.stack,
+            started: self.started,
+        }
+    }
+
+    fn candidates(&self, depth: usize) -> Vec<usize> {
+        let p = self.parent[depth];
+        if p == NONE {
+            (0..self.gv.len())
+                .filter(|&v| self.core_g[v] == NONE)
+                .collect()
+        } else {
+            // n is adjacent to p, so its match must be adjacent to the match of p
+            self.g_adj[self.core_h[p]]
+                .iter()
+                .cloned()
+                .filter(|&v| self.core_g[v] == NONE)
+                .collect()
+        }
+    }
+
+    fn is_feasible(&mut self, n: usize, m: usize) -> bool {
+        if (self.h_loop[n] > 0) != (self.g_loop[m] > 0)
+            || self.same_multiplicity && self.h_loop[n] != self.g_loop[m]
+            || self.h_adj[n].len() > self.g_adj[m].len()
+            || !(self.vertex_compatible)(self.hv[n], self.gv[m])
+        {
+            return false;
+        }
+
+        if self.h_loop[n] > 0 {
+            let (e, f) = (self.h_edge(n, n), self.g_edge(m, m));
+            if !(self.edge_compatible)(e, f) {
+                return false;
+            }
+        }
+
+        // the number of unmatched neighbors in and out of the terminal set
+        let (mut h_term, mut h_new) = (0, 0);
+        for i in 0..self.h_adj[n].len() {
+            let x = self.h_adj[n][i];
+            let y = self.core_h[x];
+            if y != NONE {
+                match self.g_adj[m].binary_search(&y) {
+                    Ok(j) => {
+                        if self.same_multiplicity && self.h_mult[n][i] != self.g_mult[m][j] {
+                            return false;
+                        }
+                    }
+                    Err(_) => return false,
+                }
+                let (e, f) = (self.h_edge(n, x), self.g_edge(m, y));
+                if !(self.edge_compatible)(e, f) {
+                    return false;
+                }
+            } else if self.term_h[x] != 0 {
+                h_term += 1;
+            } else {
+                h_new += 1;
+            }
+        }
+
+        let (mut g_term, mut g_new) = (0, 0);
+        for &y in &self.g_adj[m] {
+            let x = self.core_g[y];
+            if x != NONE {
+                // induced: the neighbors of m must be matched to neighbors of n
+                if self.h_adj[n].binary_search(&x).is_err() {
+                    return false;
+                }
+            } else if self.term_g[y] != 0 {
+                g_term += 1;
+            } else {
+                g_new += 1;
+            }
+        }
+
+        h_term <= g_term && h_new <= g_new
+    }
+
+    fn h_edge(&self, u: usize, v: usize) -> Edge<H> {
+        self.h.edge_by_ends(self.hv[u], self.hv[v])
+    }
+
+    fn g_edge(&self, u: usize, v: usize) -> Edge<G> {
+        self.g.edge_by_ends(self.gv[u], self.gv[v])
+    }
+
+    fn push(&mut self, depth: usize, n: usize, m: usize) {
+        self.core_h[n] = m;
+        self.core_g[m] = n;
+        for &x in &self.h_adj[n] {
+            if self.term_h[x] == 0 {
+                self.term_h[x] = depth;
+            }
+        }
+        for &y in &self.g_adj[m] {
+            if self.term_g[y] == 0 {
+                self.term_g[y] = depth;
+            }
+        }
+    }
+
+    fn pop(&mut self, depth: usize, n: usize) {
+        let m = self.core_h[n];
+        self.core_h[n] = NONE;
+        self.core_g[m] = NONE;
+        for &x in &self.h_adj[n] {
+            if self.term_h[x] == depth {
+                self.term_h[x] = 0;
+            }
+        }
+        for &y in &self.g_adj[m] {
+            if self.term_g[y] == depth {
+                self.term_g[y] = 0;
+            }
+        }
+    }
+
+    fn mapping(&self) -> Vec<(Vertex<H>, Vertex<G>)> {
+        self.hv
+            .iter()
+            .zip(&self.core_h)
+            .map(|(&u, &v)| (u, self.gv[v]))
+            .collect()
+    }
+}
+
+impl<'a, G, H, VC, EC> Iterator for SubgraphIsomorphisms<'a, G, H, VC, EC>
+where
+    G: AdjacencyGraph,
+    H: AdjacencyGraph,
+    VC: FnMut(Vertex<H>, Vertex<G>) -> bool,
+    EC: FnMut(Edge<H>, Edge<G>) -> bool,
+{
+    type Item = Vec<(Vertex<H>, Vertex<G>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            if self.hv.len() > self.gv.len() {
+                return None;
+            }
+            if self.hv.is_empty() {
+                return Some(vec![]);
+            }
+            let c = self.candidates(0);
+            self.stack.push((c, 0));
+        }
+
+        while !self.stack.is_empty() {
+            let depth = self.stack.len() - 1;
+            let n = self.order[depth];
+            if self.core_h[n] != NONE {
+                // undo the previous candidate of this depth
+                self.pop(depth + 1, n);
+            }
+            let m = {
+                let &mut (ref candidates, ref mut pos) = self.stack.last_mut().unwrap();
+                if *pos == candidates.len() {
+                    None
+                } else {
+                    *pos += 1;
+                    Some(candidates[*pos - 1])
+                }
+            };
+            let m = match m {
+                Some(m) => m,
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+            if !self.is_feasible(n, m) {
+                continue;
+            }
+            self.push(depth + 1, n, m);
+            if depth + 1 == self.order.len() {
+                return Some(self.mapping());
+            }
+            let c = self.candidates(depth + 1);
+            self.stack.push((c, 0));
+        }
+
+        None
+    }
+}
+
+// The vertices, the sorted adjacency lists (without loops), the multiplicity of each adjacency
+// and the number of loops of each vertex.
+type AdjacencyLists<V> = (Vec<V>, Vec<Vec<usize>>, Vec<Vec<usize>>, Vec<usize>);
+
+fn adjacency<G: AdjacencyGraph>(g: &G) -> AdjacencyLists<Vertex<G>> {
+    let vertices: Vec<_> = g.vertices().collect();
+    let mut index = g.default_vertex_prop(0usize);
+    for (i, &v) in vertices.iter().enumerate() {
+        index[v] = i;
+    }
+    // each edge is considered once, so the parallel edges and loops can be counted
+    let mut neighbors = vec![vec![]; vertices.len()];
+    let mut loops = vec![0; vertices.len()];
+    for (u, v) in g.edges_ends() {
+        let (i, j) = (index[u], index[v]);
+        if i == j {
+            loops[i] += 1;
+        } else {
+            neighbors[i].push(j);
+            neighbors[j].push(i);
+        }
+    }
+    let mut adj = Vec::with_capacity(vertices.len());
+    let mut mult = Vec::with_capacity(vertices.len());
+    for mut ns in neighbors {
+        ns.sort();
+        let (mut a, mut m): (Vec<usize>, Vec<usize>) = (vec![], vec![]);
+        for j in ns {
+            if a.last() == Some(&j) {
+                *m.last_mut().unwrap() += 1;
+            } else {
+                a.push(j);
+                m.push(1);
+            }
+        }
+        adj.push(a);
+        mult.push(m);
+    }
+    (vertices, adj, mult, loops)
+}
+
+// Returns the positions in vertices of the one or two centers of the tree g.
+fn tree_center_positions<G: IncidenceGraph>(g: &G, vertices: &[Vertex<G>]) -> Vec<usize> {
+    g.tree_center()
+        .unwrap()
+        .into_iter()
+        .map(|c| vertices.iter().position(|&v| v == c).unwrap())
+        .collect()
+}
+
+// Returns the label of the tree rooted at root. The label of a subtree is the position in labels
+// of the sorted labels of the subtrees of its children.
+fn tree_label(adj: &[Vec<usize>], root: usize, labels: &mut HashMap<Vec<usize>, usize>) -> usize {
+    let mut parent = vec![NONE; adj.len()];
+    let mut order = vec![root];
+    let mut i = 0;
+    while i < order.len() {
+        let u = order[i];
+        i += 1;
+        for &v in &adj[u] {
+            if v != parent[u] {
+                parent[v] = u;
+                order.push(v);
+            }
+        }
+    }
+    let mut label = vec![0; adj.len()];
+    for &v in order.iter().rev() {
+        let mut children: Vec<_> = adj[v]
+            .iter()
+            .filter(|&&u| u != parent[v])
+            .map(|&u| label[u])
+            .collect();
+        children.sort();
+        let len = labels.len();
+        label[v] = *labels.entry(children).or_insert(len);
+    }
+    label[root]
+}
+
+// Returns the order in which the vertices are matched and, for each position of the order, a
+// neighbor of the vertex that comes before it (or NONE). The vertices are ordered in breadth
+// first order, starting each component with a vertex of maximum degree.
+fn matching_order(adj: &[Vec<usize>]) -> (Vec<usize>, Vec<usize>) {
+    let n = adj.len();
+    let mut roots: Vec<_> = (0..n).collect();
+    roots.sort_by_key(|&v| Reverse(adj[v].len()));
+    let mut visited = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    let mut parent = Vec::with_capacity(n);
+    let mut queue = VecDeque::new();
+    for r in roots {
+        if visited[r] {
+            continue;
+        }
+        visited[r] = true;
+        queue.push_back((r, NONE));
+        while let Some((u, p)) = queue.pop_front() {
+            order.push(u);
+            parent.push(p);
+            for &v in &adj[u] {
+                if !visited[v] {
+                    visited[v] = true;
+                    queue.push_back((v, u));
+                }
+            }
+        }
+    }
+    (order, parent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::{set, vec};
+    use named::{cycle_graph, path_graph, petersen_graph, star_graph};
+    use rand::prelude::*;
+
+    #[test]
+    fn trees() {
+        let path = path_graph(6);
+        let star = star_graph(5);
+        assert!(!trees_isomorphic(&path, &star));
+        assert!(trees_isomorphic(&path, &path));
+
+        // two centers (2 and 3), with the subtrees swapped
+        let g: StaticGraph = graph!(6, (0, 2), (1, 2), (2, 3), (3, 4), (4, 5));
+        let h: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 3), (3, 4), (3, 5));
+        assert!(trees_isomorphic(&g, &h));
+        assert!(!trees_isomorphic(&g, &path));
+
+        // same degree sequence
+        let g: StaticGraph = graph!(7, (0, 1), (1, 2), (2, 3), (3, 4), (1, 5), (3, 6));
+        let h: StaticGraph = graph!(7, (0, 1), (1, 2), (2, 3), (3, 4), (1, 5), (2, 6));
+        assert!(!trees_isomorphic(&g, &h));
+
+        let empty: StaticGraph = graph!(0);
+        assert!(trees_isomorphic(&empty, &empty));
+        let single: StaticGraph = graph!(1);
+        assert!(trees_isomorphic(&single, &single));
+        assert!(!trees_isomorphic(&empty, &single));
+    }
+
+    #[test]
+    #[should_panic(expected = "h is not a tree")]
+    fn trees_not_tree() {
+        trees_isomorphic(&path_graph(3), &cycle_graph(3));
+    }
+
+    #[test]
+    fn trees_random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..10 {
+            for _ in 0..10 {
+                let g = StaticGraph::new_random_tree(n, &mut rng);
+                let h = StaticGraph::new_random_tree(n, &mut rng);
+                assert_eq!(is_isomorphic(&g, &h), trees_isomorphic(&g, &h));
+                // the same tree with the vertices relabeled
+                let mut perm = vec(0..n);
+                perm.shuffle(&mut rng);
+                let h = StaticGraph::new_with_edges(
+                    n,
+                    g.edges_ends().map(|(u, v)| (perm[u as usize], perm[v as usize])),
+                );
+                assert!(trees_isomorphic(&g, &h));
+            }
+        }
+    }
+
+    fn is_subgraph_isomorphism<G, H>(g: &G, h: &H, m: &[(Vertex<H>, Vertex<G>)]) -> bool
+    where
+        G: AdjacencyGraph,
+        H: AdjacencyGraph,
+    {
+        m.len() == h.num_vertices()
+            && set(m.iter().map(|&(_, v)| v)).len() == m.len()
+            && m.iter().all(|&(u, x)| {
+                m.iter().all(|&(w, y)| {
+                    h.get_edge_by_ends(u, w).is_some() == g.get_edge_by_ends(x, y).is_some()
+                })
+            })
+    }
+
+    #[test]
+    fn triangle() {
+        let t: StaticGraph = graph!(3, (0, 1), (1, 2), (2, 0));
+        let c = cycle_graph(3);
+        assert!(is_isomorphic(&t, &c));
+        assert_eq!(6, subgraph_isomorphisms(&c, &t).count());
+        let k3 = CompleteGraph::new(3);
+        assert!(is_isomorphic(&k3, &t));
+        assert_eq!(6, subgraph_isomorphisms(&k3, &t).count());
+        assert!(!is_isomorphic(&t, &path_graph(3)));
+        assert!(!is_isomorphic(&t, &path_graph(4)));
+    }
+
+    #[test]
+    fn k4_triangles() {
+        let t: StaticGraph = graph!(3, (0, 1), (1, 2), (2, 0));
+        let k4 = CompleteGraph::new(4);
+        let all = vec(subgraph_isomorphisms(&k4, &t));
+        // 4 triangles and 3! automorphisms
+        assert_eq!(24, all.len());
+        for m in &all {
+            assert!(is_subgraph_isomorphism(&k4, &t, m));
+        }
+    }
+
+    #[test]
+    fn induced() {
+        // a path is not an induced subgraph of a complete graph
+        let k4 = CompleteGraph::new(4);
+        assert_eq!(0, subgraph_isomorphisms(&k4, &path_graph(3)).count());
+        // but is an induced subgraph of a cycle (in two directions)
+        assert_eq!(
+            2 * 6,
+            subgraph_isomorphisms(&cycle_graph(6), &path_graph(3)).count()
+        );
+        // and of the petersen graph (the graph has girth 5)
+        let p = petersen_graph();
+        assert_eq!(10 * 6, subgraph_isomorphisms(&p, &path_graph(3)).count());
+        assert_eq!(0, subgraph_isomorphisms(&p, &cycle_graph(4)).count());
+    }
+
+    #[test]
+    fn empty_and_loops() {
+        let e: StaticGraph = graph!();
+        let empty: Vec<(u32, u32)> = vec![];
+        assert_eq!(vec![empty], vec(subgraph_isomorphisms(&e, &e)));
+        assert!(is_isomorphic(&e, &e));
+        assert_eq!(0, subgraph_isomorphisms(&e, &path_graph(1)).count());
+
+        let g: StaticGraph = graph!(3, (0, 1), (1, 1), (1, 2));
+        let h: StaticGraph = graph!(2, (0, 0), (0, 1));
+        assert_eq!(
+            vec![vec![(0, 1), (1, 0)], vec![(0, 1), (1, 2)]],
+            vec(subgraph_isomorphisms(&g, &h))
+        );
+        assert!(!is_isomorphic(&g, &path_graph(3)));
+    }
+
+    #[test]
+    fn parallel_edges_and_loops() {
+        // the first graph doubles an end edge of the path and the second the middle edge
+        let g: StaticGraph = graph!(4, (0, 1), (0, 1), (1, 2), (2, 3));
+        let h: StaticGraph = graph!(4, (0, 1), (1, 2), (1, 2), (2, 3));
+        assert!(!is_isomorphic(&g, &h));
+        let h: StaticGraph = graph!(4, (3, 2), (2, 1), (1, 0), (3, 2));
+        assert!(is_isomorphic(&g, &h));
+        // subgraph_isomorphisms ignores the multiplicity
+        assert_eq!(2, subgraph_isomorphisms(&g, &path_graph(4)).count());
+
+        let g: StaticGraph = graph!(2, (0, 0), (0, 0), (0, 1), (1, 1));
+        let h: StaticGraph = graph!(2, (0, 0), (0, 1), (1, 1), (1, 1));
+        assert!(is_isomorphic(&g, &h));
+        let h: StaticGraph = graph!(2, (0, 0), (0, 1), (0, 1), (1, 1));
+        assert!(!is_isomorphic(&g, &h));
+    }
+
+    #[test]
+    fn labeled() {
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (3, 0));
+        let h: StaticGraph = graph!(2, (0, 1));
+        let mut gw = g.default_edge_prop(0u32);
+        gw[g.edge_by_ends(1, 2)] = 1;
+        let mut hw = h.default_edge_prop(0u32);
+        hw[h.edge_by_ends(0, 1)] = 1;
+        let m = vec(subgraph_isomorphisms(&g, &h).edge_compatibility(|e, f| hw[e] == gw[f]));
+        assert_eq!(vec![vec![(0, 1), (1, 2)], vec![(0, 2), (1, 1)]], m);
+
+        let m = subgraph_isomorphisms(&g, &h)
+            .vertex_compatibility(|u, v| u == 0 && v == 0 || u == 1 && v != 0)
+            .edge_compatibility(|e, f| hw[e] != gw[f])
+            .count();
+        assert_eq!(2, m);
+    }
+
+    #[test]
+    fn random_relabel() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..12 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            let mut perm: Vec<usize> = vec(0..n);
+            perm.shuffle(&mut rng);
+            let edges = vec(g.edges_ends().map(|(u, v)| (perm[u as usize], perm[v as usize])));
+            let h = StaticGraph::new_with_edges(n, edges);
+            assert!(is_isomorphic(&g, &h));
+            let first = subgraph_isomorphisms(&g, &h).next().unwrap();
+            assert!(is_subgraph_isomorphism(&g, &h, &first));
+        }
+        assert!(!is_isomorphic(&star_graph(4), &path_graph(5)));
+    }
+}
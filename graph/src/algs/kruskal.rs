@@ -12,6 +12,7 @@ use unionfind::{NewUnionFind, UnionFind, WithUnionFind};
 
 use fera_fun::vec;
 
+use std::cmp::Ordering;
 use std::ops::DerefMut;
 
 pub trait Visitor<G>
@@ -90,6 +91,20 @@ pub trait Kruskal: WithUnionFind {
         self.kruskal().weight(weight)
     }
 
+    /// Returns the edges of a minimum spanning forest where the edges are considered in the order
+    /// defined by `compare`. Edges that `compare` considers equal are considered in the order of
+    /// `edges()`. This makes it possible to break the ties between edges with the same weight in
+    /// a deterministic way, for example, by comparing the edge indices.
+    fn kruskal_mst_by<F>(
+        &self,
+        compare: F,
+    ) -> KruskalAlg<&Self, Vec<Edge<Self>>, AcceptAll, NewUnionFind<Self>>
+    where
+        F: FnMut(Edge<Self>, Edge<Self>) -> Ordering,
+    {
+        self.kruskal().sort_by(compare)
+    }
+
     fn kruskal(&self) -> KruskalAlg<&Self, AllEdges<Self>, AcceptAll, NewUnionFind<Self>> {
         KruskalAlg(self, AllEdges(self), AcceptAll, NewUnionFind(self))
     }
@@ -111,7 +126,15 @@ where
         W: EdgePropGet<G, T>,
         T: Ord,
     {
-        let edges = vec(self.0.edges()).sorted_by_prop(&w);
+        self.sort_by(|a, b| w.get(a).cmp(&w.get(b)))
+    }
+
+    pub fn sort_by<F>(self, mut compare: F) -> KruskalAlg<&'a G, Vec<Edge<G>>, V, U>
+    where
+        F: FnMut(Edge<G>, Edge<G>) -> Ordering,
+    {
+        let mut edges = vec(self.0.edges());
+        edges.sort_by(|&a, &b| compare(a, b));
         self.edges(edges)
     }
 }
@@ -166,6 +189,36 @@ mod tests {
         assert_eq!(11usize, sum_prop(&weight, &tree));
         assert_eq!(vec![e[0], e[1], e[2], e[4]], tree);
     }
+
+    #[test]
+    fn kruskal_mst_by() {
+        // a cycle with 4 vertices and a chord, all edges with the same weight except (0, 2)
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (3, 0), (0, 2));
+        let mut weight = g.default_edge_prop(1u32);
+        let e = vec(g.edges());
+        weight[e[4]] = 0;
+        let index = g.edge_index();
+
+        // ties broken by the smallest index
+        let tree = vec(g.kruskal_mst_by(|a, b| {
+            weight[a]
+                .cmp(&weight[b])
+                .then(index.get(a).cmp(&index.get(b)))
+        }));
+        assert_eq!(vec![e[4], e[0], e[2]], tree);
+
+        // ties broken by the largest index
+        let tree = vec(g.kruskal_mst_by(|a, b| {
+            weight[a]
+                .cmp(&weight[b])
+                .then(index.get(b).cmp(&index.get(a)))
+        }));
+        assert_eq!(vec![e[4], e[3], e[1]], tree);
+
+        // equal edges are considered in the order of edges()
+        let tree = vec(g.kruskal_mst_by(|a, b| weight[a].cmp(&weight[b])));
+        assert_eq!(vec(g.kruskal_mst(&weight)), tree);
+    }
 }
 
 // TODO: write benchmarks and optimize
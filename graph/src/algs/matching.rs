@@ -0,0 +1,1057 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Matchings in general graphs.
+//!
+//! A matching is a set of edges without common ends (and without loops). The matching is
+//! represented by an edge property that is `true` for the edges in the matching.
+
+use prelude::*;
+
+use num_traits::{One, Zero};
+
+use std::cmp;
+use std::ops::{Add, Div, Sub};
+
+const NONE: usize = usize::MAX;
+
+/// Returns a maximum weight matching of `g` using Edmonds' blossom algorithm.
+///
+/// The algorithm is the primal-dual method of Edmonds with the O(V^3) implementation described by
+/// Galil. Augmenting paths are searched in alternating trees, and each odd cycle (a blossom) found
+/// is shrunk to a single vertex and later expanded. Only edges with positive weight can be in the
+/// returned matching, so the matching does not need to be perfect or to have maximum
+/// cardinality. The loops of `g` are ignored.
+///
+/// If all weights are integers, all computations are exact.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::matching::max_weight_matching;
+///
+/// # fn main() {
+/// // a path with weights 2, 3 and 2
+/// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+/// let e = g.edges().collect::<Vec<_>>();
+/// let mut w = g.default_edge_prop(2i32);
+/// w[e[1]] = 3;
+/// let m = max_weight_matching(&g, &w);
+/// assert_eq!(vec![true, false, true], g.edges().map(|e| m[e]).collect::<Vec<_>>());
+/// # }
+/// ```
+pub fn max_weight_matching<G, W, T>(g: &G, w: W) -> DefaultEdgePropMut<G, bool>
+where
+    G: Graph + WithVertexIndexProp,
+    W: EdgePropGet<G, T>,
+    T: Copy + Ord + Zero + One + Add<Output = T> + Sub<Output = T> + Div<Output = T>,
+{
+    let (ids, edges) = blossom_edges(g, w);
+    let matched = Blossom::new(g.num_vertices(), edges, false).run();
+    matching_prop(g, ids, &matched)
+}
+
+/// Returns a minimum weight perfect matching of `g`, that is, a matching that covers all
+/// vertices of `g` and has minimum total weight, or `None` if `g` has no perfect matching.
+///
+/// Each weight `w(e)` is replaced by `max - w(e)`, where `max` is the maximum weight of an edge,
+/// and a maximum weight matching among the matchings with maximum cardinality is found with the
+/// same blossom algorithm used by [`max_weight_matching`]. If the maximum cardinality matching is
+/// perfect, it has minimum weight with respect to `w`. The running time is O(V^3) and the
+/// weights can be negative. The loops of `g` are ignored.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::matching::min_weight_perfect_matching;
+///
+/// # fn main() {
+/// // a path with weights 2, 1 and 2, the middle edge is not in any perfect matching
+/// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+/// let e = g.edges().collect::<Vec<_>>();
+/// let mut w = g.default_edge_prop(2i32);
+/// w[e[1]] = 1;
+/// let m = min_weight_perfect_matching(&g, &w).unwrap();
+/// assert_eq!(vec![true, false, true], g.edges().map(|e| m[e]).collect::<Vec<_>>());
+///
+/// let g: StaticGraph = graph!(4, (0, 1), (0, 2), (0, 3));
+/// assert!(min_weight_perfect_matching(&g, g.default_edge_prop(1i32)).is_none());
+/// # }
+/// ```
+///
+/// [`max_weight_matching`]: fn.max_weight_matching.html
+pub fn min_weight_perfect_matching<G, W, T>(g: &G, w: W) -> Option<DefaultEdgePropMut<G, bool>>
+where
+    G: Graph + WithVertexIndexProp,
+    W: EdgePropGet<G, T>,
+    T: Copy + Ord + Zero + One + Add<Output = T> + Sub<Output = T> + Div<Output = T>,
+{
+    let n = g.num_vertices();
+    if n % 2 == 1 {
+        return None;
+    }
+    let (ids, mut edges) = blossom_edges(g, w);
+    if let Some(max) = edges.iter().map(|e| e.2).max() {
+        for e in &mut edges {
+            e.2 = max - e.2;
+        }
+    }
+    let matched = Blossom::new(n, edges, true).run();
+    if 2 * matched.iter().filter(|&&m| m).count() == n {
+        Some(matching_prop(g, ids, &matched))
+    } else {
+        None
+    }
+}
+
+type BlossomEdges<G, T> = (Vec<Edge<G>>, Vec<(usize, usize, T)>);
+
+// Returns the edges of g that are not loops and the corresponding (i, j, w) triples used by
+// Blossom, where i and j are the indices of the ends.
+fn blossom_edges<G, W, T>(g: &G, w: W) -> BlossomEdges<G, T>
+where
+    G: Graph + WithVertexIndexProp,
+    W: EdgePropGet<G, T>,
+{
+    let index = g.vertex_index();
+    let mut ids = vec![];
+    let mut edges = vec![];
+    for (e, u, v) in g.edges_with_ends() {
+        let (i, j) = (index.get(u), index.get(v));
+        if i != j {
+            ids.push(e);
+            edges.push((i, j, w.get(e)));
+        }
+    }
+    (ids, edges)
+}
+
+fn matching_prop<G: Graph>(
+    g: &G,
+    ids: Vec<Edge<G>>,
+    matched: &[bool],
+) -> DefaultEdgePropMut<G, bool> {
+    let mut matching = g.default_edge_prop(false);
+    for (e, &m) in ids.into_iter().zip(matched) {
+        matching[e] = m;
+    }
+    matching
+}
+
+/// Returns a maximal matching of `g`, that is, a matching that is not contained in a larger
+/// matching.
+///
+/// The edges are scanned in the order of `g.edges()` and each edge whose ends are both free is
+/// added to the matching. The matching has at least half of the edges of a maximum matching. The
+/// running time is O(V + E).
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::matching::{greedy_maximal_matching, is_valid_matching};
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(4, (1, 2), (0, 1), (2, 3));
+/// let m = greedy_maximal_matching(&g);
+/// assert!(is_valid_matching(&g, &m));
+/// assert_eq!(vec![true, false, false], g.edges().map(|e| m[e]).collect::<Vec<_>>());
+/// # }
+/// ```
+pub fn greedy_maximal_matching<G>(g: &G) -> DefaultEdgePropMut<G, bool>
+where
+    G: Graph,
+{
+    let mut covered = g.default_vertex_prop(false);
+    let mut matching = g.default_edge_prop(false);
+    for (e, u, v) in g.edges_with_ends() {
+        if u != v && !covered[u] && !covered[v] {
+            covered[u] = true;
+            covered[v] = true;
+            matching[e] = true;
+        }
+    }
+    matching
+}
+
+/// Returns `true` if the edges `e` of `g` with `matching[e] == true` are a matching, that is, no
+/// two of them have a common end and none of them is a loop, otherwise `false`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::matching::is_valid_matching;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(3, (0, 1), (1, 2), (2, 2));
+/// let e = g.edges().collect::<Vec<_>>();
+/// let mut m = g.default_edge_prop(false);
+/// m[e[0]] = true;
+/// assert!(is_valid_matching(&g, &m));
+/// m[e[1]] = true;
+/// assert!(!is_valid_matching(&g, &m));
+/// m[e[0]] = false;
+/// m[e[1]] = false;
+/// m[e[2]] = true;
+/// assert!(!is_valid_matching(&g, &m));
+/// # }
+/// ```
+pub fn is_valid_matching<G, P>(g: &G, matching: P) -> bool
+where
+    G: EdgeList + WithVertexProp<bool>,
+    P: EdgePropGet<G, bool>,
+{
+    let mut covered = g.default_vertex_prop(false);
+    for (e, u, v) in g.edges_with_ends() {
+        if matching.get(e) {
+            if u == v || covered[u] || covered[v] {
+                return false;
+            }
+            covered[u] = true;
+            covered[v] = true;
+        }
+    }
+    true
+}
+
+// The state of the blossom algorithm. The vertices are 0..n and the blossoms (other than the
+// trivial ones, which are the vertices) are n..2n. The edge k has endpoints 2k and 2k + 1, and
+// endpoint[p] is the vertex of the endpoint p. NONE is used for a missing vertex, edge, endpoint
+// or blossom.
+struct Blossom<T> {
+    n: usize,
+    edges: Vec<(usize, usize, T)>,
+    endpoint: Vec<usize>,
+    // neighbend[v] is the remote endpoints of the edges incident to v
+    neighbend: Vec<Vec<usize>>,
+    // mate[v] is the remote endpoint of the matched edge of v
+    mate: Vec<usize>,
+    // 0 for unlabeled, 1 for S, 2 for T, 5 is used temporarily by scan_blossom
+    label: Vec<u8>,
+    // the endpoint through which the blossom got its label
+    labelend: Vec<usize>,
+    // the top level blossom that contains each vertex
+    inblossom: Vec<usize>,
+    parent: Vec<usize>,
+    childs: Vec<Vec<usize>>,
+    // endps[b][i] is the endpoint that connects childs[b][i] to childs[b][i + 1]
+    endps: Vec<Vec<usize>>,
+    base: Vec<usize>,
+    // the least slack edge to a different S blossom
+    bestedge: Vec<usize>,
+    blossombestedges: Vec<Option<Vec<usize>>>,
+    unused: Vec<usize>,
+    dual: Vec<T>,
+    allowedge: Vec<bool>,
+    queue: Vec<usize>,
+    // if true, only matchings with maximum cardinality are considered
+    max_cardinality: bool,
+}
+
+impl<T> Blossom<T>
+where
+    T: Copy + Ord + Zero + One + Add<Output = T> + Sub<Output = T> + Div<Output = T>,
+{
+    fn new(n: usize, edges: Vec<(usize, usize, T)>, max_cardinality: bool) -> Self {
+        let m = edges.len();
+        let mut endpoint = Vec::with_capacity(2 * m);
+        let mut neighbend = vec![vec![]; n];
+        for (k, &(i, j, _)) in edges.iter().enumerate() {
+            endpoint.push(i);
+            endpoint.push(j);
+            neighbend[i].push(2 * k + 1);
+            neighbend[j].push(2 * k);
+        }
+        let maxweight = edges.iter().map(|e| e.2).fold(T::zero(), |a, b| a.max(b));
+        let mut dual = vec![maxweight; n];
+        dual.extend(vec![T::zero(); n]);
+        Blossom {
+            n,
+            endpoint,
+            neighbend,
+            mate: vec![NONE; n],
+            label: vec![0; 2 * n],
+            labelend: vec![NONE; 2 * n],
+            inblossom: (0..n).collect(),
+            parent: vec![NONE; 2 * n],
+            childs: vec![vec![]; 2 * n],
+            endps: vec![vec![]; 2 * n],
+            base: (0..n).chain((0..n).map(|_| NONE)).collect(),
+            bestedge: vec![NONE; 2 * n],
+            blossombestedges: vec![None; 2 * n],
+            unused: (n..2 * n).collect(),
+            dual,
+            allowedge: vec![false; m],
+            queue: vec![],
+            max_cardinality,
+            edges,
+        }
+    }
+
+    fn slack(&self, k: usize) -> T {
+        let (i, j, wt) = self.edges[k];
+        self.dual[i] + self.dual[j] - (wt + wt)
+    }
+
+    fn two() -> T {
+        T::one() + T::one()
+    }
+
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = vec![];
+        self.add_leaves(b, &mut leaves);
+        leaves
+    }
+
+    fn add_leaves(&self, b: usize, leaves: &mut Vec<usize>) {
+        if b < self.n {
+            leaves.push(b);
+        } else {
+            for &t in &self.childs[b] {
+                self.add_leaves(t, leaves);
+            }
+        }
+    }
+
+    // Position i of the cyclic list of the children of b, where i may be negative.
+    fn child(&self, b: usize, i: isize) -> usize {
+        let len = self.childs[b].len() as isize;
+        self.childs[b][((i % len + len) % len) as usize]
+    }
+
+    fn endp(&self, b: usize, i: isize) -> usize {
+        let len = self.endps[b].len() as isize;
+        self.endps[b][((i % len + len) % len) as usize]
+    }
+
+    fn assign_label(&mut self, w: usize, t: u8, p: usize) {
+        let b = self.inblossom[w];
+        self.label[w] = t;
+        self.label[b] = t;
+        self.labelend[w] = p;
+        self.labelend[b] = p;
+        self.bestedge[w] = NONE;
+        self.bestedge[b] = NONE;
+        if t == 1 {
+            let leaves = self.leaves(b);
+            self.queue.extend(leaves);
+        } else {
+            // the mate of the base of a T blossom becomes an S vertex
+            let base = self.base[b];
+            let p = self.mate[base];
+            self.assign_label(self.endpoint[p], 1, p ^ 1);
+        }
+    }
+
+    // Traces back from v and w to find a new blossom or an augmenting path. Returns the base of
+    // the new blossom or NONE if an augmenting path was found.
+    fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+        let mut path = vec![];
+        let mut base = NONE;
+        while v != NONE || w != NONE {
+            let mut b = self.inblossom[v];
+            if self.label[b] & 4 != 0 {
+                base = self.base[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = 5;
+            if self.labelend[b] == NONE {
+                // the root of the alternating tree
+                v = NONE;
+            } else {
+                v = self.endpoint[self.labelend[b]];
+                b = self.inblossom[v];
+                v = self.endpoint[self.labelend[b]];
+            }
+            if w != NONE {
+                ::std::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in path {
+            self.label[b] = 1;
+        }
+        base
+    }
+
+    // Creates a new blossom with base `base` formed by the edge k and the tree paths from its ends.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (mut v, mut w, _) = self.edges[k];
+        let bb = self.inblossom[base];
+        let mut bv = self.inblossom[v];
+        let mut bw = self.inblossom[w];
+        let b = self.unused.pop().unwrap();
+        self.base[b] = base;
+        self.parent[b] = NONE;
+        self.parent[bb] = b;
+        let mut path = vec![];
+        let mut endps = vec![];
+        while bv != bb {
+            self.parent[bv] = b;
+            path.push(bv);
+            endps.push(self.labelend[bv]);
+            v = self.endpoint[self.labelend[bv]];
+            bv = self.inblossom[v];
+        }
+        path.push(bb);
+        path.reverse();
+        endps.reverse();
+        endps.push(2 * k);
+        while bw != bb {
+            self.parent[bw] = b;
+            path.push(bw);
+            endps.push(self.labelend[bw] ^ 1);
+            w = self.endpoint[self.labelend[bw]];
+            bw = self.inblossom[w];
+        }
+        self.label[b] = 1;
+        self.labelend[b] = self.labelend[bb];
+        self.dual[b] = T::zero();
+        self.childs[b] = path.clone();
+        self.endps[b] = endps;
+        for v in self.leaves(b) {
+            if self.label[self.inblossom[v]] == 2 {
+                // the T vertices become S vertices
+                self.queue.push(v);
+            }
+            self.inblossom[v] = b;
+        }
+
+        // compute the least slack edges to the other S blossoms
+        let mut bestedgeto = vec![NONE; 2 * self.n];
+        for bv in path {
+            let nblists = match self.blossombestedges[bv].take() {
+                Some(list) => vec![list],
+                None => self
+                    .leaves(bv)
+                    .into_iter()
+                    .map(|v| self.neighbend[v].iter().map(|p| p / 2).collect())
+                    .collect(),
+            };
+            for nblist in nblists {
+                for k in nblist {
+                    let (i, j, _) = self.edges[k];
+                    let j = if self.inblossom[j] == b { i } else { j };
+                    let bj = self.inblossom[j];
+                    if bj != b
+                        && self.label[bj] == 1
+                        && (bestedgeto[bj] == NONE || self.slack(k) < self.slack(bestedgeto[bj]))
+                    {
+                        bestedgeto[bj] = k;
+                    }
+                }
+            }
+            self.bestedge[bv] = NONE;
+        }
+        let list: Vec<_> = bestedgeto.into_iter().filter(|&k| k != NONE).collect();
+        self.bestedge[b] = NONE;
+        for &k in &list {
+            if self.bestedge[b] == NONE || self.slack(k) < self.slack(self.bestedge[b]) {
+                self.bestedge[b] = k;
+            }
+        }
+        self.blossombestedges[b] = Some(list);
+    }
+
+    fn expand_blossom(&mut self, b: usize, endstage: bool) {
+        for s in self.childs[b].clone() {
+            self.parent[s] = NONE;
+            if s < self.n {
+                self.inblossom[s] = s;
+            } else if endstage && self.dual[s] == T::zero() {
+                self.expand_blossom(s, endstage);
+            } else {
+                for v in self.leaves(s) {
+                    self.inblossom[v] = s;
+                }
+            }
+        }
+        if !endstage && self.label[b] == 2 {
+            // relabel the children in the path from the entry child to the base as T and S
+            let entrychild = self.inblossom[self.endpoint[self.labelend[b] ^ 1]];
+            let mut j = self.childs[b]
+                .iter()
+                .position(|&c| c == entrychild)
+                .unwrap() as isize;
+            let (jstep, endptrick) = if j & 1 != 0 {
+                j -= self.childs[b].len() as isize;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+            let mut p = self.labelend[b];
+            while j != 0 {
+                let q = self.endp(b, j - endptrick as isize) ^ endptrick;
+                self.label[self.endpoint[p ^ 1]] = 0;
+                self.label[self.endpoint[q ^ 1]] = 0;
+                self.assign_label(self.endpoint[p ^ 1], 2, p);
+                self.allowedge[q / 2] = true;
+                j += jstep;
+                p = self.endp(b, j - endptrick as isize) ^ endptrick;
+                self.allowedge[p / 2] = true;
+                j += jstep;
+            }
+            let bv = self.child(b, j);
+            let v = self.endpoint[p ^ 1];
+            self.label[v] = 2;
+            self.label[bv] = 2;
+            self.labelend[v] = p;
+            self.labelend[bv] = p;
+            self.bestedge[bv] = NONE;
+            j += jstep;
+            // the other children may be reachable through their own T vertices
+            while self.child(b, j) != entrychild {
+                let bv = self.child(b, j);
+                j += jstep;
+                if self.label[bv] == 1 {
+                    continue;
+                }
+                if let Some(v) = self.leaves(bv).into_iter().find(|&v| self.label[v] != 0) {
+                    self.label[v] = 0;
+                    let base = self.base[bv];
+                    self.label[self.endpoint[self.mate[base]]] = 0;
+                    let p = self.labelend[v];
+                    self.assign_label(v, 2, p);
+                }
+            }
+        }
+        self.label[b] = 0;
+        self.labelend[b] = NONE;
+        self.childs[b] = vec![];
+        self.endps[b] = vec![];
+        self.base[b] = NONE;
+        self.blossombestedges[b] = None;
+        self.bestedge[b] = NONE;
+        self.unused.push(b);
+    }
+
+    // Swaps the matched and unmatched edges of the path inside b from v to the base of b.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.parent[t] != b {
+            t = self.parent[t];
+        }
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+        let i = self.childs[b].iter().position(|&c| c == t).unwrap();
+        let mut j = i as isize;
+        let (jstep, endptrick) = if i & 1 != 0 {
+            j -= self.childs[b].len() as isize;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+        while j != 0 {
+            j += jstep;
+            let t = self.child(b, j);
+            let p = self.endp(b, j - endptrick as isize) ^ endptrick;
+            if t >= self.n {
+                let v = self.endpoint[p];
+                self.augment_blossom(t, v);
+            }
+            j += jstep;
+            let t = self.child(b, j);
+            if t >= self.n {
+                let v = self.endpoint[p ^ 1];
+                self.augment_blossom(t, v);
+            }
+            self.mate[self.endpoint[p]] = p ^ 1;
+            self.mate[self.endpoint[p ^ 1]] = p;
+        }
+        // v becomes the base of b
+        self.childs[b].rotate_left(i);
+        self.endps[b].rotate_left(i);
+        self.base[b] = self.base[self.childs[b][0]];
+    }
+
+    // Swaps the matched and unmatched edges of the augmenting path through the edge k.
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        for &(mut s, mut p) in &[(v, 2 * k + 1), (w, 2 * k)] {
+            loop {
+                let bs = self.inblossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = p;
+                if self.labelend[bs] == NONE {
+                    // the root of the alternating tree
+                    break;
+                }
+                let t = self.endpoint[self.labelend[bs]];
+                let bt = self.inblossom[t];
+                s = self.endpoint[self.labelend[bt]];
+                let j = self.endpoint[self.labelend[bt] ^ 1];
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = self.labelend[bt];
+                p = self.labelend[bt] ^ 1;
+            }
+        }
+    }
+
+    // Returns which edges are in the matching.
+    fn run(mut self) -> Vec<bool> {
+        let n = self.n;
+        // each stage finds an augmenting path or stops
+        for _ in 0..n {
+            for x in &mut self.label {
+                *x = 0;
+            }
+            for x in &mut self.bestedge {
+                *x = NONE;
+            }
+            for x in &mut self.blossombestedges[n..] {
+                *x = None;
+            }
+            for x in &mut self.allowedge {
+                *x = false;
+            }
+            self.queue.clear();
+            for v in 0..n {
+                if self.mate[v] == NONE && self.label[self.inblossom[v]] == 0 {
+                    self.assign_label(v, 1, NONE);
+                }
+            }
+
+            let mut augmented = false;
+            loop {
+                while let Some(v) = self.queue.pop() {
+                    for i in 0..self.neighbend[v].len() {
+                        let p = self.neighbend[v][i];
+                        let k = p / 2;
+                        let w = self.endpoint[p];
+                        if self.inblossom[v] == self.inblossom[w] {
+                            continue;
+                        }
+                        let mut kslack = T::zero();
+                        if !self.allowedge[k] {
+                            kslack = self.slack(k);
+                            if kslack <= T::zero() {
+                                self.allowedge[k] = true;
+                            }
+                        }
+                        if self.allowedge[k] {
+                            if self.label[self.inblossom[w]] == 0 {
+                                self.assign_label(w, 2, p ^ 1);
+                            } else if self.label[self.inblossom[w]] == 1 {
+                                let base = self.scan_blossom(v, w);
+                                if base != NONE {
+                                    self.add_blossom(base, k);
+                                } else {
+                                    self.augment_matching(k);
+                                    augmented = true;
+                                    break;
+                                }
+                            } else if self.label[w] == 0 {
+                                // w is inside a T blossom but was not reached yet
+                                self.label[w] = 2;
+                                self.labelend[w] = p ^ 1;
+                            }
+                        } else if self.label[self.inblossom[w]] == 1 {
+                            let b = self.inblossom[v];
+                            if self.bestedge[b] == NONE || kslack < self.slack(self.bestedge[b]) {
+                                self.bestedge[b] = k;
+                            }
+                        } else if self.label[w] == 0
+                            && (self.bestedge[w] == NONE || kslack < self.slack(self.bestedge[w]))
+                        {
+                            self.bestedge[w] = k;
+                        }
+                    }
+                    if augmented {
+                        break;
+                    }
+                }
+                if augmented {
+                    break;
+                }
+
+                // no augmenting path with the allowed edges, update the dual variables
+                // with max_cardinality, the search stops (deltatype 1) only when no other
+                // update is possible (deltatype 0)
+                let mut deltatype = 0;
+                let mut delta = T::zero();
+                if !self.max_cardinality {
+                    deltatype = 1;
+                    delta = *self.dual[..n].iter().min().unwrap();
+                }
+                let mut deltaedge = NONE;
+                let mut deltablossom = NONE;
+                for v in 0..n {
+                    if self.label[self.inblossom[v]] == 0 && self.bestedge[v] != NONE {
+                        let d = self.slack(self.bestedge[v]);
+                        if deltatype == 0 || d < delta {
+                            delta = d;
+                            deltatype = 2;
+                            deltaedge = self.bestedge[v];
+                        }
+                    }
+                }
+                for b in 0..2 * n {
+                    if self.parent[b] == NONE && self.label[b] == 1 && self.bestedge[b] != NONE {
+                        let d = self.slack(self.bestedge[b]) / Self::two();
+                        if deltatype == 0 || d < delta {
+                            delta = d;
+                            deltatype = 3;
+                            deltaedge = self.bestedge[b];
+                        }
+                    }
+                }
+                for b in n..2 * n {
+                    if self.base[b] != NONE
+                        && self.parent[b] == NONE
+                        && self.label[b] == 2
+                        && (deltatype == 0 || self.dual[b] < delta)
+                    {
+                        delta = self.dual[b];
+                        deltatype = 4;
+                        deltablossom = b;
+                    }
+                }
+                if deltatype == 0 {
+                    // the matching has maximum cardinality and no further improvement is possible
+                    deltatype = 1;
+                    delta = cmp::max(T::zero(), *self.dual[..n].iter().min().unwrap());
+                }
+
+                for v in 0..n {
+                    match self.label[self.inblossom[v]] {
+                        1 => self.dual[v] = self.dual[v] - delta,
+                        2 => self.dual[v] = self.dual[v] + delta,
+                        _ => (),
+                    }
+                }
+                for b in n..2 * n {
+                    if self.base[b] != NONE && self.parent[b] == NONE {
+                        match self.label[b] {
+                            1 => self.dual[b] = self.dual[b] + delta,
+                            2 => self.dual[b] = self.dual[b] - delta,
+                            _ => (),
+                        }
+                    }
+                }
+
+                match deltatype {
+                    // the matching is optimal
+                    1 => break,
+                    2 => {
+                        self.allowedge[deltaedge] = true;
+                        let (i, j, _) = self.edges[deltaedge];
+                        let i = if self.label[self.inblossom[i]] == 0 {
+                            j
+                        } else {
+                            i
+                        };
+                        self.queue.push(i);
+                    }
+                    3 => {
+                        self.allowedge[deltaedge] = true;
+                        let (i, _, _) = self.edges[deltaedge];
+                        self.queue.push(i);
+                    }
+                    _ => self.expand_blossom(deltablossom, false),
+                }
+            }
+
+            if !augmented {
+                break;
+            }
+
+            // expand the S blossoms with zero dual
+            for b in n..2 * n {
+                if self.parent[b] == NONE
+                    && self.base[b] != NONE
+                    && self.label[b] == 1
+                    && self.dual[b] == T::zero()
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+
+        (0..self.edges.len())
+            .map(|k| self.mate[self.edges[k].0] == 2 * k + 1)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::vec;
+    use rand::prelude::*;
+
+    fn weight_of(g: &StaticGraph, w: &DefaultEdgePropMut<StaticGraph, i32>, m: &[bool]) -> i32 {
+        g.edges().zip(m).filter(|p| *p.1).map(|(e, _)| w[e]).sum()
+    }
+
+    fn check_matching(g: &StaticGraph, m: &DefaultEdgePropMut<StaticGraph, bool>) -> Vec<bool> {
+        assert!(is_valid_matching(g, m));
+        vec(g.edges().map(|e| m[e]))
+    }
+
+    // the weight of a maximum weight matching by testing all subsets of edges
+    fn brute(g: &StaticGraph, w: &DefaultEdgePropMut<StaticGraph, i32>) -> i32 {
+        let edges = vec(g.edges());
+        let mut best = 0;
+        for set in 0..1u32 << edges.len() {
+            let mut covered = g.default_vertex_prop(false);
+            let mut weight = 0;
+            let mut valid = true;
+            for (i, &e) in edges.iter().enumerate() {
+                if set & (1 << i) != 0 {
+                    let (u, v) = g.ends(e);
+                    if u == v || covered[u] || covered[v] {
+                        valid = false;
+                        break;
+                    }
+                    covered[u] = true;
+                    covered[v] = true;
+                    weight += w[e];
+                }
+            }
+            if valid {
+                best = best.max(weight);
+            }
+        }
+        best
+    }
+
+    // the weight of a minimum weight perfect matching by testing all subsets of edges
+    fn brute_perfect(g: &StaticGraph, w: &DefaultEdgePropMut<StaticGraph, i32>) -> Option<i32> {
+        let edges = vec(g.edges());
+        let mut best = None;
+        for set in 0..1u32 << edges.len() {
+            let mut covered = g.default_vertex_prop(false);
+            let mut weight = 0;
+            let mut count = 0;
+            for (i, &e) in edges.iter().enumerate() {
+                if set & (1 << i) != 0 {
+                    let (u, v) = g.ends(e);
+                    if u == v || covered[u] || covered[v] {
+                        count = usize::MAX;
+                        break;
+                    }
+                    covered[u] = true;
+                    covered[v] = true;
+                    weight += w[e];
+                    count += 2;
+                }
+            }
+            if count == g.num_vertices() && best.into_iter().all(|b| weight < b) {
+                best = Some(weight);
+            }
+        }
+        best
+    }
+
+    fn new(
+        n: usize,
+        edges: &[(usize, usize, i32)],
+    ) -> (StaticGraph, DefaultEdgePropMut<StaticGraph, i32>) {
+        let g = StaticGraph::new_with_edges(n, edges.iter().map(|&(u, v, _)| (u, v)));
+        let mut w = g.default_edge_prop(0);
+        for (e, &(_, _, x)) in g.edges().zip(edges) {
+            w[e] = x;
+        }
+        (g, w)
+    }
+
+    #[test]
+    fn small() {
+        let (g, w) = new(0, &[]);
+        assert!(check_matching(&g, &max_weight_matching(&g, &w)).is_empty());
+
+        let (g, w) = new(2, &[(0, 1, 1)]);
+        assert_eq!(vec![true], check_matching(&g, &max_weight_matching(&g, &w)));
+
+        let (g, w) = new(3, &[(0, 1, 10), (1, 2, 11)]);
+        assert_eq!(
+            vec![false, true],
+            check_matching(&g, &max_weight_matching(&g, &w))
+        );
+
+        let (g, w) = new(4, &[(0, 1, 5), (1, 2, 11), (2, 3, 5)]);
+        assert_eq!(
+            vec![false, true, false],
+            check_matching(&g, &max_weight_matching(&g, &w))
+        );
+
+        // negative weights and loops are never used
+        let (g, w) = new(3, &[(0, 1, -2), (1, 1, 4), (1, 2, -1)]);
+        assert_eq!(
+            vec![false; 3],
+            check_matching(&g, &max_weight_matching(&g, &w))
+        );
+    }
+
+    #[test]
+    fn odd_cycle() {
+        // a 5-cycle has a maximum matching with 2 edges
+        let (g, w) = new(5, &[(0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 4, 1), (4, 0, 1)]);
+        let m = check_matching(&g, &max_weight_matching(&g, &w));
+        assert_eq!(2, m.iter().filter(|&&x| x).count());
+
+        // the triangle 0, 1, 2 is shrunk to a blossom before the augmentation through (2, 3)
+        let (g, w) = new(4, &[(0, 1, 8), (0, 2, 9), (1, 2, 10), (2, 3, 7)]);
+        let m = check_matching(&g, &max_weight_matching(&g, &w));
+        assert_eq!(vec![true, false, false, true], m);
+        assert_eq!(15, weight_of(&g, &w, &m));
+    }
+
+    #[test]
+    fn nested_blossoms() {
+        // test cases of van Rantwijk's implementation (vertices numbered from 0)
+        let (g, w) = new(
+            6,
+            &[
+                (0, 1, 9),
+                (0, 2, 9),
+                (1, 2, 10),
+                (1, 3, 8),
+                (2, 4, 8),
+                (3, 4, 10),
+                (4, 5, 6),
+            ],
+        );
+        let m = check_matching(&g, &max_weight_matching(&g, &w));
+        assert_eq!(23, weight_of(&g, &w, &m));
+
+        let (g, w) = new(
+            8,
+            &[
+                (0, 1, 23),
+                (0, 4, 22),
+                (0, 5, 15),
+                (1, 2, 25),
+                (2, 3, 22),
+                (3, 4, 25),
+                (3, 7, 14),
+                (4, 6, 13),
+            ],
+        );
+        let m = check_matching(&g, &max_weight_matching(&g, &w));
+        assert_eq!(brute(&g, &w), weight_of(&g, &w, &m));
+
+        let (g, w) = new(
+            10,
+            &[
+                (0, 1, 45),
+                (0, 4, 45),
+                (1, 2, 50),
+                (2, 3, 45),
+                (3, 4, 50),
+                (0, 5, 30),
+                (2, 8, 35),
+                (3, 7, 35),
+                (4, 6, 26),
+                (8, 9, 5),
+            ],
+        );
+        let m = check_matching(&g, &max_weight_matching(&g, &w));
+        assert_eq!(brute(&g, &w), weight_of(&g, &w, &m));
+    }
+
+    #[test]
+    fn perfect() {
+        let (g, w) = new(0, &[]);
+        assert!(check_matching(&g, &min_weight_perfect_matching(&g, &w).unwrap()).is_empty());
+
+        let (g, w) = new(3, &[(0, 1, 1), (1, 2, 1), (2, 0, 1)]);
+        assert!(min_weight_perfect_matching(&g, &w).is_none());
+
+        // the maximum weight matching is the middle edge, but it is not perfect
+        let (g, w) = new(4, &[(0, 1, 5), (1, 2, 11), (2, 3, 5)]);
+        let m = min_weight_perfect_matching(&g, &w).unwrap();
+        assert_eq!(vec![true, false, true], check_matching(&g, &m));
+
+        // a 4-cycle with a chord and a loop
+        let (g, w) = new(
+            4,
+            &[
+                (0, 1, 3),
+                (1, 2, 1),
+                (2, 3, 4),
+                (3, 0, 1),
+                (0, 2, 0),
+                (1, 1, -5),
+            ],
+        );
+        let m = min_weight_perfect_matching(&g, &w).unwrap();
+        assert_eq!(
+            vec![false, true, false, true, false, false],
+            check_matching(&g, &m)
+        );
+    }
+
+    #[test]
+    fn greedy() {
+        let (g, _) = new(4, &[(0, 1, 0), (1, 2, 0), (2, 3, 0)]);
+        let m = check_matching(&g, &greedy_maximal_matching(&g));
+        assert_eq!(vec![true, false, true], m);
+
+        let (g, _) = new(4, &[(0, 0, 0), (1, 2, 0), (0, 1, 0), (2, 3, 0), (3, 0, 0)]);
+        let m = check_matching(&g, &greedy_maximal_matching(&g));
+        assert_eq!(vec![false, true, false, false, true], m);
+    }
+
+    #[test]
+    fn invalid() {
+        let (g, _) = new(4, &[(0, 1, 0), (1, 2, 0), (2, 3, 0), (3, 3, 0)]);
+        let e = vec(g.edges());
+        let mut m = g.default_edge_prop(false);
+        assert!(is_valid_matching(&g, &m));
+        m[e[0]] = true;
+        m[e[2]] = true;
+        assert!(is_valid_matching(&g, &m));
+        m[e[1]] = true;
+        assert!(!is_valid_matching(&g, &m));
+        m[e[1]] = false;
+        m[e[3]] = true;
+        assert!(!is_valid_matching(&g, &m));
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..9 {
+            for _ in 0..20 {
+                let m = rng.gen_range(0, (n * (n - 1) / 2).min(12) + 1);
+                let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+                let mut w = g.default_edge_prop(0);
+                for e in g.edges() {
+                    w[e] = rng.gen_range(-2, 10);
+                }
+                let m = check_matching(&g, &max_weight_matching(&g, &w));
+                assert_eq!(brute(&g, &w), weight_of(&g, &w, &m));
+
+                let m = min_weight_perfect_matching(&g, &w).map(|m| check_matching(&g, &m));
+                assert_eq!(brute_perfect(&g, &w), m.map(|m| weight_of(&g, &w, &m)));
+
+                // no edge can be added to a maximal matching
+                let m = check_matching(&g, &greedy_maximal_matching(&g));
+                let mut covered = g.default_vertex_prop(false);
+                for (e, &x) in g.edges().zip(&m) {
+                    if x {
+                        covered[g.source(e)] = true;
+                        covered[g.target(e)] = true;
+                    }
+                }
+                assert!(g
+                    .edges_ends()
+                    .all(|(u, v)| u == v || covered[u] || covered[v]));
+            }
+        }
+    }
+}
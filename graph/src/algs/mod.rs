@@ -4,18 +4,42 @@
 
 //! Collection of algorithms.
 
+pub mod arborescence;
 pub mod boruvka;
+pub mod centrality;
+pub mod combine;
+pub mod community;
+pub mod compare;
+pub mod complement;
+pub mod contract;
+pub mod cover;
+pub mod cuts;
 pub mod components;
 pub mod cycles;
 pub mod degrees;
 pub mod distances;
+pub mod dynamic_mst;
+pub mod euler;
+pub mod iso;
 pub mod kruskal;
+pub mod matching;
+pub mod neighbors;
+pub mod order;
+#[cfg(feature = "rayon")]
+pub mod parallel;
 pub mod paths;
+pub mod power;
 pub mod prim;
+pub mod relabel;
 pub mod sets;
+pub mod simple;
+pub mod summary;
 pub mod trees;
+pub mod tsp;
 
 pub use self::boruvka::Boruvka;
+pub use self::centrality::Centrality;
+pub use self::complement::Complement;
 pub use self::components::Components;
 pub use self::cycles::Cycles;
 pub use self::degrees::Degrees;
@@ -0,0 +1,236 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Neighborhood sets and similarity of vertices.
+//!
+//! The neighbors of a vertex `v` are the vertices adjacent to `v` other than `v` itself, that is,
+//! loops are ignored and a vertex adjacent by parallel edges is considered only once.
+
+use algs::distances::AllPairsDist;
+use prelude::*;
+
+use std::cmp;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// A measure of the similarity of the neighborhoods of two vertices, used by
+/// [`all_pairs_neighbor_similarity`]. For neighbor sets `A` and `B`, each metric divides `|A ∩ B|`
+/// by a different normalization term. If that term is zero, the similarity is zero.
+///
+/// [`all_pairs_neighbor_similarity`]: fn.all_pairs_neighbor_similarity.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimilarityMetric {
+    /// `|A ∩ B| / |A ∪ B|`.
+    Jaccard,
+    /// `|A ∩ B| / sqrt(|A| |B|)`.
+    Cosine,
+    /// `|A ∩ B| / min(|A|, |B|)`.
+    Overlap,
+}
+
+/// Returns the set of the neighbors of `v`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::neighbors::adjacency_set;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(3, (0, 1), (0, 0), (0, 2), (2, 0));
+/// let n = adjacency_set(&g, 0);
+/// assert_eq!(2, n.len());
+/// assert!(n.contains(&1) && n.contains(&2));
+/// # }
+/// ```
+pub fn adjacency_set<G>(g: &G, v: Vertex<G>) -> HashSet<Vertex<G>>
+where
+    G: AdjacencyGraph,
+{
+    g.out_neighbors(v).filter(|&u| u != v).collect()
+}
+
+/// Returns the vertices that are neighbors of both `u` and `v`, in the order of
+/// `g.out_neighbors(u)`.
+///
+/// The running time is O(deg(u) + deg(v)).
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::neighbors::common_neighbors;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(5, (0, 1), (0, 2), (0, 3), (1, 3), (1, 2), (3, 4));
+/// assert_eq!(vec![2, 3], common_neighbors(&g, 0, 1));
+/// assert!(common_neighbors(&g, 2, 4).is_empty());
+/// # }
+/// ```
+pub fn common_neighbors<G>(g: &G, u: Vertex<G>, v: Vertex<G>) -> Vec<Vertex<G>>
+where
+    G: AdjacencyGraph,
+{
+    let mut neighbors = adjacency_set(g, v);
+    // each common neighbor is removed from the set, so parallel edges do not create duplicates
+    g.out_neighbors(u)
+        .filter(|&w| w != u && neighbors.remove(&w))
+        .collect()
+}
+
+/// Returns the Jaccard similarity of `u` and `v`, that is, the number of common neighbors of `u`
+/// and `v` divided by the number of vertices that are neighbors of `u` or `v`. If neither `u` nor
+/// `v` have neighbors, the similarity is zero.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::neighbors::jaccard_similarity;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(5, (0, 1), (0, 2), (0, 3), (1, 3), (1, 2), (3, 4));
+/// // {1, 2, 3} and {0, 2, 3}
+/// assert_eq!(0.5, jaccard_similarity(&g, 0, 1));
+/// # }
+/// ```
+pub fn jaccard_similarity<G>(g: &G, u: Vertex<G>, v: Vertex<G>) -> f64
+where
+    G: AdjacencyGraph,
+{
+    similarity(SimilarityMetric::Jaccard, &adjacency_set(g, u), &adjacency_set(g, v))
+}
+
+/// Returns the similarity of the neighborhoods of every pair of vertices of `g` using `metric`.
+/// The similarity of `u` and `v` can be obtained with `dist(u, v)` or by indexing with `(u, v)`
+/// and is always `Some`. The matrix is symmetric, and the similarity of a vertex with itself is
+/// `1` if the vertex has neighbors and `0` otherwise, as with [`jaccard_similarity`]. The `path`
+/// method of the result is not meaningful.
+///
+/// The neighbor sets are computed once, but the similarity of each pair is computed by
+/// intersecting two sets, so the running time is O(V² d), where d is the maximum degree. This
+/// can be expensive for large graphs.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::neighbors::{all_pairs_neighbor_similarity, SimilarityMetric};
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(5, (0, 1), (0, 2), (0, 3), (1, 3), (1, 2), (3, 4));
+/// // {1, 2, 3} and {0, 2, 3}
+/// let jaccard = all_pairs_neighbor_similarity(&g, SimilarityMetric::Jaccard);
+/// assert_eq!(Some(0.5), jaccard.dist(0, 1));
+/// let overlap = all_pairs_neighbor_similarity(&g, SimilarityMetric::Overlap);
+/// assert_eq!(Some(2.0 / 3.0), overlap.dist(1, 0));
+/// // {0, 1} and {3}
+/// let cosine = all_pairs_neighbor_similarity(&g, SimilarityMetric::Cosine);
+/// assert_eq!(Some(0.0), cosine.dist(2, 4));
+/// # }
+/// ```
+///
+/// [`jaccard_similarity`]: fn.jaccard_similarity.html
+pub fn all_pairs_neighbor_similarity<G>(g: &G, metric: SimilarityMetric) -> AllPairsDist<G, f64>
+where
+    G: AdjacencyGraph + WithVertexIndexProp,
+{
+    let vertices: Vec<_> = g.vertices().collect();
+    let sets: Vec<_> = vertices.iter().map(|&v| adjacency_set(g, v)).collect();
+    let mut apd = AllPairsDist::new(g);
+    for (i, &u) in vertices.iter().enumerate() {
+        for (j, &v) in vertices.iter().enumerate().skip(i) {
+            let s = similarity(metric, &sets[i], &sets[j]);
+            apd.set(u, v, s, v);
+            apd.set(v, u, s, u);
+        }
+    }
+    apd
+}
+
+fn similarity<T: Eq + Hash>(metric: SimilarityMetric, a: &HashSet<T>, b: &HashSet<T>) -> f64 {
+    let common = a.intersection(b).count();
+    let norm = match metric {
+        SimilarityMetric::Jaccard => (a.len() + b.len() - common) as f64,
+        SimilarityMetric::Cosine => ((a.len() * b.len()) as f64).sqrt(),
+        SimilarityMetric::Overlap => cmp::min(a.len(), b.len()) as f64,
+    };
+    if norm == 0.0 {
+        0.0
+    } else {
+        common as f64 / norm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::set;
+    use rand::prelude::*;
+
+    #[test]
+    fn loops_and_parallel_edges() {
+        let g: StaticGraph = graph!(4, (0, 0), (0, 1), (0, 1), (1, 2), (0, 2), (2, 0), (2, 2));
+        assert_eq!(set(vec![1, 2]), adjacency_set(&g, 0));
+        assert_eq!(set(vec![0, 1]), adjacency_set(&g, 2));
+        assert_eq!(vec![1], common_neighbors(&g, 0, 2));
+        assert_eq!(vec![2], common_neighbors(&g, 0, 1));
+        assert!(common_neighbors(&g, 0, 0).contains(&1));
+        // {1, 2} and {0, 1}
+        assert_eq!(1.0 / 3.0, jaccard_similarity(&g, 0, 2));
+        assert_eq!(1.0, jaccard_similarity(&g, 0, 0));
+        assert_eq!(0.0, jaccard_similarity(&g, 3, 3));
+        assert_eq!(0.0, jaccard_similarity(&g, 0, 3));
+    }
+
+    #[test]
+    fn complete() {
+        let g = CompleteGraph::new(5);
+        assert_eq!(vec![1, 3, 4], common_neighbors(&g, 0, 2));
+        // {1, 2, 3, 4} and {0, 2, 3, 4}
+        assert_eq!(0.6, jaccard_similarity(&g, 0, 1));
+    }
+
+    #[test]
+    fn all_pairs_complete() {
+        for n in 1..8 {
+            let g = CompleteGraph::new(n);
+            let jaccard = all_pairs_neighbor_similarity(&g, SimilarityMetric::Jaccard);
+            let overlap = all_pairs_neighbor_similarity(&g, SimilarityMetric::Overlap);
+            let cosine = all_pairs_neighbor_similarity(&g, SimilarityMetric::Cosine);
+            let d = if n == 1 { 0.0 } else { 1.0 };
+            for u in g.vertices() {
+                assert_eq!(Some(d), jaccard[(u, u)]);
+                assert_eq!(Some(d), overlap[(u, u)]);
+                assert_eq!(Some(d), cosine[(u, u)]);
+                for v in g.vertices().filter(|&v| v != u) {
+                    let n = f64::from(n);
+                    assert_eq!(Some((n - 2.0) / n), jaccard[(u, v)]);
+                    assert_eq!(Some((n - 2.0) / (n - 1.0)), overlap[(u, v)]);
+                    assert_eq!(Some((n - 2.0) / (n - 1.0)), cosine[(u, v)]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn all_pairs_random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..15 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            let jaccard = all_pairs_neighbor_similarity(&g, SimilarityMetric::Jaccard);
+            for u in g.vertices() {
+                for v in g.vertices() {
+                    assert_eq!(Some(jaccard_similarity(&g, u, v)), jaccard[(u, v)]);
+                    assert_eq!(jaccard[(u, v)], jaccard[(v, u)]);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Vertex orderings.
+
+use prelude::*;
+use props::Color;
+use traverse::*;
+
+use std::cmp;
+
+/// Returns the [degeneracy] `d` of `g` and a degeneracy ordering of the vertices of `g`, that
+/// is, an ordering where each vertex has at most `d` neighbors that come later in the ordering.
+///
+/// The ordering is found by repeatedly removing a vertex with minimum degree (the same process
+/// used to compute the k-cores of a graph), using a bucket queue indexed by the degree. This
+/// takes O(V + E) time. Loops are ignored and parallel edges are counted once for each edge.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::order::degeneracy_ordering;
+///
+/// # fn main() {
+/// // a triangle with a pendant vertex
+/// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 0), (2, 3));
+/// let (d, order) = degeneracy_ordering(&g);
+/// assert_eq!(2, d);
+/// assert_eq!(3, order[0]);
+/// # }
+/// ```
+///
+/// [degeneracy]: https://en.wikipedia.org/wiki/Degeneracy_(graph_theory)
+pub fn degeneracy_ordering<G>(g: &G) -> (usize, Vec<Vertex<G>>)
+where
+    G: IncidenceGraph,
+{
+    let mut degree = g.default_vertex_prop(0usize);
+    let mut removed = g.default_vertex_prop(false);
+    let mut buckets: Vec<Vec<Vertex<G>>> = vec![];
+    for v in g.vertices() {
+        let d = g.out_neighbors(v).filter(|&u| u != v).count();
+        degree[v] = d;
+        if buckets.len() <= d {
+            buckets.resize(d + 1, vec![]);
+        }
+        buckets[d].push(v);
+    }
+    // a vertex may be in more than one bucket, only the entry that matches its current degree
+    // is valid
+    let mut order = Vec::with_capacity(g.num_vertices());
+    let mut degeneracy = 0;
+    let mut i = 0;
+    while order.len() < g.num_vertices() {
+        let v = match buckets[i].pop() {
+            Some(v) => v,
+            None => {
+                i += 1;
+                continue;
+            }
+        };
+        if removed[v] || degree[v] != i {
+            continue;
+        }
+        degeneracy = cmp::max(degeneracy, i);
+        removed[v] = true;
+        order.push(v);
+        for u in g.out_neighbors(v) {
+            if !removed[u] {
+                degree[u] -= 1;
+                buckets[degree[u]].push(u);
+            }
+        }
+        // the neighbors degrees are at least i - 1
+        i = i.saturating_sub(1);
+    }
+    (degeneracy, order)
+}
+
+/// Returns the vertices of `g` in the order they are discovered by a breadth-first search. The
+/// roots of the search are taken in the order of `g.vertices()`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::order::bfs_order;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(5, (0, 1), (1, 2), (0, 3));
+/// assert_eq!(vec![0, 1, 3, 2, 4], bfs_order(&g));
+/// # }
+/// ```
+pub fn bfs_order<G>(g: &G) -> Vec<Vertex<G>>
+where
+    G: Incidence + VertexList + WithVertexProp<Color>,
+{
+    let mut order = Vec::with_capacity(g.num_vertices());
+    g.bfs(OnDiscoverVertex(|v| order.push(v))).run();
+    order
+}
+
+/// Returns the vertices of `g` in the order they are discovered by a depth-first search (that
+/// is, in preorder). The roots of the search are taken in the order of `g.vertices()`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::order::dfs_order;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(5, (0, 1), (1, 2), (0, 3));
+/// assert_eq!(vec![0, 1, 2, 3, 4], dfs_order(&g));
+/// # }
+/// ```
+pub fn dfs_order<G>(g: &G) -> Vec<Vertex<G>>
+where
+    G: Incidence + VertexList + WithVertexProp<Color>,
+{
+    let mut order = Vec::with_capacity(g.num_vertices());
+    g.dfs(OnDiscoverVertex(|v| order.push(v))).run();
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::{set, vec};
+    use rand::prelude::*;
+
+    fn max_later_neighbors<G: IncidenceGraph>(g: &G, order: &[Vertex<G>]) -> usize {
+        let mut pos = g.default_vertex_prop(0usize);
+        for (i, &v) in order.iter().enumerate() {
+            pos[v] = i;
+        }
+        order
+            .iter()
+            .map(|&v| g.out_neighbors(v).filter(|&u| pos[u] > pos[v]).count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn degeneracy_tree() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 2..20 {
+            let g = StaticGraph::new_random_tree(n, &mut rng);
+            let (d, order) = degeneracy_ordering(&g);
+            assert_eq!(1, d);
+            assert_eq!(1, max_later_neighbors(&g, &order));
+        }
+    }
+
+    #[test]
+    fn degeneracy_complete() {
+        for n in 1..10 {
+            let g = CompleteGraph::new(n);
+            let (d, order) = degeneracy_ordering(&g);
+            assert_eq!(n as usize - 1, d);
+            assert_eq!(n as usize - 1, max_later_neighbors(&g, &order));
+        }
+    }
+
+    #[test]
+    fn degeneracy_random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..30 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            let (d, order) = degeneracy_ordering(&g);
+            assert_eq!(set(g.vertices()), set(order.iter().cloned()));
+            assert_eq!(d, max_later_neighbors(&g, &order));
+        }
+    }
+
+    #[test]
+    fn traversal_orders() {
+        let g: StaticGraph = graph!(6, (0, 1), (0, 2), (1, 3), (2, 4));
+        assert_eq!(vec![0, 1, 2, 3, 4, 5], bfs_order(&g));
+        assert_eq!(vec![0, 1, 3, 2, 4, 5], dfs_order(&g));
+        let g = StaticGraph::new_empty(0);
+        assert_eq!(vec(g.vertices()), bfs_order(&g));
+        assert_eq!(vec(g.vertices()), dfs_order(&g));
+    }
+}
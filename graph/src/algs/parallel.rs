@@ -0,0 +1,161 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Parallel algorithms using [rayon].
+//!
+//! This module is available only when the `rayon` feature is enabled.
+//!
+//! [rayon]: https://docs.rs/rayon
+
+use prelude::*;
+
+use fera_unionfind::UnionFindRange;
+use rayon::prelude::*;
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Computes the connected components of `g` in parallel.
+///
+/// The vertices are split in chunks, one for each thread, and each thread builds a union find
+/// with the edges incident to the vertices of its chunk. The union finds are then merged. Each
+/// thread uses `O(V)` memory.
+///
+/// Returns a property that maps each vertex to the index of its component. The components are
+/// numbered in the same way as [`Components::connected_components`], that is, the components are
+/// numbered from `0` in the order of the first vertex of each component in `g.vertices()`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::parallel::connected_components_parallel;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(5, (0, 3), (1, 2));
+/// let comp = connected_components_parallel(&g);
+/// assert_eq!(vec![0, 1, 1, 0, 2], g.vertices().map(|v| comp[v]).collect::<Vec<_>>());
+/// # }
+/// ```
+///
+/// [`Components::connected_components`]: ../components/trait.Components.html#method.connected_components
+pub fn connected_components_parallel<G>(g: &G) -> DefaultVertexPropMut<G, usize>
+where
+    G: IncidenceGraph + WithVertexIndexProp + Sync,
+    Vertex<G>: Send + Sync,
+    VertexIndexProp<G>: Sync,
+{
+    let n = g.num_vertices();
+    let vertices: Vec<_> = g.vertices().collect();
+    let index = g.vertex_index();
+    let chunk = (n / ::rayon::current_num_threads()).max(1);
+    let locals: Vec<_> = vertices
+        .par_chunks(chunk)
+        .map(|vs| {
+            let mut ds = UnionFindRange::with_keys_in_range(..n);
+            for &u in vs {
+                let i = index.get(u);
+                for v in g.out_neighbors(u) {
+                    let j = index.get(v);
+                    if !ds.in_same_set(i, j) {
+                        ds.union(i, j);
+                    }
+                }
+            }
+            ds
+        }).collect();
+    let mut ds = UnionFindRange::with_keys_in_range(..n);
+    for mut local in locals {
+        for i in 0..n {
+            let r = local.find_set(i);
+            if !ds.in_same_set(i, r) {
+                ds.union(i, r);
+            }
+        }
+    }
+    relabel(g, &vertices, |i| ds.find_set(i))
+}
+
+/// Computes the connected components of `g` in parallel using label propagation.
+///
+/// Each vertex starts with its own label and, in each round, all vertices are processed in
+/// parallel and take the smallest label of its neighbors. The rounds stops when no label changes.
+/// The number of rounds is at most the diameter of the largest component plus one, so this is
+/// fast for graphs with small diameter and uses only `O(V)` memory, independently of the number
+/// of threads.
+///
+/// The components are numbered as in [`connected_components_parallel`].
+///
+/// [`connected_components_parallel`]: fn.connected_components_parallel.html
+pub fn connected_components_label_propagation<G>(g: &G) -> DefaultVertexPropMut<G, usize>
+where
+    G: IncidenceGraph + WithVertexIndexProp + Sync,
+    Vertex<G>: Send + Sync,
+    VertexIndexProp<G>: Sync,
+{
+    let vertices: Vec<_> = g.vertices().collect();
+    let index = g.vertex_index();
+    let labels: Vec<_> = (0..vertices.len()).map(AtomicUsize::new).collect();
+    let changed = AtomicBool::new(true);
+    while changed.swap(false, Ordering::Relaxed) {
+        vertices.par_iter().for_each(|&u| {
+            let i = index.get(u);
+            let min = g
+                .out_neighbors(u)
+                .map(|v| labels[index.get(v)].load(Ordering::Relaxed))
+                .min()
+                .unwrap_or(i);
+            if min < labels[i].fetch_min(min, Ordering::Relaxed) {
+                changed.store(true, Ordering::Relaxed);
+            }
+        });
+    }
+    relabel(g, &vertices, |i| labels[i].load(Ordering::Relaxed))
+}
+
+// Numbers the components in the order of the first vertex of each component. `root(i)` is the
+// index of a representative vertex of the component of the vertex with index `i`.
+fn relabel<G, F>(g: &G, vertices: &[Vertex<G>], mut root: F) -> DefaultVertexPropMut<G, usize>
+where
+    G: IncidenceGraph + WithVertexIndexProp,
+    F: FnMut(usize) -> usize,
+{
+    let index = g.vertex_index();
+    let none = usize::max_value();
+    let mut ids = vec![none; vertices.len()];
+    let mut comp = g.default_vertex_prop(0usize);
+    let mut next = 0;
+    for &v in vertices {
+        let r = root(index.get(v));
+        if ids[r] == none {
+            ids[r] = next;
+            next += 1;
+        }
+        comp[v] = ids[r];
+    }
+    comp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algs::Components;
+    use fera_fun::vec;
+    use rand::prelude::*;
+
+    #[test]
+    fn random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..100 {
+            let m = rng.gen_range(0, 2 * n).min(n * (n - 1) / 2);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            let cc = g.connected_components();
+            let expected = vec(g.vertices().map(|v| cc.component(v)));
+            let par = connected_components_parallel(&g);
+            assert_eq!(expected, vec(g.vertices().map(|v| par[v])));
+            let lp = connected_components_label_propagation(&g);
+            assert_eq!(expected, vec(g.vertices().map(|v| lp[v])));
+        }
+    }
+}
@@ -4,11 +4,19 @@
 
 //! Paths related algorithms, including find path between two vertices.
 
+use algs::prim::QueueItem;
+use algs::Cycles;
 use params::IntoOwned;
 use prelude::*;
-use props::Color;
+use props::{Color, FnProp};
 use traverse::*;
 
+use num_traits::Zero;
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::ops::{Add, Sub};
+
 pub trait Paths: Incidence {
     fn find_path(&self, u: Vertex<Self>, v: Vertex<Self>) -> Option<Vec<Edge<Self>>>
     where
@@ -105,10 +113,936 @@ pub trait Paths: Incidence {
                     })
                     .unwrap_or(false)
     }
+
+    /// Finds the shortest path distances from `source` to every vertex using the Bellman-Ford
+    /// algorithm. Edge weights can be negative.
+    ///
+    /// Returns `Ok((dist, pred))` where `dist[v]` is `Some(d)` if `d` is the length of a shortest
+    /// path from `source` to `v` or `None` if `v` is not reachable from `source`, and `pred[v]` is
+    /// the last edge of that path (`pred[source]` is none). If a negative cycle is reachable from
+    /// `source`, returns `Err(cycle)` where `cycle` are the edges of a negative cycle in the walk
+    /// order. Note that in an undirected graph each negative edge forms a negative cycle.
+    ///
+    /// The running time is O(VE).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Paths;
+    ///
+    /// # fn main() {
+    /// let (g, mut w): (StaticDigraph, _) = graph!(
+    ///     3,
+    ///     (0, 1) -> 4i32,
+    ///     (0, 2) -> 1,
+    ///     (2, 1) -> -2,
+    ///     (1, 2) -> 3
+    /// );
+    /// let e = g.edges().collect::<Vec<_>>();
+    /// let (dist, pred) = g.bellman_ford(&w, 0).unwrap();
+    /// assert_eq!(Some(-1), dist[1u32]);
+    /// assert_eq!(Some(e[2]), pred[1u32].into_option());
+    ///
+    /// // 1 -> 2 -> 1 has weight -1
+    /// w[e[3]] = 1;
+    /// let cycle = g.bellman_ford(&w, 0).err().unwrap();
+    /// assert_eq!(vec![e[2], e[3]], cycle);
+    /// # }
+    /// ```
+    fn bellman_ford<W, T>(
+        &self,
+        w: W,
+        source: Vertex<Self>,
+    ) -> Result<
+        (
+            DefaultVertexPropMut<Self, Option<T>>,
+            DefaultVertexPropMut<Self, OptionEdge<Self>>,
+        ),
+        Vec<Edge<Self>>,
+    >
+    where
+        Self: VertexList + WithVertexProp<Option<T>> + WithVertexProp<OptionEdge<Self>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Add<Output = T> + Zero,
+    {
+        let n = self.num_vertices();
+        let mut dist = self.default_vertex_prop(None);
+        let mut pred = self.default_vertex_prop(Self::edge_none());
+        dist[source] = Some(T::zero());
+
+        // After n - 1 rounds all distances are final, unless there is a negative cycle. In this
+        // case the n-th round relaxes an edge.
+        let mut last = None;
+        for _ in 0..n {
+            last = None;
+            for u in self.vertices() {
+                let du = match dist[u] {
+                    Some(du) => du,
+                    None => continue,
+                };
+                for e in self.out_edges(u) {
+                    let v = self.target(e);
+                    let d = du + w.get(e);
+                    if dist[v].map(|dv| d < dv).unwrap_or(true) {
+                        dist[v] = Some(d);
+                        pred[v] = Self::edge_some(e);
+                        last = Some(v);
+                    }
+                }
+            }
+            if last.is_none() {
+                return Ok((dist, pred));
+            }
+        }
+
+        // Walking back n edges from a vertex relaxed in the n-th round reaches a vertex in a
+        // negative cycle.
+        let mut v = last.unwrap();
+        for _ in 0..n {
+            v = self.source(pred[v].into_option().unwrap());
+        }
+        let mut cycle = vec![];
+        let mut u = v;
+        loop {
+            let e = pred[u].into_option().unwrap();
+            cycle.push(e);
+            u = self.source(e);
+            if u == v {
+                break;
+            }
+        }
+        cycle.reverse();
+        Err(cycle)
+    }
+
+    /// Finds a shortest path from `source` to `goal` using the [A*] search algorithm. Edge weights
+    /// must be non-negative.
+    ///
+    /// The `heuristic` property estimates the length of a shortest path from a vertex to `goal`.
+    /// It can be any vertex property, for example, a closure wrapped in [`FnProp`] or a default
+    /// vertex property filled in advance. The vertices are explored in non-decreasing order of
+    /// `d + heuristic.get(v)`, where `d` is the length of the shortest path from `source` to `v`
+    /// found so far, and the search stops as soon as `goal` is explored. If the heuristic is
+    /// admissible (that is, it never overestimates the length of a shortest path), the returned
+    /// path is a shortest path. With a heuristic that always returns zero this is Dijkstra's
+    /// algorithm (see [`dijkstra`]), and both use the same search code.
+    ///
+    /// Returns `Some((d, path))` where `path` are the edges of the path from `source` to `goal`
+    /// and `d` is its length, or `None` if `goal` is not reachable from `source`. If `source ==
+    /// goal`, returns `Some((zero, vec![]))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Paths;
+    /// use fera_graph::props::FnProp;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (0, 3), (3, 2));
+    /// let e = g.edges().collect::<Vec<_>>();
+    /// let mut w = g.default_edge_prop(1u32);
+    /// w[e[0]] = 5;
+    /// assert_eq!(Some((2, vec![e[2], e[3]])), g.astar(&w, 0, 2, FnProp(|_| 0)));
+    /// assert_eq!(Some((0, vec![])), g.astar(&w, 1, 1, FnProp(|_| 0)));
+    ///
+    /// // the number of edges to 2 is an admissible heuristic
+    /// let mut h = g.default_vertex_prop(1u32);
+    /// h[2u32] = 0;
+    /// h[0u32] = 2;
+    /// assert_eq!(Some((2, vec![e[2], e[3]])), g.astar(&w, 0, 2, &h));
+    /// # }
+    /// ```
+    ///
+    /// [A*]: https://en.wikipedia.org/wiki/A*_search_algorithm
+    /// [`dijkstra`]: #method.dijkstra
+    /// [`FnProp`]: ../../props/struct.FnProp.html
+    fn astar<W, T, H>(
+        &self,
+        w: W,
+        source: Vertex<Self>,
+        goal: Vertex<Self>,
+        heuristic: H,
+    ) -> Option<(T, Vec<Edge<Self>>)>
+    where
+        Self: WithVertexProp<Option<T>> + WithVertexProp<OptionEdge<Self>>,
+        W: EdgePropGet<Self, T>,
+        H: VertexPropGet<Self, T>,
+        T: Copy + Ord + Add<Output = T> + Zero,
+    {
+        let (dist, pred) = best_first_search(self, w, source, Some(goal), |v| heuristic.get(v));
+        dist[goal].map(|d| (d, pred_path(self, &pred, goal)))
+    }
+
+    /// Finds the shortest path distances from `source` using Dijkstra's algorithm. Edge weights
+    /// must be non-negative.
+    ///
+    /// Returns `(dist, pred)` where `dist[v]` is `Some(d)` if `d` is the length of a shortest path
+    /// from `source` to `v` or `None` if `v` is not reachable from `source`, and `pred[v]` is the
+    /// last edge of that path (`pred[source]` is none).
+    ///
+    /// If `target` is `Some(t)`, the search stops as soon as the distance to `t` is known. In this
+    /// case only the distances that are not greater than the distance to `t` are final, the
+    /// others may be `None` or greater than the length of a shortest path.
+    ///
+    /// The running time is O((V + E) log V).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Paths;
+    ///
+    /// # fn main() {
+    /// let (g, w): (StaticDigraph, _) = graph!(
+    ///     4,
+    ///     (0, 1) -> 1u32,
+    ///     (1, 2) -> 1,
+    ///     (0, 2) -> 3,
+    ///     (2, 3) -> 5
+    /// );
+    /// let e = g.edges().collect::<Vec<_>>();
+    /// let (dist, pred) = g.dijkstra(&w, 0, None);
+    /// assert_eq!(
+    ///     vec![Some(0), Some(1), Some(2), Some(7)],
+    ///     g.vertices().map(|v| dist[v]).collect::<Vec<_>>()
+    /// );
+    /// assert_eq!(Some(e[1]), pred[2u32].into_option());
+    /// assert_eq!(None, pred[0u32].into_option());
+    ///
+    /// let (dist, _) = g.dijkstra(&w, 0, Some(1));
+    /// assert_eq!(Some(1), dist[1u32]);
+    /// // not final, the shortest path from 0 to 2 has length 2
+    /// assert_eq!(Some(3), dist[2u32]);
+    /// assert_eq!(None, dist[3u32]);
+    /// # }
+    /// ```
+    fn dijkstra<W, T>(
+        &self,
+        w: W,
+        source: Vertex<Self>,
+        target: Option<Vertex<Self>>,
+    ) -> (
+        DefaultVertexPropMut<Self, Option<T>>,
+        DefaultVertexPropMut<Self, OptionEdge<Self>>,
+    )
+    where
+        Self: WithVertexProp<Option<T>> + WithVertexProp<OptionEdge<Self>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Add<Output = T> + Zero,
+    {
+        best_first_search(self, w, source, target, |_| T::zero())
+    }
+
+    /// Finds a shortest path from `source` to `target` using Dijkstra's algorithm. Edge weights
+    /// must be non-negative. The search stops as soon as the distance to `target` is known.
+    ///
+    /// Returns `Some((d, path))` where `path` are the edges of the path from `source` to `target`
+    /// and `d` is its length, or `None` if `target` is not reachable from `source`. If `source ==
+    /// target`, returns `Some((zero, vec![]))`.
+    ///
+    /// This is the same as [`astar`] with a heuristic that always returns zero. See [`dijkstra`]
+    /// for the distances from `source` to every vertex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Paths;
+    ///
+    /// # fn main() {
+    /// let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (0, 2));
+    /// let e = g.edges().collect::<Vec<_>>();
+    /// let mut w = g.default_edge_prop(1u32);
+    /// w[e[2]] = 3;
+    /// assert_eq!(Some((2, vec![e[0], e[1]])), g.shortest_path(&w, 0, 2));
+    /// assert_eq!(None, g.shortest_path(&w, 2, 0));
+    /// # }
+    /// ```
+    ///
+    /// [`astar`]: #method.astar
+    /// [`dijkstra`]: #method.dijkstra
+    fn shortest_path<W, T>(
+        &self,
+        w: W,
+        source: Vertex<Self>,
+        target: Vertex<Self>,
+    ) -> Option<(T, Vec<Edge<Self>>)>
+    where
+        Self: WithVertexProp<Option<T>> + WithVertexProp<OptionEdge<Self>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Add<Output = T> + Zero,
+    {
+        self.astar(w, source, target, FnProp(|_| T::zero()))
+    }
+
+    /// Finds up to `k` shortest simple paths from `source` to `target` using Yen's algorithm.
+    /// Edge weights must be non-negative.
+    ///
+    /// Each path is found by deviating from one of the previous paths: for each vertex `u` of the
+    /// previous path (except `target`), the edges that leave `u` in the previous paths with the
+    /// same prefix up to `u` are removed, as are the vertices of the prefix, and a shortest path
+    /// from `u` to `target` (see [`shortest_path`]) is appended to the prefix. The shortest of
+    /// these candidate paths is the next path.
+    ///
+    /// Returns a vector of `(d, path)` sorted by `d`, where `path` are the edges of the path and
+    /// `d` is its length. If there are less than `k` simple paths, all of them are returned. If
+    /// `source == target`, only the empty path is returned.
+    ///
+    /// Each path requires O(V) calls to [`shortest_path`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Paths;
+    ///
+    /// # fn main() {
+    /// let (g, w): (StaticDigraph, _) = graph!(
+    ///     4,
+    ///     (0, 1) -> 1u32,
+    ///     (1, 3) -> 1,
+    ///     (0, 2) -> 1,
+    ///     (2, 3) -> 2,
+    ///     (0, 3) -> 4
+    /// );
+    /// let e = g.edges().collect::<Vec<_>>();
+    /// let paths = g.k_shortest_paths(&w, 0, 3, 2);
+    /// assert_eq!(vec![(2, vec![e[0], e[1]]), (3, vec![e[2], e[3]])], paths);
+    /// assert_eq!(3, g.k_shortest_paths(&w, 0, 3, 5).len());
+    /// # }
+    /// ```
+    ///
+    /// [`shortest_path`]: #method.shortest_path
+    fn k_shortest_paths<W, T>(
+        &self,
+        w: W,
+        source: Vertex<Self>,
+        target: Vertex<Self>,
+        k: usize,
+    ) -> Vec<(T, Vec<Edge<Self>>)>
+    where
+        Self: VertexList + EdgeList + WithVertexProp<bool> + WithEdgeProp<bool>,
+        Self: WithVertexProp<Option<T>> + WithVertexProp<OptionEdge<Self>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Add<Output = T> + Zero,
+    {
+        let mut paths: Vec<(T, Vec<Edge<Self>>)> = vec![];
+        let mut candidates = vec![];
+        if k == 0 {
+            return paths;
+        }
+        match self.shortest_path(&w, source, target) {
+            Some(path) => paths.push(path),
+            None => return paths,
+        }
+        let mut vertex_ok = self.default_vertex_prop(true);
+        let mut edge_ok = self.default_edge_prop(true);
+        while paths.len() < k {
+            let prev = paths.last().unwrap().1.clone();
+            let mut root_len = T::zero();
+            for i in 0..prev.len() {
+                let spur = self.source(prev[i]);
+                let root = &prev[..i];
+                let removed = paths
+                    .iter()
+                    .map(|p| &p.1)
+                    .filter(|p| p.len() > i && &p[..i] == root)
+                    .map(|p| p[i]);
+                edge_ok.set_values(removed, false);
+                for &e in root {
+                    vertex_ok[self.source(e)] = false;
+                }
+
+                let filtered = {
+                    let (vertex_ok, edge_ok) = (&vertex_ok, &edge_ok);
+                    self.filter(FnProp(move |v| vertex_ok[v]), FnProp(move |e| edge_ok[e]))
+                };
+                if let Some((d, spur_path)) = filtered.shortest_path(&w, spur, target) {
+                    let mut path = root.to_vec();
+                    path.extend(spur_path);
+                    let candidate = (root_len + d, path);
+                    if !candidates.contains(&candidate) {
+                        candidates.push(candidate);
+                    }
+                }
+
+                vertex_ok.set_values(root.iter().map(|&e| self.source(e)), true);
+                edge_ok.set_values(paths.iter().filter_map(|p| p.1.get(i).cloned()), true);
+                root_len = root_len + w.get(prev[i]);
+            }
+            // the first candidate with minimum length
+            let best = (0..candidates.len()).min_by_key(|&i| candidates[i].0);
+            match best {
+                Some(i) => paths.push(candidates.remove(i)),
+                None => break,
+            }
+        }
+        paths
+    }
+
+    /// Finds two edge-disjoint paths from `source` to `target` with minimum total length using
+    /// Suurballe's algorithm. Edge weights must be non-negative.
+    ///
+    /// A first shortest path is found with Dijkstra's algorithm, the edges are reweighted by the
+    /// distances from `source` (so all weights remain non-negative) and the edges of the first
+    /// path are reversed with weight zero. A second shortest path in this graph may go back along
+    /// the first path; the edges used in both directions are discarded, and the remaining edges
+    /// of the two paths form the two disjoint paths.
+    ///
+    /// Returns `None` if there are no two edge-disjoint paths from `source` to `target`. If
+    /// `source == target`, returns two empty paths. The running time is that of two runs of
+    /// Dijkstra's algorithm, that is, O((V + E) log V).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Paths;
+    ///
+    /// # fn main() {
+    /// let (g, w): (StaticDigraph, _) = graph!(
+    ///     4,
+    ///     (0, 1) -> 1u32,
+    ///     (1, 3) -> 1,
+    ///     (0, 2) -> 2,
+    ///     (2, 3) -> 2
+    /// );
+    /// let e = g.edges().collect::<Vec<_>>();
+    /// let (p, q) = g.edge_disjoint_shortest_pair(&w, 0, 3).unwrap();
+    /// let mut paths = vec![p, q];
+    /// paths.sort();
+    /// assert_eq!(vec![vec![e[0], e[1]], vec![e[2], e[3]]], paths);
+    /// assert_eq!(None, g.edge_disjoint_shortest_pair(&w, 0, 1));
+    /// # }
+    /// ```
+    fn edge_disjoint_shortest_pair<W, T>(
+        &self,
+        w: W,
+        source: Vertex<Self>,
+        target: Vertex<Self>,
+    ) -> Option<(Vec<Edge<Self>>, Vec<Edge<Self>>)>
+    where
+        Self: IncidenceDigraph + WithEdgeProp<bool> + WithVertexProp<Vec<Edge<Self>>>,
+        Self: WithVertexProp<Option<T>> + WithVertexProp<OptionEdge<Self>>,
+        Self: WithVertexProp<Option<usize>>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Add<Output = T> + Sub<Output = T> + Zero,
+    {
+        if source == target {
+            return Some((vec![], vec![]));
+        }
+
+        let mut on_first = self.default_edge_prop(false);
+        let (dist, pred) = residual_dijkstra(self, source, &on_first, |e| Some(w.get(e)));
+        dist[target]?;
+        let mut v = target;
+        while let Some(e) = pred[v].into_option() {
+            on_first[e] = true;
+            v = self.source(e);
+        }
+
+        // the reduced weights w(u, v) + dist(u) - dist(v) are non-negative and zero in the first
+        // path, the vertices not reachable from source are ignored
+        let (_, pred) = residual_dijkstra(self, source, &on_first, |e| {
+            let (u, v) = self.ends(e);
+            dist[u].map(|du| w.get(e) + du - dist[v].unwrap())
+        });
+        pred[target].into_option()?;
+
+        // keep the edges of the first path that are not used backwards by the second path, and
+        // the edges of the second path that are not backwards
+        let mut used = on_first;
+        let mut v = target;
+        while let Some(e) = pred[v].into_option() {
+            if used[e] {
+                used[e] = false;
+                v = self.target(e);
+            } else {
+                used[e] = true;
+                v = self.source(e);
+            }
+        }
+        let mut out = self.default_vertex_prop(Vec::<Edge<Self>>::new());
+        for e in self.edges().filter(|&e| used[e]) {
+            out[self.source(e)].push(e);
+        }
+
+        let mut pos = self.default_vertex_prop(None::<usize>);
+        let mut walk = || {
+            let mut path: Vec<Edge<Self>> = vec![];
+            let mut u = source;
+            pos[u] = Some(0);
+            while u != target {
+                let e = out[u].pop().unwrap();
+                path.push(e);
+                u = self.target(e);
+                // discard the cycles with zero weight
+                if let Some(i) = pos[u] {
+                    for e in path.drain(i..) {
+                        pos[self.target(e)] = None;
+                    }
+                    pos[u] = Some(i);
+                } else {
+                    pos[u] = Some(path.len());
+                }
+            }
+            pos[source] = None;
+            for &e in &path {
+                pos[self.target(e)] = None;
+            }
+            path
+        };
+        let first = walk();
+        let second = walk();
+        Some((first, second))
+    }
+
+    /// Finds a longest path (the path with maximum total weight) in a DAG by relaxing the edges in
+    /// topological order (see [`Cycles::topological_sort`]). The path can start at any vertex, so
+    /// the length is at least zero. To find the path with the maximum number of edges (the
+    /// critical path) use a weight of one for every edge.
+    ///
+    /// Returns `(d, path)` where `path` are the edges of the path and `d` is its length.
+    ///
+    /// The running time is O(V + E).
+    ///
+    /// # Panics
+    ///
+    /// If the graph is not a DAG.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Paths;
+    /// use fera_graph::props::FnProp;
+    ///
+    /// # fn main() {
+    /// let g: StaticDigraph = graph!(4, (0, 1), (1, 3), (0, 2), (2, 3));
+    /// let e = g.edges().collect::<Vec<_>>();
+    /// let mut w = g.default_edge_prop(1u32);
+    /// w[e[2]] = 3;
+    /// assert_eq!((4, vec![e[2], e[3]]), g.dag_longest_path(&w));
+    /// assert_eq!(2, g.dag_longest_path(FnProp(|_| 1u32)).0);
+    /// # }
+    /// ```
+    ///
+    /// [`Cycles::topological_sort`]: ../cycles/trait.Cycles.html#method.topological_sort
+    fn dag_longest_path<W, T>(&self, w: W) -> (T, Vec<Edge<Self>>)
+    where
+        Self: VertexList + WithVertexProp<T> + WithVertexProp<OptionEdge<Self>>,
+        Self: WithVertexProp<Color>,
+        W: EdgePropGet<Self, T>,
+        T: Copy + Ord + Add<Output = T> + Zero,
+    {
+        let order = self.topological_sort().expect("the graph is not a DAG");
+        let mut dist = self.default_vertex_prop(T::zero());
+        let mut pred = self.default_vertex_prop(Self::edge_none());
+        let mut last = None;
+        for &u in &order {
+            if last.map(|v| dist[v] < dist[u]).unwrap_or(true) {
+                last = Some(u);
+            }
+            for e in self.out_edges(u) {
+                let v = self.target(e);
+                let d = dist[u] + w.get(e);
+                if dist[v] < d {
+                    dist[v] = d;
+                    pred[v] = Self::edge_some(e);
+                }
+            }
+        }
+        let mut path = vec![];
+        let mut v = match last {
+            Some(v) => v,
+            None => return (T::zero(), path),
+        };
+        let d = dist[v];
+        while let Some(e) = pred[v].into_option() {
+            path.push(e);
+            v = self.source(e);
+        }
+        path.reverse();
+        (d, path)
+    }
+
+    /// Returns the vertices that are reachable from `source` by a walk with at most `max_hops`
+    /// edges. The vertices are returned in breadth first order, so `source` is the first vertex
+    /// and the vertices at distance `i` come before the vertices at distance `i + 1`.
+    ///
+    /// Only the edges incident to the vertices at distance less than `max_hops` are visited, so
+    /// the running time depends only on the size of the returned neighborhood (plus the time to
+    /// create a vertex property).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Paths;
+    ///
+    /// let g = CompleteGraph::new(4);
+    /// assert_eq!(vec![2], g.reachable_within(2, 0));
+    /// assert_eq!(vec![2, 0, 1, 3], g.reachable_within(2, 1));
+    /// ```
+    fn reachable_within(&self, source: Vertex<Self>, max_hops: usize) -> Vec<Vertex<Self>>
+    where
+        Self: WithVertexProp<bool>,
+    {
+        let mut visited = self.default_vertex_prop(false);
+        let mut vertices = vec![source];
+        visited[source] = true;
+        // vertices[start..] are the vertices of the last explored distance
+        let mut start = 0;
+        for _ in 0..max_hops {
+            let end = vertices.len();
+            if start == end {
+                break;
+            }
+            for i in start..end {
+                let u = vertices[i];
+                for v in self.out_neighbors(u) {
+                    if !visited[v] {
+                        visited[v] = true;
+                        vertices.push(v);
+                    }
+                }
+            }
+            start = end;
+        }
+        vertices
+    }
+
+    /// Returns an iterator over all simple paths from `source` to `target`. Each path is a vector
+    /// with its edges. If `max_len` is `Some(k)`, only the paths with at most `k` edges are
+    /// returned. If `source == target`, only the empty path is returned.
+    ///
+    /// The paths are generated lazily by a backtracking depth first search, so the iterator can be
+    /// used to count or to take some of the paths, even if the number of paths is exponential.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Paths;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (0, 2), (2, 3));
+    /// let e = g.edges().collect::<Vec<_>>();
+    /// let paths: Vec<_> = g.all_simple_paths(0, 3, None).collect();
+    /// assert_eq!(vec![vec![e[0], e[1], e[3]], vec![e[2], e[3]]], paths);
+    /// assert_eq!(1, g.all_simple_paths(0, 3, Some(2)).count());
+    /// assert_eq!(Some(vec![]), g.all_simple_paths(1, 1, None).next());
+    /// # }
+    /// ```
+    fn all_simple_paths<'a>(
+        &'a self,
+        source: Vertex<Self>,
+        target: Vertex<Self>,
+        max_len: Option<usize>,
+    ) -> SimplePaths<'a, Self>
+    where
+        Self: WithVertexProp<bool>,
+    {
+        let max_len = max_len.unwrap_or_else(usize::max_value);
+        let mut on_path = self.default_vertex_prop(false);
+        on_path[source] = true;
+        let stack = if source != target && max_len > 0 {
+            vec![self.out_edges(source)]
+        } else {
+            vec![]
+        };
+        SimplePaths {
+            g: self,
+            target,
+            max_len,
+            on_path,
+            path: vec![],
+            stack,
+            empty: source == target,
+        }
+    }
+
+    /// Creates the transitive closure of this digraph, that is, a digraph with the same number of
+    /// vertices and with an edge `(u, v)` if `v` is reachable from `u` by a walk with at least
+    /// one edge. This implies that the closure has a loop `(v, v)` only if `v` is in a cycle.
+    ///
+    /// The vertex `v` of this digraph corresponds to the vertex `vertex_index().get(v)` of the
+    /// closure.
+    ///
+    /// If the digraph is acyclic, the vertices are processed in reverse topological order (see
+    /// [`Cycles::topological_sort`]) and the set of vertices reachable from `u` is the union of
+    /// the out neighbors of `u` and their (already computed) reachable sets. The sets are stored
+    /// as bit vectors, so the running time is O(V^2 + VE / w), where `w` is the number of bits
+    /// of a word. Otherwise, a search is done from each vertex and the running time is
+    /// O(V(V + E)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Paths;
+    ///
+    /// # fn main() {
+    /// let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (2, 1));
+    /// let c = g.transitive_closure();
+    /// assert_eq!(6, c.num_edges());
+    /// assert!(c.get_edge_by_ends(0, 2).is_some());
+    /// assert!(c.get_edge_by_ends(1, 1).is_some());
+    /// assert!(c.get_edge_by_ends(0, 0).is_none());
+    /// # }
+    /// ```
+    ///
+    /// [`Cycles::topological_sort`]: trait.Cycles.html#method.topological_sort
+    fn transitive_closure(&self) -> StaticDigraph
+    where
+        Self: IncidenceDigraph + WithVertexIndexProp,
+    {
+        let index = self.vertex_index();
+        let mut edges = vec![];
+        if let Some(order) = self.topological_sort() {
+            const BITS: usize = 64;
+            let n = self.num_vertices();
+            let words = n / BITS + 1;
+            // reach[i] is the set of the indices of the vertices reachable from the vertex i
+            let mut reach = vec![vec![]; n];
+            for &u in order.iter().rev() {
+                let mut set = vec![0u64; words];
+                for v in self.out_neighbors(u) {
+                    let j = index.get(v);
+                    set[j / BITS] |= 1 << (j % BITS);
+                    for (a, &b) in set.iter_mut().zip(&reach[j]) {
+                        *a |= b;
+                    }
+                }
+                reach[index.get(u)] = set;
+            }
+            for (i, set) in reach.iter().enumerate() {
+                for j in (0..n).filter(|&j| set[j / BITS] & (1 << (j % BITS)) != 0) {
+                    edges.push((i, j));
+                }
+            }
+        } else {
+            // stamp[v] == i if v was reached from the i-th vertex
+            let mut stamp = self.default_vertex_prop(usize::max_value());
+            let mut stack = vec![];
+            for (i, u) in self.vertices().enumerate() {
+                stack.extend(self.out_neighbors(u));
+                while let Some(v) = stack.pop() {
+                    if stamp[v] == i {
+                        continue;
+                    }
+                    stamp[v] = i;
+                    edges.push((index.get(u), index.get(v)));
+                    stack.extend(self.out_neighbors(v));
+                }
+            }
+        }
+        let mut b = StaticDigraph::builder(self.num_vertices(), edges.len());
+        for (u, v) in edges {
+            b.add_edge(u, v);
+        }
+        b.finalize()
+    }
 }
 
 impl<G> Paths for G where G: Incidence {}
 
+// Explores the vertices reachable from `source` in non-decreasing order of `d + heuristic(v)`,
+// where `d` is the length of the shortest path from `source` to `v` found so far, and stops when
+// `goal` is explored. Returns the distance and the edge used to reach each vertex. This is used
+// by `astar` and `dijkstra`.
+fn best_first_search<G, W, T, H>(
+    g: &G,
+    w: W,
+    source: Vertex<G>,
+    goal: Option<Vertex<G>>,
+    mut heuristic: H,
+) -> (
+    DefaultVertexPropMut<G, Option<T>>,
+    DefaultVertexPropMut<G, OptionEdge<G>>,
+)
+where
+    G: Incidence + WithVertexProp<Option<T>> + WithVertexProp<OptionEdge<G>>,
+    W: EdgePropGet<G, T>,
+    H: FnMut(Vertex<G>) -> T,
+    T: Copy + Ord + Add<Output = T> + Zero,
+{
+    let mut dist = g.default_vertex_prop(None);
+    let mut pred = g.default_vertex_prop(G::edge_none());
+    let mut queue = BinaryHeap::new();
+    dist[source] = Some(T::zero());
+    // ties are broken in favor of the longest path, which explores less vertices when there are
+    // many shortest paths
+    queue.push(QueueItem::new(
+        (heuristic(source), Reverse(T::zero())),
+        source,
+    ));
+    while let Some(QueueItem {
+        prio: (_, Reverse(du)),
+        vertex: u,
+    }) = queue.pop()
+    {
+        if dist[u].map(|d| d < du).unwrap_or(false) {
+            // the vertex was already explored with a smaller distance
+            continue;
+        }
+        if Some(u) == goal {
+            break;
+        }
+        for e in g.out_edges(u) {
+            let v = g.target(e);
+            let dv = du + w.get(e);
+            if dist[v].map(|d| dv < d).unwrap_or(true) {
+                dist[v] = Some(dv);
+                pred[v] = G::edge_some(e);
+                queue.push(QueueItem::new((dv + heuristic(v), Reverse(dv)), v));
+            }
+        }
+    }
+    (dist, pred)
+}
+
+// Returns the edges of the path that ends in `v` following the edges in `pred`.
+fn pred_path<G, P>(g: &G, pred: &P, mut v: Vertex<G>) -> Vec<Edge<G>>
+where
+    G: WithEdge,
+    P: VertexProp<G, OptionEdge<G>>,
+{
+    let mut path = vec![];
+    while let Some(e) = pred[v].into_option() {
+        path.push(e);
+        v = g.source(e);
+    }
+    path.reverse();
+    path
+}
+
+// Dijkstra's algorithm from `source` in the graph where the edges `e` with `reversed[e]` go from
+// the target to the source with weight zero, and the other edges have weight `w(e)` or are
+// ignored if `w(e)` is `None`. Returns the distance and the edge used to reach each vertex.
+fn residual_dijkstra<G, R, W, T>(
+    g: &G,
+    source: Vertex<G>,
+    reversed: &R,
+    mut w: W,
+) -> (
+    DefaultVertexPropMut<G, Option<T>>,
+    DefaultVertexPropMut<G, OptionEdge<G>>,
+)
+where
+    G: IncidenceDigraph + WithVertexProp<Option<T>> + WithVertexProp<OptionEdge<G>>,
+    R: EdgeProp<G, bool>,
+    W: FnMut(Edge<G>) -> Option<T>,
+    T: Copy + Ord + Add<Output = T> + Zero,
+{
+    // back[v] is the reversed edge that leaves v
+    let mut back = g.default_vertex_prop(G::edge_none());
+    for e in g.edges().filter(|&e| reversed[e]) {
+        back[g.target(e)] = G::edge_some(e);
+    }
+    let mut dist = g.default_vertex_prop(None);
+    let mut pred = g.default_vertex_prop(G::edge_none());
+    let mut queue = BinaryHeap::new();
+    dist[source] = Some(T::zero());
+    queue.push(QueueItem::new(Reverse(T::zero()), source));
+    while let Some(QueueItem {
+        prio: Reverse(du),
+        vertex: u,
+    }) = queue.pop()
+    {
+        if dist[u].map(|d| d < du).unwrap_or(false) {
+            continue;
+        }
+        let forward = g
+            .out_edges(u)
+            .filter(|&e| !reversed[e])
+            .filter_map(|e| w(e).map(|x| (e, g.target(e), x)));
+        let backward = back[u].into_option().map(|e| (e, g.source(e), T::zero()));
+        for (e, v, x) in forward.collect::<Vec<_>>().into_iter().chain(backward) {
+            let dv = du + x;
+            if dist[v].map(|d| dv < d).unwrap_or(true) {
+                dist[v] = Some(dv);
+                pred[v] = G::edge_some(e);
+                queue.push(QueueItem::new(Reverse(dv), v));
+            }
+        }
+    }
+    (dist, pred)
+}
+
+/// An iterator over the simple paths between two vertices. See [`Paths::all_simple_paths`].
+///
+/// [`Paths::all_simple_paths`]: trait.Paths.html#method.all_simple_paths
+pub struct SimplePaths<'a, G>
+where
+    G: 'a + Incidence + WithVertexProp<bool>,
+{
+    g: &'a G,
+    target: Vertex<G>,
+    max_len: usize,
+    on_path: DefaultVertexPropMut<G, bool>,
+    // the edges of the current path and the remaining out edges of each vertex in the path
+    path: Vec<Edge<G>>,
+    stack: Vec<OutEdgeIter<'a, G>>,
+    // true if the empty path (source == target) must be returned
+    empty: bool,
+}
+
+impl<'a, G> Iterator for SimplePaths<'a, G>
+where
+    G: 'a + Incidence + WithVertexProp<bool>,
+{
+    type Item = Vec<Edge<G>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.empty {
+            self.empty = false;
+            return Some(vec![]);
+        }
+        loop {
+            let next = match self.stack.last_mut() {
+                Some(iter) => iter.next(),
+                None => return None,
+            };
+            match next {
+                Some(e) => {
+                    let v = self.g.target(e);
+                    if self.on_path[v] {
+                        continue;
+                    }
+                    if v == self.target {
+                        let mut path = self.path.clone();
+                        path.push(e);
+                        return Some(path);
+                    }
+                    if self.path.len() + 1 < self.max_len {
+                        self.on_path[v] = true;
+                        self.path.push(e);
+                        self.stack.push(self.g.out_edges(v));
+                    }
+                }
+                None => {
+                    self.stack.pop();
+                    if let Some(e) = self.path.pop() {
+                        self.on_path[self.g.target(e)] = false;
+                    }
+                }
+            }
+        }
+    }
+}
+
 pub struct RecordPath<'a, G: WithEdge> {
     path: &'a mut Vec<Edge<G>>,
     target: Vertex<G>,
@@ -141,8 +1075,10 @@ impl<'a, G: WithEdge> Visitor<G> for RecordPath<'a, G> {
 #[cfg(test)]
 mod tests {
     use super::Paths;
-    use fera_fun::vec;
+    use fera_fun::{set, vec};
+    use named::path_graph;
     use prelude::*;
+    use props::FnProp;
 
     #[test]
     fn find_path() {
@@ -158,6 +1094,463 @@ mod tests {
         assert_eq!(vec![e[0], e[1], e[4]], g.find_path(1, 4).unwrap());
     }
 
+    #[test]
+    fn bellman_ford() {
+        let g: StaticDigraph = graph!(5, (0, 1), (0, 2), (1, 3), (2, 1), (3, 2));
+        let e = vec(g.edges());
+        let mut w = g.default_edge_prop(0i32);
+        w[e[0]] = 4;
+        w[e[1]] = 5;
+        w[e[2]] = 3;
+        w[e[3]] = -3;
+        w[e[4]] = 2;
+        let (dist, pred) = g.bellman_ford(&w, 0).unwrap();
+        assert_eq!(
+            vec![Some(0), Some(2), Some(5), Some(5), None],
+            vec(g.vertices().map(|v| dist[v]))
+        );
+        assert_eq!(
+            vec![None, Some(e[3]), Some(e[1]), Some(e[2]), None],
+            vec(g.vertices().map(|v| pred[v].into_option()))
+        );
+
+        let (dist, _) = g.bellman_ford(&w, 3).unwrap();
+        assert_eq!(
+            vec![None, Some(-1), Some(2), Some(0), None],
+            vec(g.vertices().map(|v| dist[v]))
+        );
+
+        // 1 -> 3 -> 2 -> 1 has weight -1
+        w[e[3]] = -6;
+        let cycle = g.bellman_ford(&w, 0).unwrap_err();
+        assert_eq!(3, cycle.len());
+        assert!(g.is_walk(&cycle));
+        assert_eq!(g.source(cycle[0]), g.target(cycle[2]));
+        assert_eq!(-1, cycle.iter().map(|&e| w[e]).sum::<i32>());
+
+        // the negative cycle is not reachable from 4
+        assert!(g.bellman_ford(&w, 4).is_ok());
+    }
+
+    #[test]
+    fn bellman_ford_undirected_negative_edge() {
+        let g: StaticGraph = graph!(3, (0, 1), (1, 2));
+        let e = vec(g.edges());
+        let mut w = g.default_edge_prop(1i32);
+        assert_eq!(Some(2), g.bellman_ford(&w, 0).unwrap().0[2u32]);
+        w[e[1]] = -1;
+        let cycle = g.bellman_ford(&w, 0).unwrap_err();
+        assert_eq!(vec![e[1], e[1]], cycle);
+    }
+
+    #[test]
+    fn astar_grid() {
+        use std::cell::Cell;
+
+        // 10 x 10 grid, the vertex r * 10 + c is in row r and column c
+        let n = 10;
+        let mut edges = vec![];
+        for r in 0..n {
+            for c in 0..n {
+                if c + 1 < n {
+                    edges.push((r * n + c, r * n + c + 1));
+                }
+                if r + 1 < n {
+                    edges.push((r * n + c, (r + 1) * n + c));
+                }
+            }
+        }
+        let g = StaticGraph::new_with_edges(n * n, edges);
+        let w = g.default_edge_prop(1u32);
+        let (source, goal) = (0, 5 * n as u32 + 6);
+
+        let count = Cell::new(0);
+        let (d, path) = g
+            .astar(
+                &w,
+                source,
+                goal,
+                FnProp(|_| {
+                    count.set(count.get() + 1);
+                    0
+                }),
+            )
+            .unwrap();
+        let dijkstra_count = count.get();
+        assert_eq!(11, d);
+        assert_eq!(11, path.len());
+
+        count.set(0);
+        let (d, path) = g
+            .astar(
+                &w,
+                source,
+                goal,
+                FnProp(|v| {
+                    count.set(count.get() + 1);
+                    let (r, c) = ((v as usize / n) as i32, (v as usize % n) as i32);
+                    ((r - 5).abs() + (c - 6).abs()) as u32
+                }),
+            )
+            .unwrap();
+        assert_eq!(11, d);
+        assert!(g.is_path(&path));
+        assert_eq!(source, g.source(path[0]));
+        assert_eq!(goal, g.target(path[10]));
+        assert!(count.get() < dijkstra_count);
+
+        let h = g.default_vertex_prop_from_fn(|v| {
+            let (r, c) = ((v as usize / n) as i32, (v as usize % n) as i32);
+            ((r - 5).abs() + (c - 6).abs()) as u32
+        });
+        assert_eq!(Some((d, path)), g.astar(&w, source, goal, &h));
+    }
+
+    #[test]
+    fn astar_random() {
+        use rand::prelude::*;
+
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..20 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticDigraph::new_gnm(n, m, &mut rng).unwrap();
+            let mut w = g.default_edge_prop(0u32);
+            for e in g.edges() {
+                w[e] = rng.gen_range(0, 10);
+            }
+            let s = g.vertices().next().unwrap();
+            let (dist, _) = g.bellman_ford(&w, s).unwrap();
+            for v in g.vertices() {
+                match g.astar(&w, s, v, FnProp(|_| 0)) {
+                    Some((d, path)) => {
+                        assert_eq!(dist[v], Some(d));
+                        assert!(g.is_walk(&path));
+                        assert_eq!(d, path.iter().map(|&e| w[e]).sum::<u32>());
+                    }
+                    None => assert_eq!(None, dist[v]),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dijkstra_random() {
+        use rand::prelude::*;
+
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..20 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticDigraph::new_gnm(n, m, &mut rng).unwrap();
+            let w = g.default_edge_prop_from_fn(|_| rng.gen_range(0, 10u32));
+            let s = g.vertices().next().unwrap();
+            let (expected, _) = g.bellman_ford(&w, s).unwrap();
+            let (dist, pred) = g.dijkstra(&w, s, None);
+            assert_eq!(None, pred[s].into_option());
+            for v in g.vertices() {
+                assert_eq!(expected[v], dist[v]);
+                if let Some(e) = pred[v].into_option() {
+                    assert_eq!(v, g.target(e));
+                    assert_eq!(dist[v], dist[g.source(e)].map(|d| d + w[e]));
+                }
+                let (dist_t, _) = g.dijkstra(&w, s, Some(v));
+                assert_eq!(expected[v], dist_t[v]);
+            }
+        }
+    }
+
+    #[test]
+    fn shortest_path() {
+        let g = path_graph(6);
+        let e = vec(g.edges());
+        let mut w = g.default_edge_prop(0u32);
+        for (i, &e) in e.iter().enumerate() {
+            w[e] = i as u32 + 1;
+        }
+        let v = vec(g.vertices());
+
+        assert_eq!(Some((0, vec![])), g.shortest_path(&w, v[2], v[2]));
+        assert_eq!(Some((1, vec![e[0]])), g.shortest_path(&w, v[0], v[1]));
+        assert_eq!(
+            Some((9, vec![e[1], e[2], e[3]])),
+            g.shortest_path(&w, v[1], v[4])
+        );
+
+        // the path is in the walk order also when going backwards
+        let (d, path) = g.shortest_path(&w, v[5], v[0]).unwrap();
+        assert_eq!(15, d);
+        assert_eq!(vec(e.iter().rev().cloned()), path);
+        assert!(g.is_path(&path));
+        assert_eq!(v[5], g.source(path[0]));
+        assert_eq!(v[0], g.target(path[4]));
+
+        let g: StaticDigraph = graph!(3, (0, 1));
+        let w = g.default_edge_prop(1u32);
+        assert_eq!(None, g.shortest_path(&w, 1, 0));
+        assert_eq!(None, g.shortest_path(&w, 0, 2));
+    }
+
+    #[test]
+    fn k_shortest_paths() {
+        // C D E F G H
+        let (g, w): (StaticDigraph, _) = graph!(
+            6,
+            (0, 1) -> 3u32,
+            (0, 2) -> 2,
+            (1, 3) -> 4,
+            (2, 1) -> 1,
+            (2, 3) -> 2,
+            (2, 4) -> 3,
+            (3, 4) -> 2,
+            (3, 5) -> 1,
+            (4, 5) -> 2
+        );
+        let e = vec(g.edges());
+        let paths = g.k_shortest_paths(&w, 0, 5, 3);
+        assert_eq!((5, vec![e[1], e[4], e[7]]), paths[0]);
+        assert_eq!((7, vec![e[1], e[5], e[8]]), paths[1]);
+        assert_eq!(8, paths[2].0);
+        assert_eq!(
+            vec![5, 7, 8, 8, 8],
+            vec(g.k_shortest_paths(&w, 0, 5, 5).iter().map(|p| p.0))
+        );
+        assert_eq!(7, g.k_shortest_paths(&w, 0, 5, 100).len());
+        assert!(g.k_shortest_paths(&w, 0, 5, 0).is_empty());
+        assert!(g.k_shortest_paths(&w, 5, 0, 3).is_empty());
+        assert_eq!(vec![(0, vec![])], g.k_shortest_paths(&w, 2, 2, 3));
+    }
+
+    #[test]
+    fn k_shortest_paths_random() {
+        use rand::prelude::*;
+        let mut rng = SmallRng::from_entropy();
+        for n in 2..8 {
+            let m = rng.gen_range(n - 1, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm_connected(n, m, &mut rng).unwrap();
+            let mut w = g.default_edge_prop(0u32);
+            for e in g.edges() {
+                w[e] = rng.gen_range(0, 5);
+            }
+            let (s, t) = (0, n as u32 - 1);
+            let mut expected = vec(g
+                .all_simple_paths(s, t, None)
+                .map(|p| p.iter().map(|&e| w[e]).sum::<u32>()));
+            expected.sort();
+            let k = rng.gen_range(1, expected.len() + 2);
+            let paths = g.k_shortest_paths(&w, s, t, k);
+            expected.truncate(k);
+            assert_eq!(expected, vec(paths.iter().map(|p| p.0)));
+            for (d, p) in paths {
+                assert!(g.is_path(&p));
+                assert_eq!(d, p.iter().map(|&e| w[e]).sum::<u32>());
+                assert_eq!(s, g.source(p[0]));
+                assert_eq!(t, g.target(*p.last().unwrap()));
+            }
+        }
+    }
+
+    #[test]
+    fn edge_disjoint_shortest_pair() {
+        // removing the shortest path 0, 1, 2, 3 disconnects 3 from 0
+        let (g, w): (StaticDigraph, _) = graph!(
+            4,
+            (0, 1) -> 1u32,
+            (1, 2) -> 1,
+            (2, 3) -> 1,
+            (0, 2) -> 2,
+            (1, 3) -> 2
+        );
+        let e = vec(g.edges());
+        let (p, q) = g.edge_disjoint_shortest_pair(&w, 0, 3).unwrap();
+        assert_eq!(
+            set(vec![vec![e[0], e[4]], vec![e[3], e[2]]]),
+            set(vec![p, q])
+        );
+        assert_eq!(None, g.edge_disjoint_shortest_pair(&w, 0, 1).map(|_| ()));
+        assert_eq!(None, g.edge_disjoint_shortest_pair(&w, 3, 0).map(|_| ()));
+        assert_eq!(
+            Some((vec![], vec![])),
+            g.edge_disjoint_shortest_pair(&w, 1, 1)
+        );
+    }
+
+    #[test]
+    fn edge_disjoint_shortest_pair_random() {
+        use rand::prelude::*;
+        let mut rng = SmallRng::from_entropy();
+        for n in 2..8 {
+            for _ in 0..10 {
+                let m = rng.gen_range(0, n * (n - 1) + 1);
+                let g = StaticDigraph::new_gnm(n, m, &mut rng).unwrap();
+                let mut w = g.default_edge_prop(0u32);
+                for e in g.edges() {
+                    w[e] = rng.gen_range(0, 5);
+                }
+                let len = |p: &[Edge<StaticDigraph>]| p.iter().map(|&e| w[e]).sum::<u32>();
+                let (s, t) = (0, n as u32 - 1);
+                let paths = vec(g.all_simple_paths(s, t, None));
+                let mut expected = None;
+                for (i, p) in paths.iter().enumerate() {
+                    for q in &paths[i + 1..] {
+                        if p.iter().all(|e| !q.contains(e)) {
+                            let d = len(p) + len(q);
+                            expected = Some(expected.map_or(d, |x: u32| x.min(d)));
+                        }
+                    }
+                }
+                let pair = g.edge_disjoint_shortest_pair(&w, s, t);
+                assert_eq!(expected, pair.as_ref().map(|(p, q)| len(p) + len(q)));
+                if let Some((p, q)) = pair {
+                    for p in &[&p, &q] {
+                        assert!(g.is_path(p.iter()));
+                        assert_eq!(s, g.source(p[0]));
+                        assert_eq!(t, g.target(*p.last().unwrap()));
+                    }
+                    assert!(p.iter().all(|e| !q.contains(e)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reachable_within() {
+        let g = path_graph(10);
+        let v = vec(g.vertices());
+        assert_eq!(vec![v[4]], g.reachable_within(v[4], 0));
+        for k in 0..12 {
+            for (i, &u) in v.iter().enumerate() {
+                let mut r = g.reachable_within(u, k);
+                assert_eq!(u, r[0]);
+                r.sort();
+                let window = vec(v[i.saturating_sub(k)..(i + k + 1).min(10)].iter().cloned());
+                assert_eq!(window, r);
+            }
+        }
+
+        let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (2, 0), (2, 3));
+        assert_eq!(vec![1, 2], g.reachable_within(1, 1));
+        assert_eq!(vec![1, 2, 0, 3], g.reachable_within(1, 2));
+        assert_eq!(vec![3], g.reachable_within(3, 5));
+    }
+
+    #[test]
+    fn dag_longest_path() {
+        // tasks with durations as edge weights, 0 is the start and 7 the end
+        let g: StaticDigraph = graph!(
+            8,
+            (0, 1),
+            (0, 2),
+            (1, 3),
+            (2, 3),
+            (2, 4),
+            (3, 5),
+            (4, 5),
+            (4, 6),
+            (5, 7),
+            (6, 7)
+        );
+        let e = vec(g.edges());
+        let mut w = g.default_edge_prop(0u32);
+        for (&e, &x) in e.iter().zip(&[3, 2, 4, 1, 6, 2, 1, 5, 1, 2]) {
+            w[e] = x;
+        }
+        assert_eq!((15, vec![e[1], e[4], e[7], e[9]]), g.dag_longest_path(&w));
+        let (len, path) = g.dag_longest_path(FnProp(|_| 1u32));
+        assert_eq!(4, len);
+        assert!(g.is_path(path));
+
+        let g: StaticDigraph = graph!(3);
+        assert_eq!((0, vec![]), g.dag_longest_path(FnProp(|_| 1u32)));
+        let g: StaticDigraph = graph!(0);
+        assert_eq!((0, vec![]), g.dag_longest_path(FnProp(|_| 1u32)));
+    }
+
+    #[test]
+    #[should_panic(expected = "the graph is not a DAG")]
+    fn dag_longest_path_cycle() {
+        let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (2, 0));
+        g.dag_longest_path(FnProp(|_| 1u32));
+    }
+
+    #[test]
+    fn all_simple_paths() {
+        let g: StaticGraph = graph!(5, (0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4), (3, 3));
+        let e = vec(g.edges());
+        let paths = set(g.all_simple_paths(0, 4, None));
+        let expected = set(vec![
+            vec![e[0], e[3], e[5]],
+            vec![e[0], e[2], e[4], e[5]],
+            vec![e[1], e[4], e[5]],
+            vec![e[1], e[2], e[3], e[5]],
+        ]);
+        assert_eq!(expected, paths);
+        for p in g.all_simple_paths(0, 4, None) {
+            assert!(g.is_path(&p));
+        }
+        assert_eq!(2, g.all_simple_paths(0, 4, Some(3)).count());
+        assert_eq!(0, g.all_simple_paths(0, 4, Some(2)).count());
+        assert_eq!(0, g.all_simple_paths(0, 4, Some(0)).count());
+        assert_eq!(
+            vec![Vec::<Edge<StaticGraph>>::new()],
+            vec(g.all_simple_paths(3, 3, None))
+        );
+        assert_eq!(3, g.all_simple_paths(1, 2, None).count());
+
+        let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (2, 0), (2, 3));
+        assert_eq!(1, g.all_simple_paths(0, 3, None).count());
+        assert_eq!(0, g.all_simple_paths(3, 0, None).count());
+    }
+
+    #[test]
+    fn all_simple_paths_complete() {
+        // the number of simple paths between two vertices of K_n is the sum of (n - 2)! / (n - 2
+        // - k)! for k in 0..n - 1, where k is the number of internal vertices
+        let expected = [1, 2, 5, 16, 65];
+        for (n, &count) in (2..7).zip(&expected) {
+            let g = CompleteGraph::new(n);
+            assert_eq!(count, g.all_simple_paths(0, 1, None).count());
+        }
+    }
+
+    #[test]
+    fn transitive_closure() {
+        use algs::{Cycles, Distances};
+        use rand::prelude::*;
+
+        fn check(g: &StaticDigraph) {
+            let c = g.transitive_closure();
+            let d = g.all_pairs_bfs();
+            let mut m = 0;
+            for u in g.vertices() {
+                for v in g.vertices() {
+                    let reachable = if u == v {
+                        g.out_neighbors(u).any(|w| d.dist(w, u).is_some())
+                    } else {
+                        d.dist(u, v).is_some()
+                    };
+                    assert_eq!(reachable, c.get_edge_by_ends(u, v).is_some());
+                    m += reachable as usize;
+                }
+            }
+            assert_eq!(m, c.num_edges());
+        }
+
+        let mut rng = SmallRng::from_entropy();
+        for n in (1..15).chain(60..70) {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticDigraph::new_gnm(n, m, &mut rng).unwrap();
+            check(&g);
+            // an acyclic digraph with the same edges oriented from the smaller to the larger end,
+            // and with a parallel edge
+            let mut edges = vec(g
+                .edges_ends()
+                .map(|(u, v)| (u.min(v) as usize, u.max(v) as usize)));
+            edges.extend(edges.first().cloned());
+            let dag = StaticDigraph::new_with_edges(n, edges);
+            assert!(dag.topological_sort().is_some());
+            check(&dag);
+        }
+    }
+
     #[test]
     fn is_path_graph() {
         let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (2, 3));
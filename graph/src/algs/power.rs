@@ -0,0 +1,111 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Powers of graphs.
+
+use prelude::*;
+
+use std::collections::VecDeque;
+
+/// Creates the `k`-th power of `g`, that is, a simple graph with the same number of vertices and
+/// with an edge `(u, v)` for each pair of distinct vertices `u` and `v` whose distance in `g` is
+/// at most `k`. The first power is the simple graph of `g` (see [`simple_graph`]) and the
+/// zeroth power has no edges.
+///
+/// The vertex `v` of `g` corresponds to the vertex `g.vertex_index().get(v)` of the new graph.
+/// The edges are found by a breadth-first search limited to `k` hops from each vertex, which
+/// takes O(V (V + E)) time in the worst case.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::power::graph_power;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+/// let square = graph_power(&g, 2);
+/// assert_eq!(
+///     vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)],
+///     square.edges_ends().collect::<Vec<_>>()
+/// );
+/// # }
+/// ```
+///
+/// [`simple_graph`]: ../simple/fn.simple_graph.html
+pub fn graph_power<G>(g: &G, k: usize) -> StaticGraph
+where
+    G: IncidenceGraph + WithVertexIndexProp,
+{
+    let index = g.vertex_index();
+    let mut edges = vec![];
+    // stamp[v] == i if v was reached from the vertex with index i
+    let mut stamp = g.default_vertex_prop(usize::max_value());
+    let mut queue = VecDeque::new();
+    for u in g.vertices() {
+        let i = index.get(u);
+        stamp[u] = i;
+        queue.push_back((u, 0));
+        while let Some((v, d)) = queue.pop_front() {
+            if d == k {
+                continue;
+            }
+            for w in g.out_neighbors(v) {
+                if stamp[w] != i {
+                    stamp[w] = i;
+                    queue.push_back((w, d + 1));
+                    let j = index.get(w);
+                    if i < j {
+                        edges.push((i, j));
+                    }
+                }
+            }
+        }
+    }
+    edges.sort();
+    StaticGraph::new_with_edges(g.num_vertices(), edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algs::simple::simple_graph;
+    use algs::Distances;
+    use fera_fun::{set, vec};
+    use rand::prelude::*;
+
+    #[test]
+    fn path() {
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+        assert_eq!(0, graph_power(&g, 0).num_edges());
+        assert_eq!(3, graph_power(&g, 1).num_edges());
+        assert_eq!(5, graph_power(&g, 2).num_edges());
+        assert_eq!(6, graph_power(&g, 3).num_edges());
+        assert_eq!(6, graph_power(&g, 10).num_edges());
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..12 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            let ends = |g: &StaticGraph| set(g.edges_ends().map(|(u, v)| (u.min(v), u.max(v))));
+            assert_eq!(ends(&simple_graph(&g)), ends(&graph_power(&g, 1)));
+            let dist = g.all_pairs_bfs();
+            for k in 0..4 {
+                let mut expected = vec![];
+                for u in g.vertices() {
+                    for v in u + 1..n as u32 {
+                        if dist[(u, v)].into_iter().any(|d| d <= k) {
+                            expected.push((u, v));
+                        }
+                    }
+                }
+                assert_eq!(expected, vec(graph_power(&g, k).edges_ends()));
+            }
+        }
+    }
+}
@@ -139,8 +139,8 @@ where
 type PrimPriorityQueue<G, T> = BinaryHeap<QueueItem<T, Vertex<G>>>;
 
 pub struct QueueItem<A, B> {
-    prio: A,
-    vertex: B,
+    pub(crate) prio: A,
+    pub(crate) vertex: B,
 }
 
 impl<A, B> QueueItem<A, B> {
@@ -0,0 +1,144 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Relabeling of the vertices of a graph.
+//!
+//! A relabeling is described by a permutation `perm` of `0..n`, where the vertex with index `i`
+//! (see [`WithVertexIndexProp`]) becomes the vertex `perm[i]` of the new graph.
+//!
+//! [`WithVertexIndexProp`]: ../../props/trait.WithVertexIndexProp.html
+
+use prelude::*;
+use traverse::*;
+
+use std::mem::replace;
+
+/// Creates a copy of `g` where the vertex with index `i` becomes the vertex `perm[i]`. The new
+/// graph is isomorphic to `g` and its edges are added in the order of `g.edges()`.
+///
+/// # Panics
+///
+/// If `perm` is not a permutation of `0..g.num_vertices()`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::relabel::relabel;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(3, (0, 1), (1, 2));
+/// let h = relabel(&g, &[2, 0, 1]);
+/// assert_eq!(vec![(2, 0), (0, 1)], h.edges_ends().collect::<Vec<_>>());
+/// # }
+/// ```
+pub fn relabel<G>(g: &G, perm: &[usize]) -> StaticGraph
+where
+    G: Graph + WithVertexIndexProp,
+{
+    let n = g.num_vertices();
+    let mut seen = vec![false; n];
+    let valid = perm.len() == n && perm.iter().all(|&i| i < n && !replace(&mut seen[i], true));
+    assert!(valid, "perm is not a permutation of 0..{}", n);
+    let index = g.vertex_index();
+    StaticGraph::new_with_edges(
+        n,
+        g.edges_ends().map(|(u, v)| (perm[index.get(u)], perm[index.get(v)])),
+    )
+}
+
+/// Returns a permutation (see [`relabel`]) that numbers the vertices in breadth-first search
+/// order, that is, the vertex with index `i` is the `perm[i]`-th vertex discovered by a
+/// breadth-first search that starts from each vertex not yet discovered (in the order of
+/// `g.vertices()`).
+///
+/// In the relabeled graph the neighbors of a vertex tend to have close numbers, which improves
+/// the memory locality of many algorithms.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::relabel::{bfs_order_permutation, relabel};
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(5, (0, 3), (3, 1), (0, 4), (2, 2));
+/// let perm = bfs_order_permutation(&g);
+/// assert_eq!(vec![0, 3, 4, 1, 2], perm);
+/// let h = relabel(&g, &perm);
+/// assert_eq!(vec![(0, 1), (1, 3), (0, 2), (4, 4)], h.edges_ends().collect::<Vec<_>>());
+/// # }
+/// ```
+///
+/// [`relabel`]: fn.relabel.html
+pub fn bfs_order_permutation<G>(g: &G) -> Vec<usize>
+where
+    G: IncidenceGraph + WithVertexIndexProp,
+{
+    let index = g.vertex_index();
+    let mut perm = vec![0; g.num_vertices()];
+    let mut next = 0;
+    g.bfs(OnDiscoverVertex(|v| {
+        perm[index.get(v)] = next;
+        next += 1;
+    })).run();
+    perm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algs::iso::is_isomorphic;
+    use fera_fun::{set, vec};
+    use rand::prelude::*;
+
+    #[test]
+    fn identity() {
+        let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 0), (3, 3), (1, 2));
+        let h = relabel(&g, &[0, 1, 2, 3, 4]);
+        assert_eq!(g, h);
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..10 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            let mut perm = vec(0..n);
+            perm.shuffle(&mut rng);
+            let h = relabel(&g, &perm);
+            assert_eq!(g.num_vertices(), h.num_vertices());
+            assert_eq!(g.num_edges(), h.num_edges());
+            assert!(is_isomorphic(&g, &h));
+            let p = |v: u32| perm[v as usize] as u32;
+            assert_eq!(
+                set(g.edges_ends().map(|(u, v)| (p(u).min(p(v)), p(u).max(p(v))))),
+                set(h.edges_ends().map(|(u, v)| (u.min(v), u.max(v))))
+            );
+
+            let mut perm = bfs_order_permutation(&g);
+            let h = relabel(&g, &perm);
+            assert!(is_isomorphic(&g, &h));
+            perm.sort();
+            assert_eq!(vec(0..n), perm);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "perm is not a permutation of 0..3")]
+    fn not_permutation() {
+        let g: StaticGraph = graph!(3, (0, 1));
+        relabel(&g, &[0, 1, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "perm is not a permutation of 0..3")]
+    fn wrong_len() {
+        let g: StaticGraph = graph!(3, (0, 1));
+        relabel(&g, &[0, 1]);
+    }
+}
@@ -0,0 +1,131 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Detection and removal of loops and parallel edges.
+//!
+//! A graph is simple if it has no loops (edges with the same source and target) and no parallel
+//! edges (edges with the same ends). Many algorithms assume that the graph is simple.
+
+use prelude::*;
+
+use std::collections::HashSet;
+
+/// Returns `true` if `g` has at least one loop, `false` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::simple::has_self_loop;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(3, (0, 1), (1, 2));
+/// assert!(!has_self_loop(&g));
+/// let g: StaticGraph = graph!(3, (0, 1), (1, 1));
+/// assert!(has_self_loop(&g));
+/// # }
+/// ```
+pub fn has_self_loop<G>(g: &G) -> bool
+where
+    G: EdgeList,
+{
+    g.edges_ends().any(|(u, v)| u == v)
+}
+
+/// Returns the loops of `g` in the order of `g.edges()`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::simple::self_loops;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(3, (0, 0), (0, 1), (2, 2));
+/// let loops = self_loops(&g);
+/// assert_eq!(2, loops.len());
+/// assert_eq!((0, 0), g.ends(loops[0]));
+/// assert_eq!((2, 2), g.ends(loops[1]));
+/// # }
+/// ```
+pub fn self_loops<G>(g: &G) -> Vec<Edge<G>>
+where
+    G: EdgeList,
+{
+    g.edges_with_ends()
+        .filter(|&(_, u, v)| u == v)
+        .map(|(e, _, _)| e)
+        .collect()
+}
+
+/// Creates a simple graph from `g` by removing the loops and keeping only the first (in the order
+/// of `g.edges()`) of each set of parallel edges.
+///
+/// The vertex `v` of `g` corresponds to the vertex `g.vertex_index().get(v)` of the new graph, and
+/// the edges are added in the order of `g.edges()`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::simple::simple_graph;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(3, (0, 1), (1, 0), (1, 1), (1, 2));
+/// let h = simple_graph(&g);
+/// assert_eq!(3, h.num_vertices());
+/// assert_eq!(vec![(0, 1), (1, 2)], h.edges_ends().collect::<Vec<_>>());
+/// # }
+/// ```
+pub fn simple_graph<G>(g: &G) -> StaticGraph
+where
+    G: Graph + WithVertexIndexProp,
+{
+    let index = g.vertex_index();
+    let mut seen = HashSet::new();
+    let mut edges = Vec::with_capacity(g.num_edges());
+    for (u, v) in g.edges_ends() {
+        let (a, b) = (index.get(u), index.get(v));
+        if a != b && seen.insert((a.min(b), a.max(b))) {
+            edges.push((a, b));
+        }
+    }
+    StaticGraph::new_with_edges(g.num_vertices(), edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::{set, vec};
+    use rand::prelude::*;
+
+    #[test]
+    fn multigraph() {
+        let g: StaticGraph = graph!(4, (0, 1), (2, 2), (1, 2), (1, 0), (2, 3), (0, 1));
+        assert!(has_self_loop(&g));
+        assert_eq!(vec![g.edge_by_ends(2, 2)], self_loops(&g));
+
+        let h = simple_graph(&g);
+        assert_eq!(4, h.num_vertices());
+        assert!(!has_self_loop(&h));
+        assert!(self_loops(&h).is_empty());
+        assert_eq!(vec![(0, 1), (1, 2), (2, 3)], vec(h.edges_ends()));
+    }
+
+    #[test]
+    fn simple() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..10 {
+            let m = rng.gen_range(0, n * (n - 1) / 2 + 1);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            let h = simple_graph(&g);
+            assert!(!has_self_loop(&g));
+            assert_eq!(g.num_vertices(), h.num_vertices());
+            assert_eq!(set(g.edges_ends()), set(h.edges_ends()));
+        }
+    }
+}
@@ -0,0 +1,246 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A summary of the basic properties of a graph.
+
+use algs::{Components, Degrees};
+use prelude::*;
+
+/// The basic properties of a graph. This is created by [`summary`].
+///
+/// [`summary`]: fn.summary.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphSummary {
+    pub num_vertices: usize,
+    pub num_edges: usize,
+    /// The number of connected components, see [`Components::connected_components`].
+    ///
+    /// [`Components::connected_components`]:
+    /// ../components/trait.Components.html#method.connected_components
+    pub num_components: usize,
+    /// The maximum out degree or `None` if the graph has no vertices.
+    pub max_degree: Option<usize>,
+    /// The minimum out degree or `None` if the graph has no vertices.
+    pub min_degree: Option<usize>,
+    /// See [`density`].
+    ///
+    /// [`density`]: fn.density.html
+    pub density: f64,
+    pub is_connected: bool,
+    pub has_self_loops: bool,
+    pub num_self_loops: usize,
+}
+
+/// Computes a [`GraphSummary`] of `g`.
+///
+/// The running time is O(V + E).
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::summary::summary;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 0));
+/// let s = summary(&g);
+/// assert_eq!(2, s.num_components);
+/// assert_eq!(Some(0), s.min_degree);
+/// assert_eq!(0.5, s.density);
+/// assert!(!s.has_self_loops);
+/// # }
+/// ```
+///
+/// [`GraphSummary`]: struct.GraphSummary.html
+pub fn summary<G>(g: &G) -> GraphSummary
+where
+    G: VertexList + EdgeList + Incidence + BasicProps,
+    G::Kind: UniformEdgeKind,
+{
+    let num_self_loops = g.edges_ends().filter(|&(u, v)| u == v).count();
+    GraphSummary {
+        num_vertices: g.num_vertices(),
+        num_edges: g.num_edges(),
+        num_components: g.connected_components().num_components(),
+        max_degree: g.maximum_out_degree(),
+        min_degree: g.minimum_out_degree(),
+        density: density(g),
+        is_connected: g.is_connected(),
+        has_self_loops: num_self_loops != 0,
+        num_self_loops,
+    }
+}
+
+/// Returns the number of edges of `g` divided by the maximum number of edges without loops and
+/// parallel edges, that is, `2E / (V(V - 1))` for undirected graphs and `E / (V(V - 1))` for
+/// directed graphs. The density of a graph with less than two vertices is zero.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::summary::density;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+/// assert_eq!(0.5, density(&g));
+/// let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (2, 3));
+/// assert_eq!(0.25, density(&g));
+/// assert_eq!(1.0, density(&CompleteGraph::new(5)));
+/// # }
+/// ```
+pub fn density<G>(g: &G) -> f64
+where
+    G: VertexList + EdgeList,
+    G::Kind: UniformEdgeKind,
+{
+    let n = g.num_vertices();
+    let m = g.num_edges() as f64;
+    if n < 2 {
+        0.0
+    } else if G::Kind::is_undirected() {
+        2.0 * m / (n * (n - 1)) as f64
+    } else {
+        m / (n * (n - 1)) as f64
+    }
+}
+
+/// Returns `true` if the [`density`] of `g` is at least `threshold`, `false` otherwise.
+///
+/// [`density`]: fn.density.html
+pub fn is_dense<G>(g: &G, threshold: f64) -> bool
+where
+    G: VertexList + EdgeList,
+    G::Kind: UniformEdgeKind,
+{
+    density(g) >= threshold
+}
+
+/// Returns `"adjacency matrix"` if the [`density`] of `g` is at least `0.25` and `"CSR"`
+/// otherwise, that is, a suggestion of whether `g` is better represented by an
+/// [`AdjMatrixGraph`] or by a [`StaticGraph`] (which stores the edges in the compressed sparse
+/// row format).
+///
+/// An `AdjMatrixGraph` uses `V^2` bytes and takes `O(V)` time to iterate over the out edges of a
+/// vertex, while a `StaticGraph` uses memory proportional to `V + E` and takes time proportional
+/// to the degree, so the matrix only pays off when a good fraction of the vertex pairs are
+/// adjacent.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::summary::recommend_representation;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(6, (0, 1), (1, 2));
+/// assert_eq!("CSR", recommend_representation(&g));
+/// assert_eq!("adjacency matrix", recommend_representation(&CompleteGraph::new(6)));
+/// # }
+/// ```
+///
+/// [`density`]: fn.density.html
+/// [`AdjMatrixGraph`]: ../../graphs/adjmatrix/type.AdjMatrixGraph.html
+/// [`StaticGraph`]: ../../graphs/static_/type.StaticGraph.html
+pub fn recommend_representation<G>(g: &G) -> &'static str
+where
+    G: VertexList + EdgeList,
+    G::Kind: UniformEdgeKind,
+{
+    if is_dense(g, 0.25) {
+        "adjacency matrix"
+    } else {
+        "CSR"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undirected() {
+        let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 0), (2, 2), (3, 4));
+        assert_eq!(
+            GraphSummary {
+                num_vertices: 6,
+                num_edges: 5,
+                num_components: 3,
+                max_degree: Some(4),
+                min_degree: Some(0),
+                density: 10.0 / 30.0,
+                is_connected: false,
+                has_self_loops: true,
+                num_self_loops: 1,
+            },
+            summary(&g)
+        );
+    }
+
+    #[test]
+    fn directed() {
+        let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (2, 0));
+        assert_eq!(
+            GraphSummary {
+                num_vertices: 3,
+                num_edges: 3,
+                num_components: 1,
+                max_degree: Some(1),
+                min_degree: Some(1),
+                density: 0.5,
+                is_connected: true,
+                has_self_loops: false,
+                num_self_loops: 0,
+            },
+            summary(&g)
+        );
+    }
+
+    #[test]
+    fn density_directed_and_undirected() {
+        // the maximum number of edges is n(n - 1) / 2 for undirected graphs and n(n - 1) for
+        // directed graphs
+        let g: StaticGraph = graph!(3, (0, 1), (1, 2), (2, 0));
+        assert_eq!(1.0, density(&g));
+        assert!(is_dense(&g, 1.0));
+        let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (2, 0));
+        assert_eq!(0.5, density(&g));
+        assert!(is_dense(&g, 0.5));
+        assert!(!is_dense(&g, 0.6));
+        assert_eq!(1.0, density(&CompleteGraph::new(6)));
+        assert_eq!(1.0, density(&CompleteDigraph::new(6)));
+        assert_eq!(0.0, density(&CompleteGraph::new(1)));
+        assert!(!is_dense(&CompleteGraph::new(0), 0.5));
+    }
+
+    #[test]
+    fn representation() {
+        // 3 of the 6 pairs are adjacent
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+        assert_eq!("adjacency matrix", recommend_representation(&g));
+        // 3 of the 15 pairs are adjacent
+        let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 3));
+        assert_eq!("CSR", recommend_representation(&g));
+        // 3 of the 12 ordered pairs are adjacent
+        let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (2, 3));
+        assert_eq!("adjacency matrix", recommend_representation(&g));
+        assert_eq!("CSR", recommend_representation(&CompleteGraph::new(1)));
+    }
+
+    #[test]
+    fn empty() {
+        let s = summary(&CompleteGraph::new(0));
+        assert_eq!(0, s.num_vertices);
+        assert_eq!(0, s.num_components);
+        assert_eq!(None, s.max_degree);
+        assert_eq!(0.0, s.density);
+        let s = summary(&CompleteGraph::new(5));
+        assert_eq!(1.0, s.density);
+        assert_eq!(Some(4), s.min_degree);
+        assert!(s.is_connected);
+    }
+}
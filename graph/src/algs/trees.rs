@@ -4,9 +4,17 @@
 
 //! Trees related algortihms, including testing if a graph is a tree.
 
+use algs::Components;
+use choose::Choose;
 use prelude::*;
 use props::Color;
 use traverse::*;
+use unionfind::WithUnionFind;
+
+use rand::Rng;
+
+use std::iter::Cloned;
+use std::slice;
 
 pub trait Trees: Incidence {
     fn is_tree(&self) -> bool
@@ -18,6 +26,25 @@ pub trait Trees: Incidence {
         tree
     }
 
+    /// Returns `true` if the graph has no cycles, that is, if each component of the graph is a
+    /// tree. Self loops and parallel edges are cycles.
+    fn is_forest(&self) -> bool
+    where
+        Self: Graph,
+    {
+        if self.num_edges() == 0 {
+            return true;
+        }
+        let mut ds = self.new_unionfind();
+        for (_, u, v) in self.edges_with_ends() {
+            if ds.in_same_set(u, v) {
+                return false;
+            }
+            ds.union(u, v);
+        }
+        true
+    }
+
     fn tree_diameter(&self) -> Result<usize, ()>
     where
         Self: VertexList + EdgeList + WithVertexProp<Color>,
@@ -40,10 +67,752 @@ pub trait Trees: Incidence {
             Err(())
         }
     }
+
+    /// Returns the vertices of a longest path of the tree, from one end to the other, or `None` if
+    /// the graph is not a tree. The length of the path (the tree diameter) is `path.len() - 1`.
+    ///
+    /// The path is found with two searches: the first finds a vertex `u` that is farthest from an
+    /// arbitrary vertex, the second finds a vertex `v` that is farthest from `u`.
+    fn tree_diameter_path(&self) -> Option<Vec<Vertex<Self>>>
+    where
+        Self: VertexList + EdgeList + WithVertexProp<Color> + WithVertexProp<OptionVertex<Self>>,
+    {
+        let mut tree = false;
+        let mut dist = 0;
+        let mut u = Self::vertex_none();
+        self.dfs((IsTree(&mut tree), FarthestVertex(&mut u, &mut dist)))
+            .run();
+        if !tree {
+            return None;
+        }
+        let u = match u.into_option() {
+            Some(u) => u,
+            // a tree with zero or one vertex
+            None => return Some(self.vertices().collect()),
+        };
+        let mut parent = self.default_vertex_prop(Self::vertex_none());
+        let mut v = Self::vertex_none();
+        self.dfs((RecordParent(&mut parent), FarthestVertex(&mut v, &mut dist)))
+            .root(u)
+            .run();
+        let mut path = vec![];
+        let mut cur = v.into_option();
+        while let Some(x) = cur {
+            path.push(x);
+            cur = parent[x].into_option();
+        }
+        Some(path)
+    }
+
+    /// Returns the center of the tree (the one or two vertices with minimum eccentricity) or
+    /// `None` if the graph is not a tree.
+    ///
+    /// The center is found by repeatedly removing the leaves of the tree until at most two
+    /// vertices remain.
+    fn tree_center(&self) -> Option<Vec<Vertex<Self>>>
+    where
+        Self: VertexList + EdgeList + WithVertexProp<Color> + WithVertexProp<usize>,
+    {
+        if !self.is_tree() {
+            return None;
+        }
+        let mut remaining = self.num_vertices();
+        let mut deg = self.default_vertex_prop(0usize);
+        let mut leaves = vec![];
+        for v in self.vertices() {
+            deg[v] = self.out_degree(v);
+            if deg[v] <= 1 {
+                leaves.push(v);
+            }
+        }
+        while remaining > 2 {
+            remaining -= leaves.len();
+            let mut next = vec![];
+            for &v in &leaves {
+                deg[v] = 0;
+                for u in self.out_neighbors(v) {
+                    if deg[u] > 0 {
+                        deg[u] -= 1;
+                        if deg[u] == 1 {
+                            next.push(u);
+                        }
+                    }
+                }
+            }
+            leaves = next;
+        }
+        Some(leaves)
+    }
+
+    /// Creates a [`RootedTree`] of this tree with root `root`.
+    ///
+    /// # Panics
+    ///
+    /// If the graph is not a tree.
+    ///
+    /// [`RootedTree`]: struct.RootedTree.html
+    fn root_tree(&self, root: Vertex<Self>) -> RootedTree<Self>
+    where
+        Self: VertexList + EdgeList + BasicVertexProps,
+    {
+        assert!(self.is_tree(), "the graph is not a tree");
+        RootedTree::new(self, root)
+    }
+
+    /// Creates the [`HeavyLight`] decomposition of this tree with root `root`.
+    ///
+    /// # Panics
+    ///
+    /// If the graph is not a tree.
+    ///
+    /// [`HeavyLight`]: struct.HeavyLight.html
+    fn heavy_light_decomposition(&self, root: Vertex<Self>) -> HeavyLight<Self>
+    where
+        Self: VertexList + EdgeList + BasicVertexProps,
+    {
+        assert!(self.is_tree(), "the graph is not a tree");
+        HeavyLight::new(self, root)
+    }
+
+    /// Returns the Prüfer code of this tree, where the vertices are identified by their indices
+    /// (see [`WithVertexIndexProp`]).
+    ///
+    /// The code is obtained by repeatedly removing the leaf with the smallest index and recording
+    /// its neighbor, until two vertices remain, so the code of a tree with `n` vertices has length
+    /// `n - 2`. This is the inverse of [`WithBuilder::new_from_prufer_code`]. The running time is
+    /// O(V).
+    ///
+    /// # Panics
+    ///
+    /// If the graph is not a tree or has less than two vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Trees;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(6, (3, 0), (1, 3), (2, 3), (3, 4), (5, 4));
+    /// assert_eq!(vec![3, 3, 3, 4], g.prufer_code());
+    /// # }
+    /// ```
+    ///
+    /// [`WithVertexIndexProp`]: ../props/trait.WithVertexIndexProp.html
+    /// [`WithBuilder::new_from_prufer_code`]:
+    /// ../builder/trait.WithBuilder.html#method.new_from_prufer_code
+    fn prufer_code(&self) -> Vec<usize>
+    where
+        Self: Graph + WithVertexIndexProp,
+    {
+        assert!(self.is_tree(), "the graph is not a tree");
+        let n = self.num_vertices();
+        assert!(n >= 2, "the tree must have at least two vertices");
+        let index = self.vertex_index();
+        let mut adj = vec![vec![]; n];
+        for (u, v) in self.edges_ends() {
+            let (u, v) = (index.get(u), index.get(v));
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+
+        // with the tree rooted at n - 1, the neighbor of each removed leaf is its parent
+        let mut parent = vec![n - 1; n];
+        let mut stack = vec![n - 1];
+        while let Some(u) = stack.pop() {
+            for &v in &adj[u] {
+                if v != parent[u] {
+                    parent[v] = u;
+                    stack.push(v);
+                }
+            }
+        }
+
+        let mut degree: Vec<_> = adj.iter().map(|a| a.len()).collect();
+        let mut code = Vec::with_capacity(n - 2);
+        // ptr is the smallest leaf not yet removed that is not less than any removed leaf
+        let mut ptr = degree.iter().position(|&d| d == 1).unwrap();
+        let mut leaf = ptr;
+        for _ in 2..n {
+            let v = parent[leaf];
+            code.push(v);
+            degree[v] -= 1;
+            if degree[v] == 1 && v < ptr {
+                leaf = v;
+            } else {
+                ptr += 1;
+                while degree[ptr] != 1 {
+                    ptr += 1;
+                }
+                leaf = ptr;
+            }
+        }
+        code
+    }
+
+    /// Returns the centroid decomposition of this tree, that is, the root of the centroid tree and
+    /// the parent of each vertex in the centroid tree (`None` for the root).
+    ///
+    /// The root is a centroid of the tree, a vertex whose removal leaves components with at most
+    /// half of the vertices each. The children of the root are the centroids of these components,
+    /// and so on, so the centroid tree has depth O(log V). The running time is O(V log V).
+    ///
+    /// # Panics
+    ///
+    /// If the graph is not a tree or has no vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Trees;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 3), (3, 4));
+    /// let (root, parent) = g.centroid_decomposition();
+    /// assert_eq!(2, root);
+    /// assert_eq!(
+    ///     vec![Some(1), Some(2), None, Some(2), Some(3)],
+    ///     g.vertices().map(|v| parent[v].into_option()).collect::<Vec<_>>()
+    /// );
+    /// # }
+    /// ```
+    fn centroid_decomposition(
+        &self,
+    ) -> (Vertex<Self>, DefaultVertexPropMut<Self, OptionVertex<Self>>)
+    where
+        Self: Graph,
+    {
+        assert!(self.is_tree(), "the graph is not a tree");
+        let first = self.vertices().next().expect("the tree has no vertices");
+        let mut cparent = self.default_vertex_prop(Self::vertex_none());
+        let mut removed = self.default_vertex_prop(false);
+        let mut parent = self.default_vertex_prop(Self::vertex_none());
+        let mut size = self.default_vertex_prop(0usize);
+        let mut order = vec![];
+        let mut root = first;
+        // the components not yet decomposed and the centroid that is the parent of each one
+        let mut components = vec![(first, Self::vertex_none())];
+        while let Some((start, p)) = components.pop() {
+            // the vertices of the component, each one after its parent
+            order.clear();
+            order.push(start);
+            parent[start] = Self::vertex_none();
+            let mut i = 0;
+            while i < order.len() {
+                let u = order[i];
+                for v in self.out_neighbors(u) {
+                    if !removed[v] && Self::vertex_some(v) != parent[u] {
+                        parent[v] = Self::vertex_some(u);
+                        order.push(v);
+                    }
+                }
+                i += 1;
+            }
+            for &u in order.iter().rev() {
+                size[u] = 1 + self
+                    .out_neighbors(u)
+                    .filter(|&v| !removed[v] && parent[v] == Self::vertex_some(u))
+                    .map(|v| size[v])
+                    .sum::<usize>();
+            }
+
+            // walk to the child with more than half of the vertices while there is one
+            let total = order.len();
+            let mut c = start;
+            while let Some(v) = self
+                .out_neighbors(c)
+                .find(|&v| !removed[v] && parent[v] == Self::vertex_some(c) && 2 * size[v] > total)
+            {
+                c = v;
+            }
+
+            cparent[c] = p;
+            if p.into_option().is_none() {
+                root = c;
+            }
+            removed[c] = true;
+            for v in self.out_neighbors(c) {
+                if !removed[v] {
+                    components.push((v, Self::vertex_some(c)));
+                }
+            }
+        }
+        (root, cparent)
+    }
+
+    /// Returns the lowest common ancestor of each pair of vertices in `queries` when the tree is
+    /// rooted at `root`, using Tarjan's offline algorithm.
+    ///
+    /// All queries are answered in a single depth-first search: when a vertex `v` is finished, the
+    /// vertices of its subtree are united to `v` in a union-find, and the lowest common ancestor
+    /// of `v` and a finished vertex `u` is the ancestor associated with the set of `u`. The
+    /// running time is O((V + Q) α(V)), where Q is the number of queries. To answer queries one at
+    /// a time, use [`RootedTree::lca`].
+    ///
+    /// # Panics
+    ///
+    /// If the graph is not a tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Trees;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(6, (0, 1), (0, 2), (1, 3), (1, 4), (2, 5));
+    /// let queries = [(3, 4), (3, 5), (4, 1), (2, 2)];
+    /// assert_eq!(vec![1, 0, 1, 2], g.tarjan_offline_lca(0, &queries));
+    /// assert_eq!(vec![1, 5, 1, 2], g.tarjan_offline_lca(5, &queries));
+    /// # }
+    /// ```
+    ///
+    /// [`RootedTree::lca`]: struct.RootedTree.html#method.lca
+    fn tarjan_offline_lca(
+        &self,
+        root: Vertex<Self>,
+        queries: &[(Vertex<Self>, Vertex<Self>)],
+    ) -> Vec<Vertex<Self>>
+    where
+        Self: Graph,
+    {
+        assert!(self.is_tree(), "the graph is not a tree");
+        let mut ds = self.new_unionfind();
+        // ancestor[ds.find_set(v)] is the deepest vertex whose subtree was united with v
+        let mut ancestor = self.default_vertex_prop(root);
+        for v in self.vertices() {
+            ancestor[v] = v;
+        }
+        let mut finished = self.default_vertex_prop(false);
+        let mut pending = self.default_vertex_prop(Vec::<usize>::new());
+        for (i, &(u, v)) in queries.iter().enumerate() {
+            pending[u].push(i);
+            pending[v].push(i);
+        }
+        let mut lca = vec![root; queries.len()];
+        self.dfs(OnTraverseEvent(|event| match event {
+            TraverseEvent::FinishVertex(v) => {
+                finished[v] = true;
+                for &i in &pending[v] {
+                    let (a, b) = queries[i];
+                    let u = if a == v { b } else { a };
+                    if finished[u] {
+                        lca[i] = ancestor[ds.find_set(u)];
+                    }
+                }
+            }
+            TraverseEvent::FinishTreeEdge(e) => {
+                let (p, v) = self.ends(e);
+                ds.union(p, v);
+                ancestor[ds.find_set(p)] = p;
+            }
+            _ => (),
+        }))
+        .root(root)
+        .run();
+        lca
+    }
+
+    /// Returns the edges of a uniformly random spanning tree of the graph using Wilson's algorithm
+    /// (loop-erased random walks). If the graph is disconnected, returns the edges of a random
+    /// spanning forest, that is, a uniformly random spanning tree of each component.
+    ///
+    /// The expected running time is proportional to the mean hitting time of the graph.
+    fn random_spanning_tree<R: Rng>(&self, mut rng: R) -> Vec<Edge<Self>>
+    where
+        Self: IncidenceGraph + Choose,
+    {
+        let cc = self.connected_components();
+        let mut has_root = vec![false; cc.num_components()];
+        let mut in_tree = self.default_vertex_prop(false);
+        let mut next = self.default_vertex_prop(Self::edge_none());
+        let mut tree = vec![];
+
+        // The first vertex of each component is the root of the component tree
+        for v in self.vertices() {
+            let c = cc.component(v);
+            if !has_root[c] {
+                has_root[c] = true;
+                in_tree[v] = true;
+            }
+        }
+
+        for v in self.vertices() {
+            // Random walk until the tree is reached. The loops are erased by overwriting next.
+            let mut u = v;
+            while !in_tree[u] {
+                let e = self
+                    .choose_out_edge(u, &mut rng)
+                    .expect("a vertex that is not a root has an out edge");
+                next[u] = Self::edge_some(e);
+                u = self.target(e);
+            }
+
+            // Add the loop-erased path to the tree
+            let mut u = v;
+            while !in_tree[u] {
+                in_tree[u] = true;
+                let e = next[u].into_option().unwrap();
+                tree.push(e);
+                u = self.target(e);
+            }
+        }
+
+        tree
+    }
+
+    /// Returns the depth first search tree rooted at `root` as a property that maps each vertex
+    /// to the tree edge that reaches it. The source of the tree edge of `v` is the parent of `v`.
+    /// The root and the vertices not reachable from the root have no tree edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Trees;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(5, (0, 1), (0, 2), (1, 2), (3, 4));
+    /// let tree = g.dfs_tree(0);
+    /// let parents: Vec<_> = g
+    ///     .vertices()
+    ///     .map(|v| tree[v].into_option().map(|e| g.source(e)))
+    ///     .collect();
+    /// assert_eq!(vec![None, Some(0), Some(1), None, None], parents);
+    ///
+    /// let tree = g.bfs_tree(0);
+    /// let parents: Vec<_> = g
+    ///     .vertices()
+    ///     .map(|v| tree[v].into_option().map(|e| g.source(e)))
+    ///     .collect();
+    /// assert_eq!(vec![None, Some(0), Some(0), None, None], parents);
+    /// # }
+    /// ```
+    fn dfs_tree(&self, root: Vertex<Self>) -> DefaultVertexPropMut<Self, OptionEdge<Self>>
+    where
+        Self: WithVertexProp<Color> + WithVertexProp<OptionEdge<Self>>,
+    {
+        let mut tree = self.default_vertex_prop(Self::edge_none());
+        self.dfs(OnDiscoverTreeEdge(|e| {
+            tree[self.target(e)] = Self::edge_some(e)
+        }))
+        .root(root)
+        .run();
+        tree
+    }
+
+    /// Like [`dfs_tree`], but the search starts from each vertex not yet reached (in the order of
+    /// `vertices()`), so the result is a spanning forest of the graph. The roots of the forest
+    /// have no tree edge.
+    ///
+    /// [`dfs_tree`]: #method.dfs_tree
+    fn dfs_forest(&self) -> DefaultVertexPropMut<Self, OptionEdge<Self>>
+    where
+        Self: VertexList + WithVertexProp<Color> + WithVertexProp<OptionEdge<Self>>,
+    {
+        let mut tree = self.default_vertex_prop(Self::edge_none());
+        self.dfs(OnDiscoverTreeEdge(|e| {
+            tree[self.target(e)] = Self::edge_some(e)
+        }))
+        .run();
+        tree
+    }
+
+    /// Returns the breadth first search tree rooted at `root`. Each path from the root in this
+    /// tree is a shortest path in the graph. See [`dfs_tree`] for a description of the result.
+    ///
+    /// [`dfs_tree`]: #method.dfs_tree
+    fn bfs_tree(&self, root: Vertex<Self>) -> DefaultVertexPropMut<Self, OptionEdge<Self>>
+    where
+        Self: WithVertexProp<Color> + WithVertexProp<OptionEdge<Self>>,
+    {
+        let mut tree = self.default_vertex_prop(Self::edge_none());
+        self.bfs(OnDiscoverTreeEdge(|e| {
+            tree[self.target(e)] = Self::edge_some(e)
+        }))
+        .root(root)
+        .run();
+        tree
+    }
+
+    /// Like [`bfs_tree`], but the search starts from each vertex not yet reached (in the order of
+    /// `vertices()`), so the result is a spanning forest of the graph.
+    ///
+    /// [`bfs_tree`]: #method.bfs_tree
+    fn bfs_forest(&self) -> DefaultVertexPropMut<Self, OptionEdge<Self>>
+    where
+        Self: VertexList + WithVertexProp<Color> + WithVertexProp<OptionEdge<Self>>,
+    {
+        let mut tree = self.default_vertex_prop(Self::edge_none());
+        self.bfs(OnDiscoverTreeEdge(|e| {
+            tree[self.target(e)] = Self::edge_some(e)
+        }))
+        .run();
+        tree
+    }
 }
 
 impl<G: Incidence> Trees for G {}
 
+/// A tree with a designated root vertex.
+///
+/// Stores the parent, the depth and the children of each vertex. The ancestors of each vertex at
+/// distances `1, 2, 4, 8, ...` are also stored, so the lowest common ancestor of two vertices can
+/// be found in O(log n) time. The construction takes O(n log n) time and space.
+///
+/// This `struct` is created by [`Trees::root_tree`].
+///
+/// [`Trees::root_tree`]: trait.Trees.html#method.root_tree
+pub struct RootedTree<G: BasicVertexProps> {
+    root: Vertex<G>,
+    parent: DefaultVertexPropMut<G, OptionVertex<G>>,
+    depth: DefaultVertexPropMut<G, usize>,
+    children: DefaultVertexPropMut<G, Vec<Vertex<G>>>,
+    // up[k][v] is the 2^k-th ancestor of v or the root if v has less than 2^k ancestors
+    up: Vec<DefaultVertexPropMut<G, Vertex<G>>>,
+}
+
+impl<G> RootedTree<G>
+where
+    G: Incidence + VertexList + BasicVertexProps,
+{
+    fn new(g: &G, root: Vertex<G>) -> Self {
+        let mut parent = g.default_vertex_prop(G::vertex_none());
+        let mut depth = g.default_vertex_prop(0usize);
+        g.bfs((RecordParent(&mut parent), RecordDistance(&mut depth)))
+            .root(root)
+            .run();
+
+        let mut children = g.default_vertex_prop(Vec::<Vertex<G>>::new());
+        let mut up0 = g.default_vertex_prop(root);
+        for v in g.vertices() {
+            if let Some(p) = parent[v].into_option() {
+                children[p].push(v);
+                up0[v] = p;
+            }
+        }
+
+        let mut up = vec![up0];
+        while (1 << up.len()) < g.num_vertices() {
+            let mut next = g.default_vertex_prop(root);
+            {
+                let last = up.last().unwrap();
+                for v in g.vertices() {
+                    next[v] = last[last[v]];
+                }
+            }
+            up.push(next);
+        }
+
+        RootedTree {
+            root,
+            parent,
+            depth,
+            children,
+            up,
+        }
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> Vertex<G> {
+        self.root
+    }
+
+    /// Returns the parent of `v` or `None` if `v` is the root.
+    pub fn parent(&self, v: Vertex<G>) -> Option<Vertex<G>> {
+        self.parent[v].into_option()
+    }
+
+    /// Returns the depth of `v`, that is, the distance from the root to `v`.
+    pub fn depth(&self, v: Vertex<G>) -> usize {
+        self.depth[v]
+    }
+
+    /// Returns an iterator over the children of `v`.
+    pub fn children(&self, v: Vertex<G>) -> Cloned<slice::Iter<Vertex<G>>> {
+        self.children[v].iter().cloned()
+    }
+
+    /// Returns the lowest common ancestor of `u` and `v`, that is, the deepest vertex that is an
+    /// ancestor of both `u` and `v` (a vertex is an ancestor of itself).
+    pub fn lca(&self, u: Vertex<G>, v: Vertex<G>) -> Vertex<G> {
+        let (mut u, mut v) = if self.depth[u] >= self.depth[v] {
+            (u, v)
+        } else {
+            (v, u)
+        };
+
+        // lift u to the depth of v
+        let diff = self.depth[u] - self.depth[v];
+        for (k, up) in self.up.iter().enumerate() {
+            if diff & (1 << k) != 0 {
+                u = up[u];
+            }
+        }
+        if u == v {
+            return u;
+        }
+
+        for up in self.up.iter().rev() {
+            if up[u] != up[v] {
+                u = up[u];
+                v = up[v];
+            }
+        }
+        self.up[0][u]
+    }
+}
+
+/// The heavy-light decomposition of a tree with a designated root vertex.
+///
+/// The edge from each vertex to its child with the largest subtree is heavy, the others are
+/// light. The heavy edges form vertex-disjoint paths (chains), and the vertices are numbered
+/// `0..n` so that the vertices of each chain have consecutive positions, from the top of the chain
+/// (its head) down. Each path from a vertex to the root crosses O(log n) light edges, so any path
+/// of the tree is the union of O(log n) intervals of positions (see [`path_segments`]). This
+/// allows path queries to be answered by a data structure over the positions, like a segment
+/// tree. The construction takes O(n) time.
+///
+/// This `struct` is created by [`Trees::heavy_light_decomposition`].
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::Trees;
+///
+/// # fn main() {
+/// //       0
+/// //     /   \
+/// //    1     2
+/// //   / \
+/// //  3   4
+/// let g: StaticGraph = graph!(5, (0, 1), (0, 2), (1, 3), (1, 4));
+/// let hl = g.heavy_light_decomposition(0);
+/// assert_eq!(vec![0, 1, 4, 2, 3], g.vertices().map(|v| hl.pos(v)).collect::<Vec<_>>());
+/// assert_eq!(vec![0, 0, 2, 0, 4], g.vertices().map(|v| hl.head(v)).collect::<Vec<_>>());
+/// assert_eq!(vec![(3, 3), (1, 2)], hl.path_segments(4, 3));
+/// # }
+/// ```
+///
+/// [`path_segments`]: #method.path_segments
+/// [`Trees::heavy_light_decomposition`]: trait.Trees.html#method.heavy_light_decomposition
+pub struct HeavyLight<G: BasicVertexProps> {
+    parent: DefaultVertexPropMut<G, OptionVertex<G>>,
+    depth: DefaultVertexPropMut<G, usize>,
+    head: DefaultVertexPropMut<G, Vertex<G>>,
+    pos: DefaultVertexPropMut<G, usize>,
+}
+
+impl<G> HeavyLight<G>
+where
+    G: Incidence + VertexList + BasicVertexProps,
+{
+    fn new(g: &G, root: Vertex<G>) -> Self {
+        let mut parent = g.default_vertex_prop(G::vertex_none());
+        let mut depth = g.default_vertex_prop(0usize);
+        let mut order = vec![root];
+        let mut i = 0;
+        while i < order.len() {
+            let u = order[i];
+            for v in g.out_neighbors(u) {
+                if G::vertex_some(v) != parent[u] {
+                    parent[v] = G::vertex_some(u);
+                    depth[v] = depth[u] + 1;
+                    order.push(v);
+                }
+            }
+            i += 1;
+        }
+
+        // the child with the largest subtree of each vertex, the first one in case of ties
+        let mut size = g.default_vertex_prop(1usize);
+        let mut heavy = g.default_vertex_prop(G::vertex_none());
+        for &v in order.iter().rev() {
+            if let Some(p) = parent[v].into_option() {
+                size[p] += size[v];
+                if heavy[p]
+                    .into_option()
+                    .into_iter()
+                    .all(|h| size[h] <= size[v])
+                {
+                    heavy[p] = G::vertex_some(v);
+                }
+            }
+        }
+
+        let mut head = g.default_vertex_prop(root);
+        let mut pos = g.default_vertex_prop(0usize);
+        let mut next = 0;
+        let mut heads = vec![root];
+        while let Some(h) = heads.pop() {
+            let mut cur = Some(h);
+            while let Some(u) = cur {
+                head[u] = h;
+                pos[u] = next;
+                next += 1;
+                cur = heavy[u].into_option();
+                heads.extend(
+                    g.out_neighbors(u).filter(|&v| {
+                        G::vertex_some(u) == parent[v] && G::vertex_some(v) != heavy[u]
+                    }),
+                );
+            }
+        }
+
+        HeavyLight {
+            parent,
+            depth,
+            head,
+            pos,
+        }
+    }
+
+    /// Returns the parent of `v` or `None` if `v` is the root.
+    pub fn parent(&self, v: Vertex<G>) -> Option<Vertex<G>> {
+        self.parent[v].into_option()
+    }
+
+    /// Returns the depth of `v`, that is, the distance from the root to `v`.
+    pub fn depth(&self, v: Vertex<G>) -> usize {
+        self.depth[v]
+    }
+
+    /// Returns the head of the chain of `v`, that is, the vertex of the chain closest to the root.
+    pub fn head(&self, v: Vertex<G>) -> Vertex<G> {
+        self.head[v]
+    }
+
+    /// Returns the position of `v` in the linear order of the vertices.
+    pub fn pos(&self, v: Vertex<G>) -> usize {
+        self.pos[v]
+    }
+
+    /// Returns the intervals of positions of the vertices in the path from `u` to `v`, as pairs
+    /// `(start, end)` with `start <= end` and both inclusive. The intervals are disjoint, and
+    /// there are O(log n) of them.
+    pub fn path_segments(&self, mut u: Vertex<G>, mut v: Vertex<G>) -> Vec<(usize, usize)> {
+        let mut segments = vec![];
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                ::std::mem::swap(&mut u, &mut v);
+            }
+            let h = self.head[u];
+            segments.push((self.pos[h], self.pos[u]));
+            u = self.parent[h].into_option().unwrap();
+        }
+        let (a, b) = (self.pos[u], self.pos[v]);
+        segments.push((a.min(b), a.max(b)));
+        segments
+    }
+}
+
 pub struct IsTree<'a> {
     tree: &'a mut bool,
     saw_root: bool,
@@ -85,8 +854,253 @@ impl<'a, G: VertexList + EdgeList> Visitor<G> for IsTree<'a> {
 mod tests {
     use super::*;
     use algs::Distances;
+    use fera_fun::{set, vec};
     use rand::prelude::*;
 
+    fn check_forest(
+        g: &StaticGraph,
+        tree: &DefaultVertexPropMut<StaticGraph, OptionEdge<StaticGraph>>,
+    ) {
+        let edges = vec(g.vertices().filter_map(|v| tree[v].into_option()));
+        for v in g.vertices() {
+            if let Some(e) = tree[v].into_option() {
+                assert_eq!(v, g.target(e));
+            }
+        }
+        let mut ds = g.new_unionfind();
+        for &e in &edges {
+            let (u, v) = g.ends(e);
+            assert!(!ds.in_same_set(u, v));
+            ds.union(u, v);
+        }
+        let cc = g.connected_components();
+        assert_eq!(g.num_vertices() - cc.num_components(), edges.len());
+    }
+
+    #[test]
+    fn dfs_and_bfs_forest_random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..30 {
+            let m = rng.gen_range(0, 2 * n).min(n * (n - 1) / 2);
+            let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+            check_forest(&g, &g.dfs_forest());
+            check_forest(&g, &g.bfs_forest());
+        }
+    }
+
+    #[test]
+    fn dfs_and_bfs_tree() {
+        let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (4, 5));
+        let v = vec(g.vertices());
+        for tree in &[g.dfs_tree(v[0]), g.bfs_tree(v[0])] {
+            assert_eq!(None, tree[v[0]].into_option());
+            assert_eq!(None, tree[v[4]].into_option());
+            assert_eq!(None, tree[v[5]].into_option());
+            assert!(v[1..4].iter().all(|&u| tree[u].into_option().is_some()));
+        }
+        // every vertex is at distance 1 from 0
+        let tree = g.bfs_tree(v[0]);
+        assert!(v[1..4]
+            .iter()
+            .all(|&u| g.source(tree[u].into_option().unwrap()) == v[0]));
+    }
+
+    #[test]
+    fn tree_diameter_path_and_center() {
+        let g = StaticGraph::new_empty(0);
+        assert_eq!(Some(vec![]), g.tree_diameter_path());
+        assert_eq!(Some(vec![]), g.tree_center());
+
+        let g = StaticGraph::new_empty(1);
+        assert_eq!(Some(vec![0]), g.tree_diameter_path());
+        assert_eq!(Some(vec![0]), g.tree_center());
+
+        let g = StaticGraph::new_with_edges(2, vec![(0, 1)]);
+        assert_eq!(set(vec![0, 1]), set(g.tree_diameter_path().unwrap()));
+        assert_eq!(set(vec![0, 1]), set(g.tree_center().unwrap()));
+
+        let g = StaticGraph::new_with_edges(4, vec![(0, 1), (1, 2), (2, 3)]);
+        let path = g.tree_diameter_path().unwrap();
+        assert!(path == vec![0, 1, 2, 3] || path == vec![3, 2, 1, 0]);
+        assert_eq!(set(vec![1, 2]), set(g.tree_center().unwrap()));
+
+        let g = StaticGraph::new_with_edges(5, vec![(0, 1), (1, 2), (2, 3), (1, 4)]);
+        assert_eq!(4, g.tree_diameter_path().unwrap().len());
+        assert_eq!(set(vec![1, 2]), set(g.tree_center().unwrap()));
+
+        let g = StaticGraph::new_with_edges(5, vec![(0, 1), (1, 2), (2, 3), (2, 4)]);
+        assert_eq!(4, g.tree_diameter_path().unwrap().len());
+        assert_eq!(set(vec![1, 2]), set(g.tree_center().unwrap()));
+
+        let g = StaticGraph::new_with_edges(4, vec![(0, 1), (1, 2), (1, 3)]);
+        assert_eq!(3, g.tree_diameter_path().unwrap().len());
+        assert_eq!(Some(vec![1]), g.tree_center());
+
+        let g = StaticGraph::new_with_edges(3, vec![(0, 1), (1, 2), (2, 0)]);
+        assert_eq!(None, g.tree_diameter_path());
+        assert_eq!(None, g.tree_center());
+
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..30 {
+            let g = StaticGraph::new_random_tree(n, &mut rng);
+            let path = g.tree_diameter_path().unwrap();
+            assert_eq!(Ok(path.len() - 1), g.tree_diameter());
+            assert!(path
+                .windows(2)
+                .all(|w| g.get_edge_by_ends(w[0], w[1]).is_some()));
+            let center = set(vec![path[(path.len() - 1) / 2], path[path.len() / 2]]);
+            assert_eq!(center, set(g.tree_center().unwrap()));
+        }
+    }
+
+    fn lca_naive(t: &RootedTree<StaticGraph>, mut u: u32, mut v: u32) -> u32 {
+        while t.depth(u) > t.depth(v) {
+            u = t.parent(u).unwrap();
+        }
+        while t.depth(v) > t.depth(u) {
+            v = t.parent(v).unwrap();
+        }
+        while u != v {
+            u = t.parent(u).unwrap();
+            v = t.parent(v).unwrap();
+        }
+        u
+    }
+
+    #[test]
+    fn root_tree() {
+        // balanced binary tree with 15 vertices, the children of v are 2v + 1 and 2v + 2
+        let g = StaticGraph::new_with_edges(15, (1..15).map(|v| ((v - 1) / 2, v)));
+        let t = g.root_tree(0);
+        assert_eq!(0, t.root());
+        assert_eq!(None, t.parent(0));
+        for v in 1..15 {
+            assert_eq!(Some((v - 1) / 2), t.parent(v));
+        }
+        for v in 0..7 {
+            assert_eq!(vec![2 * v + 1, 2 * v + 2], vec(t.children(v)));
+        }
+        assert!(t.children(7).next().is_none());
+        for v in 0..15 {
+            assert_eq!((32 - (v + 1u32).leading_zeros() - 1) as usize, t.depth(v));
+            assert_eq!(v, t.lca(v, v));
+            assert_eq!(0, t.lca(0, v));
+        }
+        assert_eq!(3, t.lca(7, 8));
+        assert_eq!(1, t.lca(7, 10));
+        assert_eq!(1, t.lca(4, 8));
+        assert_eq!(0, t.lca(7, 14));
+        assert_eq!(3, t.lca(3, 7));
+        assert_eq!(2, t.lca(5, 6));
+        assert_eq!(2, t.lca(13, 11));
+
+        let t = g.root_tree(7);
+        assert_eq!(4, t.depth(2));
+        assert_eq!(3, t.lca(8, 9));
+        assert_eq!(2, t.lca(5, 6));
+        assert_eq!(4, t.lca(4, 10));
+        assert_eq!(1, t.lca(4, 6));
+
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..50 {
+            let g = StaticGraph::new_random_tree(n, &mut rng);
+            let r = g.choose_vertex(&mut rng).unwrap();
+            let t = g.root_tree(r);
+            for u in g.vertices() {
+                for v in g.vertices() {
+                    assert_eq!(lca_naive(&t, u, v), t.lca(u, v));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tarjan_offline_lca() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..50 {
+            let g = StaticGraph::new_random_tree(n, &mut rng);
+            let r = g.choose_vertex(&mut rng).unwrap();
+            let t = g.root_tree(r);
+            let queries = vec((0..2 * n).map(|_| {
+                (
+                    g.choose_vertex(&mut rng).unwrap(),
+                    g.choose_vertex(&mut rng).unwrap(),
+                )
+            }));
+            let expected = vec(queries.iter().map(|&(u, v)| t.lca(u, v)));
+            assert_eq!(expected, g.tarjan_offline_lca(r, &queries));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the graph is not a tree")]
+    fn tarjan_offline_lca_not_tree() {
+        StaticGraph::new_with_edges(3, vec![(0, 1), (1, 2), (2, 0)]).tarjan_offline_lca(0, &[]);
+    }
+
+    #[test]
+    fn heavy_light_decomposition() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..60 {
+            let g = StaticGraph::new_random_tree(n, &mut rng);
+            let root = rng.gen_range(0, n as u32);
+            let hl = g.heavy_light_decomposition(root);
+            let tree = g.root_tree(root);
+            let mut pos = vec(g.vertices().map(|v| hl.pos(v)));
+            pos.sort();
+            assert_eq!(vec(0..n), pos);
+            for v in g.vertices() {
+                assert_eq!(tree.parent(v), hl.parent(v));
+                assert_eq!(tree.depth(v), hl.depth(v));
+                // the chain goes up from v to its head in consecutive positions
+                let h = hl.head(v);
+                let mut u = v;
+                while u != h {
+                    let p = hl.parent(u).unwrap();
+                    assert_eq!(hl.pos(p) + 1, hl.pos(u));
+                    u = p;
+                }
+            }
+            for _ in 0..20 {
+                let u = rng.gen_range(0, n as u32);
+                let v = rng.gen_range(0, n as u32);
+                let lca = tree.lca(u, v);
+                let mut path = vec![lca];
+                for &x in &[u, v] {
+                    let mut x = x;
+                    while x != lca {
+                        path.push(x);
+                        x = tree.parent(x).unwrap();
+                    }
+                }
+                let mut expected = vec(path.iter().map(|&x| hl.pos(x)));
+                expected.sort();
+                let segments = hl.path_segments(u, v);
+                let mut covered = vec(segments.iter().flat_map(|&(a, b)| a..b + 1));
+                covered.sort();
+                assert_eq!(expected, covered);
+                assert!(1 << (segments.len() / 2) <= 2 * n);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the graph is not a tree")]
+    fn root_tree_not_tree() {
+        StaticGraph::new_with_edges(3, vec![(0, 1)]).root_tree(0);
+    }
+
+    #[test]
+    fn is_forest() {
+        assert!(StaticGraph::new_empty(0).is_forest());
+        assert!(StaticGraph::new_empty(3).is_forest());
+        assert!(StaticGraph::new_with_edges(4, vec![(0, 1), (2, 3)]).is_forest());
+        assert!(StaticGraph::new_with_edges(3, vec![(0, 1), (1, 2)]).is_forest());
+        assert!(!StaticGraph::new_with_edges(3, vec![(0, 1), (1, 2), (2, 0)]).is_forest());
+        assert!(!StaticGraph::new_with_edges(2, vec![(0, 1), (1, 0)]).is_forest());
+        assert!(!StaticGraph::new_with_edges(2, vec![(1, 1)]).is_forest());
+    }
+
     #[test]
     fn tree_diameter() {
         let mut rng = SmallRng::from_entropy();
@@ -104,4 +1118,97 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn prufer_code() {
+        let g: StaticGraph = graph!(2, (1, 0));
+        assert!(g.prufer_code().is_empty());
+
+        let mut rng = SmallRng::from_entropy();
+        for n in 2..30 {
+            let g = StaticGraph::new_random_tree(n, &mut rng);
+            let code = g.prufer_code();
+            assert_eq!(n - 2, code.len());
+            let h = StaticGraph::new_from_prufer_code(&code).unwrap();
+            let ends = |g: &StaticGraph| set(g.edges_ends().map(|(u, v)| (u.min(v), u.max(v))));
+            assert_eq!(ends(&g), ends(&h));
+            assert_eq!(code, h.prufer_code());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the graph is not a tree")]
+    fn prufer_code_not_tree() {
+        let g: StaticGraph = graph!(4, (0, 1), (2, 3));
+        g.prufer_code();
+    }
+
+    fn centroid_depth(
+        g: &StaticGraph,
+        parent: &DefaultVertexPropMut<StaticGraph, OptionVertex<StaticGraph>>,
+    ) -> usize {
+        g.vertices()
+            .map(|mut v| {
+                let mut depth = 0;
+                while let Some(p) = parent[v].into_option() {
+                    v = p;
+                    depth += 1;
+                }
+                depth
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn centroid_decomposition_path() {
+        for n in 1..100usize {
+            let g = StaticGraph::new_with_edges(n, (1..n).map(|i| (i - 1, i)));
+            let (root, parent) = g.centroid_decomposition();
+            assert_eq!(None, parent[root].into_option());
+            assert!((root as usize * 2 + 1).max(n) - (root as usize * 2 + 1).min(n) <= 1);
+            // 2^depth <= n
+            assert!(1 << centroid_depth(&g, &parent) <= n);
+        }
+    }
+
+    #[test]
+    fn centroid_decomposition_random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..50 {
+            let g = StaticGraph::new_random_tree(n, &mut rng);
+            let (root, parent) = g.centroid_decomposition();
+            assert_eq!(
+                vec![root],
+                vec(g.vertices().filter(|&v| parent[v].into_option().is_none()))
+            );
+            assert!(1 << centroid_depth(&g, &parent) <= n);
+            // removing the root leaves components with at most n / 2 vertices
+            let sub = g.induced_subgraph(g.vertices().filter(|&v| v != root));
+            assert!(sub.component_sizes().iter().all(|&s| 2 * s <= n));
+        }
+    }
+
+    #[test]
+    fn random_spanning_tree() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..30 {
+            let max = n * (n - 1) / 2;
+            for &m in &[0, n / 2, n, 2 * n, max] {
+                let m = m.min(max);
+                let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+                let tree = g.random_spanning_tree(&mut rng);
+                assert_eq!(n - g.num_components() as usize, tree.len());
+                let mut ds = g.new_unionfind();
+                for &e in &tree {
+                    let (u, v) = g.ends(e);
+                    assert!(!ds.in_same_set(u, v));
+                    ds.union(u, v);
+                }
+                if g.is_connected() {
+                    assert!(g.spanning_subgraph(&tree).is_tree());
+                }
+            }
+        }
+    }
 }
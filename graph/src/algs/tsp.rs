@@ -0,0 +1,217 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Heuristics for the traveling salesman problem.
+//!
+//! A tour is a sequence of all vertices of the graph, it represents the cycle that visits the
+//! vertices in the sequence order and goes back from the last vertex to the first. These functions
+//! are meant to be used with complete graphs (see [`Complete::with_weights`]), where there is an
+//! edge between each pair of vertices and `edge_by_ends` takes constant time.
+//!
+//! [`Complete::with_weights`]: ../../graphs/complete/struct.Complete.html#method.with_weights
+
+use prelude::*;
+
+use num_traits::Zero;
+
+use std::ops::Add;
+
+/// Returns the length of `tour`, that is, the sum of the weights of the edges between consecutive
+/// vertices of `tour` plus the weight of the edge from the last to the first vertex. The length of
+/// a tour with less than two vertices is zero.
+///
+/// # Panics
+///
+/// If there is no edge between two consecutive vertices.
+pub fn tour_length<G, W, T>(g: &G, tour: &[Vertex<G>], w: W) -> T
+where
+    G: EdgeList,
+    W: EdgePropGet<G, T>,
+    T: Add<Output = T> + Zero,
+{
+    if tour.len() < 2 {
+        return T::zero();
+    }
+    let next = tour.iter().skip(1).chain(&tour[..1]);
+    tour.iter()
+        .zip(next)
+        .fold(T::zero(), |len, (&u, &v)| len + w.get(g.edge_by_ends(u, v)))
+}
+
+/// Builds a tour starting at `start` by repeatedly going to the nearest vertex not yet visited.
+///
+/// Returns the tour length and the tour. The running time is O(V²) for a complete graph.
+///
+/// # Panics
+///
+/// If the last visited vertex has no edge to an unvisited vertex, or no edge back to `start`,
+/// which does not happen for complete graphs.
+///
+/// # Examples
+///
+/// ```
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::tsp::nearest_neighbor_tour;
+///
+/// // points in a line
+/// let x = [0i32, 5, 1, 3];
+/// let (g, w) = CompleteGraph::with_weights(4, |u, v| (x[u as usize] - x[v as usize]).abs());
+/// assert_eq!((10, vec![0, 2, 3, 1]), nearest_neighbor_tour(&g, 0, &w));
+/// ```
+pub fn nearest_neighbor_tour<G, W, T>(g: &G, start: Vertex<G>, w: W) -> (T, Vec<Vertex<G>>)
+where
+    G: IncidenceGraph,
+    W: EdgePropGet<G, T>,
+    T: Copy + Ord + Add<Output = T> + Zero,
+{
+    let n = g.num_vertices();
+    let mut visited = g.default_vertex_prop(false);
+    let mut tour = Vec::with_capacity(n);
+    let mut cur = start;
+    visited[cur] = true;
+    tour.push(cur);
+    while tour.len() < n {
+        cur = g
+            .out_edges(cur)
+            .filter(|&e| !visited[g.target(e)])
+            .min_by_key(|&e| w.get(e))
+            .map(|e| g.target(e))
+            .expect("an edge to an unvisited vertex");
+        visited[cur] = true;
+        tour.push(cur);
+    }
+    (tour_length(g, &tour, &w), tour)
+}
+
+/// Improves `tour` using the 2-opt heuristic, that is, while there are two edges `(a, b)` and
+/// `(c, d)` of the tour such that replacing them by `(a, c)` and `(b, d)` (and reversing the path
+/// from `b` to `c`) decreases the tour length, the replacement is done. The resulting tour has no
+/// crossing edges if the weights are euclidean distances.
+///
+/// The weights must be symmetric, that is, the weight of `(u, v)` must be the same as the weight
+/// of `(v, u)`.
+///
+/// Returns the tour length and the improved tour. Each improvement step takes O(V²) time.
+///
+/// # Examples
+///
+/// ```
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::tsp::{tour_length, two_opt};
+///
+/// // points in a line
+/// let x = [0i32, 5, 1, 3];
+/// let (g, w) = CompleteGraph::with_weights(4, |u, v| (x[u as usize] - x[v as usize]).abs());
+/// assert_eq!(14, tour_length(&g, &[0, 1, 2, 3], &w));
+/// assert_eq!((10, vec![0, 2, 1, 3]), two_opt(&g, vec![0, 1, 2, 3], &w));
+/// ```
+pub fn two_opt<G, W, T>(g: &G, mut tour: Vec<Vertex<G>>, w: W) -> (T, Vec<Vertex<G>>)
+where
+    G: EdgeList,
+    W: EdgePropGet<G, T>,
+    T: Copy + Ord + Add<Output = T> + Zero,
+{
+    let n = tour.len();
+    let weight = |u, v| w.get(g.edge_by_ends(u, v));
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 0..n.saturating_sub(2) {
+            // when i == 0, the edge (t[n - 1], t[0]) is adjacent to (t[0], t[1])
+            let last = if i == 0 { n - 1 } else { n };
+            for j in i + 2..last {
+                let (a, b) = (tour[i], tour[i + 1]);
+                let (c, d) = (tour[j], tour[(j + 1) % n]);
+                if weight(a, c) + weight(b, d) < weight(a, b) + weight(c, d) {
+                    tour[i + 1..j + 1].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+    (tour_length(g, &tour, &w), tour)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::vec;
+    use rand::prelude::*;
+
+    use std::f64::consts::PI;
+
+    // the weights are the euclidean distances multiplied by 10^6
+    fn points_weights(
+        points: Vec<(f64, f64)>,
+    ) -> (CompleteGraph, impl EdgePropGet<CompleteGraph, u64>) {
+        CompleteGraph::with_weights(points.len() as u32, move |u, v| {
+            let (a, b) = points[u as usize];
+            let (c, d) = points[v as usize];
+            ((a - c).hypot(b - d) * 1e6) as u64
+        })
+    }
+
+    #[test]
+    fn small() {
+        let g = CompleteGraph::new(0);
+        let w = g.weights(|_, _| 1u32);
+        assert_eq!((0, vec![]), two_opt(&g, vec![], &w));
+        let g = CompleteGraph::new(1);
+        let w = g.weights(|_, _| 1u32);
+        assert_eq!((0, vec![0]), nearest_neighbor_tour(&g, 0, &w));
+        assert_eq!((0, vec![0]), two_opt(&g, vec![0], &w));
+        let g = CompleteGraph::new(2);
+        let w = g.weights(|_, _| 1u32);
+        assert_eq!((2, vec![1, 0]), nearest_neighbor_tour(&g, 1, &w));
+        assert_eq!((2, vec![1, 0]), two_opt(&g, vec![1, 0], &w));
+    }
+
+    #[test]
+    fn two_opt_random() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 3..30 {
+            let points = vec((0..n).map(|_| (rng.gen_range(0.0, 1.0), rng.gen_range(0.0, 1.0))));
+            let (g, w) = points_weights(points);
+            let (len, tour) = nearest_neighbor_tour(&g, 0, &w);
+            let (opt_len, opt_tour) = two_opt(&g, tour, &w);
+            assert!(opt_len <= len);
+            assert_eq!(opt_len, tour_length(&g, &opt_tour, &w));
+            let mut sorted = opt_tour.clone();
+            sorted.sort();
+            assert_eq!(vec(g.vertices()), sorted);
+
+            let mut tour = vec(g.vertices());
+            tour.shuffle(&mut rng);
+            let len = tour_length(&g, &tour, &w);
+            assert!(two_opt(&g, tour, &w).0 <= len);
+        }
+    }
+
+    #[test]
+    fn two_opt_convex() {
+        // the points of a regular polygon, the optimal tour is the polygon
+        let mut rng = SmallRng::from_entropy();
+        for n in 3..15 {
+            let points = vec((0..n).map(|i| {
+                let a = 2.0 * PI * i as f64 / n as f64;
+                (a.cos(), a.sin())
+            }));
+            let (g, w) = points_weights(points);
+            let polygon = vec(g.vertices());
+            for _ in 0..5 {
+                let mut tour = polygon.clone();
+                tour.shuffle(&mut rng);
+                let (len, tour) = two_opt(&g, tour, &w);
+                assert_eq!(tour_length(&g, &polygon, &w), len);
+                // each vertex is followed or preceded by the next vertex of the polygon
+                let n = n as usize;
+                let pos = |v: u32| tour.iter().position(|&u| u == v).unwrap();
+                for v in 0..n as u32 {
+                    let d = (pos(v) + n - pos((v + 1) % n as u32)) % n;
+                    assert!(d == 1 || d == n - 1);
+                }
+            }
+        }
+    }
+}
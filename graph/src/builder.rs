@@ -82,6 +82,8 @@ use props::Color;
 use sets::FastVecSet;
 
 use std::cmp;
+use std::error::Error;
+use std::fmt;
 use std::mem;
 
 use fera_fun::set;
@@ -212,9 +214,71 @@ pub trait Builder {
     /// If `u` or `v` is not a valid vertex, that is `>= num_vertices`.
     fn add_edge(&mut self, u: usize, v: usize);
 
+    /// Add each `(u, v)` edge of `edges` to the graph.
+    ///
+    /// # Panics
+    ///
+    /// If some `u` or `v` is not a valid vertex, that is `>= num_vertices`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fera_graph::prelude::*;
+    ///
+    /// let mut builder = StaticGraph::builder(4, 3);
+    /// builder.add_edges(vec![(0, 1), (1, 2), (2, 3)]);
+    /// let g = builder.finalize();
+    /// assert_eq!(3, g.num_edges());
+    /// ```
+    fn add_edges<I>(&mut self, edges: I)
+    where
+        Self: Sized,
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        for (u, v) in edges {
+            self.add_edge(u, v);
+        }
+    }
+
     /// Builds the graph.
+    ///
+    /// # Panics
+    ///
+    /// If the graph cannot be built, see [`try_finalize`].
+    ///
+    /// [`try_finalize`]: #method.try_finalize
     fn finalize(self) -> Self::Graph;
 
+    /// Builds the graph or returns an error if the graph type cannot represent the number of
+    /// vertices or edges added to the builder.
+    ///
+    /// The default implementation calls [`finalize`], builders for graphs with limited capacity
+    /// must override this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::builder::BuilderError;
+    /// use fera_graph::graphs::static_::Static;
+    ///
+    /// // u8 vertices and edges
+    /// type G = Static<u8, (Directed, u8)>;
+    /// assert!(G::builder(254, 0).try_finalize().is_ok());
+    /// assert_eq!(
+    ///     Err(BuilderError::TooManyVertices { max: 254 }),
+    ///     G::builder(255, 0).try_finalize().map(|g| g.num_vertices())
+    /// );
+    /// ```
+    ///
+    /// [`finalize`]: #tymethod.finalize
+    fn try_finalize(self) -> Result<Self::Graph, BuilderError>
+    where
+        Self: Sized,
+    {
+        Ok(self.finalize())
+    }
+
     #[doc(hidden)]
     fn finalize_(
         self,
@@ -225,6 +289,32 @@ pub trait Builder {
     );
 }
 
+/// An error produced by [`Builder::try_finalize`].
+///
+/// [`Builder::try_finalize`]: trait.Builder.html#method.try_finalize
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuilderError {
+    /// The graph type supports at most `max` vertices.
+    TooManyVertices { max: usize },
+    /// The graph type supports at most `max` edges.
+    TooManyEdges { max: usize },
+}
+
+impl fmt::Display for BuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BuilderError::TooManyVertices { max } => {
+                write!(f, "the graph supports at most {} vertices", max)
+            }
+            BuilderError::TooManyEdges { max } => {
+                write!(f, "the graph supports at most {} edges", max)
+            }
+        }
+    }
+}
+
+impl Error for BuilderError {}
+
 /// A graph that has a [`Builder`].
 ///
 /// See the [module documentation] for examples.
@@ -244,21 +334,61 @@ pub trait WithBuilder: WithEdge {
 
     /// Creates a new graph with `n` vertices from `edges` iterator.
     ///
+    /// This is the same as creating a builder, adding the edges with [`Builder::add_edges`] and
+    /// calling [`Builder::finalize`]. The builder is created with capacity for the number of edges
+    /// given by the `edges` size hint.
+    ///
     /// # Panics
     ///
     /// If some edges is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fera_graph::prelude::*;
+    ///
+    /// let g = StaticGraph::new_with_edges(4, vec![(0, 1), (1, 2), (2, 3)]);
+    /// assert_eq!(4, g.num_vertices());
+    /// assert_eq!(3, g.num_edges());
+    /// ```
+    ///
+    /// [`Builder::add_edges`]: trait.Builder.html#method.add_edges
+    /// [`Builder::finalize`]: trait.Builder.html#tymethod.finalize
     fn new_with_edges<I>(n: usize, edges: I) -> Self
     where
         I: IntoIterator<Item = (usize, usize)>,
     {
         let edges = edges.into_iter();
-        let mut b = Self::Builder::new(n, edges.size_hint().1.unwrap_or(0));
-        for (u, v) in edges {
-            b.add_edge(u, v);
-        }
+        let (lower, upper) = edges.size_hint();
+        let mut b = Self::Builder::new(n, upper.unwrap_or(lower));
+        b.add_edges(edges);
         b.finalize()
     }
 
+    /// Creates a new graph with `num_vertices` vertices from `edges` iterator. This is the same as
+    /// [`new_with_edges`].
+    ///
+    /// # Panics
+    ///
+    /// If some edges is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fera_graph::prelude::*;
+    ///
+    /// let g = StaticGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+    /// assert_eq!(vec![(0, 1), (1, 2)], g.edges_ends().collect::<Vec<_>>());
+    /// ```
+    ///
+    /// [`new_with_edges`]: #method.new_with_edges
+    fn from_edges<I>(num_vertices: usize, edges: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        Self::new_with_edges(num_vertices, edges)
+    }
+
     #[doc(hidden)]
     fn new_with_edges_prop<T>(
         n: usize,
@@ -344,6 +474,28 @@ pub trait WithBuilder: WithEdge {
         random_tree_with_diameter::<Self, _>(n, d, rng).map(Builder::finalize)
     }
 
+    /// Creates the tree with `code.len() + 2` vertices that has the Prüfer code `code`. Returns
+    /// `None` if some value of `code` is not less than `code.len() + 2`.
+    ///
+    /// There is a bijection between the sequences of length `n - 2` with values in `0..n` and the
+    /// labeled trees with `n` vertices, so a uniformly random tree can be created from a random
+    /// sequence (see also [`new_random_tree`]).
+    ///
+    /// [`new_random_tree`]: #method.new_random_tree
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fera_graph::prelude::*;
+    ///
+    /// let g = StaticGraph::new_from_prufer_code(&[3, 3, 3, 4]).unwrap();
+    /// assert_eq!(6, g.num_vertices());
+    /// assert_eq!(vec![(0, 3), (1, 3), (2, 3), (3, 4), (4, 5)], g.edges_ends().collect::<Vec<_>>());
+    /// ```
+    fn new_from_prufer_code(code: &[usize]) -> Option<Self> {
+        prufer_decode::<Self>(code).map(Builder::finalize)
+    }
+
     /// Creates a random graph with `n` vertices.
     fn new_gn<R>(n: usize, mut rng: R) -> Self
     where
@@ -383,6 +535,18 @@ pub trait WithBuilder: WithEdge {
         gnm::<Self, _>(n, m, rng).map(Builder::finalize)
     }
 
+    /// Creates a random graph with `n` vertices where each of the possible edges (without loops
+    /// and parallel edges) is included independently with probability `p`.
+    ///
+    /// The running time is O(n + m), where m is the number of edges created.
+    fn new_gnp<R>(n: usize, p: f64, rng: R) -> Self
+    where
+        Self::Kind: UniformEdgeKind,
+        R: Rng,
+    {
+        gnp::<Self, _>(n, p, rng).finalize()
+    }
+
     /// Creates a random connected graph (weakly connected if `Self` is a digraph) with `n`
     /// vertices and `m` edges.
     ///
@@ -395,6 +559,61 @@ pub trait WithBuilder: WithEdge {
     }
 }
 
+/// Creates a grid graph with `rows` rows and `cols` columns, where each vertex is adjacent to its
+/// (up to) four neighbors in the same row or column. Returns the graph and a function that maps a
+/// pair `(row, col)` to the corresponding vertex.
+///
+/// If `periodic` is `true`, the first and last vertices of each row and of each column are also
+/// adjacent, which makes the grid a torus. To avoid loops and parallel edges, these wrapping edges
+/// are only added along dimensions with at least three vertices.
+///
+/// # Examples
+///
+/// ```
+/// use fera_graph::prelude::*;
+/// use fera_graph::builder::grid_graph;
+///
+/// let (g, vertex): (StaticGraph, _) = grid_graph(2, 3, false);
+/// assert_eq!(6, g.num_vertices());
+/// assert_eq!(7, g.num_edges());
+/// assert!(g.get_edge_by_ends(vertex(0, 1), vertex(1, 1)).is_some());
+/// assert!(g.get_edge_by_ends(vertex(0, 0), vertex(0, 2)).is_none());
+///
+/// let (g, vertex): (StaticGraph, _) = grid_graph(2, 3, true);
+/// assert_eq!(9, g.num_edges());
+/// assert!(g.get_edge_by_ends(vertex(0, 0), vertex(0, 2)).is_some());
+/// ```
+pub fn grid_graph<G>(
+    rows: usize,
+    cols: usize,
+    periodic: bool,
+) -> (G, impl Fn(usize, usize) -> Vertex<G>)
+where
+    G: WithBuilder,
+{
+    let index = move |r: usize, c: usize| r * cols + c;
+    let mut b = G::builder(rows * cols, 2 * rows * cols);
+    for r in 0..rows {
+        for c in 0..cols {
+            if c + 1 < cols {
+                b.add_edge(index(r, c), index(r, c + 1));
+            } else if periodic && cols > 2 {
+                b.add_edge(index(r, c), index(r, 0));
+            }
+            if r + 1 < rows {
+                b.add_edge(index(r, c), index(r + 1, c));
+            } else if periodic && rows > 2 {
+                b.add_edge(index(r, c), index(0, c));
+            }
+        }
+    }
+    let (g, v, _) = b.finalize_();
+    (g, move |r, c| {
+        assert!(r < rows && c < cols, "invalid position: ({}, {})", r, c);
+        v[index(r, c)]
+    })
+}
+
 fn complete<G: WithBuilder>(n: usize) -> G::Builder {
     let mut b = G::builder(n, (n * n - n) / 2);
     for u in 0..n {
@@ -467,6 +686,34 @@ where
     b
 }
 
+fn prufer_decode<G: WithBuilder>(code: &[usize]) -> Option<G::Builder> {
+    let n = code.len() + 2;
+    let mut degree = vec![1; n];
+    for &v in code {
+        *degree.get_mut(v)? += 1;
+    }
+
+    let mut b = G::builder(n, n - 1);
+    // ptr is the smallest leaf not yet removed that is not less than any removed leaf
+    let mut ptr = degree.iter().position(|&d| d == 1).unwrap();
+    let mut leaf = ptr;
+    for &v in code {
+        b.add_edge(cmp::min(leaf, v), cmp::max(leaf, v));
+        degree[v] -= 1;
+        if degree[v] == 1 && v < ptr {
+            leaf = v;
+        } else {
+            ptr += 1;
+            while degree[ptr] != 1 {
+                ptr += 1;
+            }
+            leaf = ptr;
+        }
+    }
+    b.add_edge(leaf, n - 1);
+    Some(b)
+}
+
 fn random_tree_with_diameter<G, R>(n: u32, d: u32, mut rng: R) -> Option<G::Builder>
 where
     G: WithBuilder,
@@ -686,6 +933,69 @@ where
     Some(b)
 }
 
+fn gnp<G, R>(n: usize, p: f64, mut rng: R) -> G::Builder
+where
+    G: WithBuilder,
+    G::Kind: UniformEdgeKind,
+    R: Rng,
+{
+    // Instead of testing each pair of vertices, the number of pairs skipped until the next edge
+    // (which has a geometric distribution) is sampled. See Batagelj and Brandes. Efficient
+    // generation of large random networks. Physical Review E, 2005.
+    let max = if G::Kind::is_directed() {
+        n * n.saturating_sub(1)
+    } else {
+        n * n.saturating_sub(1) / 2
+    };
+    let mut b = G::builder(n, (p.min(1.0) * max as f64) as usize);
+    if p <= 0.0 || n < 2 {
+        return b;
+    }
+
+    let lq = (1.0 - p).ln();
+    let mut skip = || {
+        if p >= 1.0 {
+            1
+        } else {
+            let r: f64 = 1.0 - rng.gen::<f64>();
+            ((r.ln() / lq).floor() as usize).saturating_add(1)
+        }
+    };
+
+    if G::Kind::is_directed() {
+        // the pairs are (u, j) with j < n - 1, which represents the edge (u, v) where v = j if
+        // j < u or v = j + 1 otherwise
+        let (mut u, mut j) = (0, skip() - 1);
+        loop {
+            while u < n && j >= n - 1 {
+                j -= n - 1;
+                u += 1;
+            }
+            if u == n {
+                break;
+            }
+            b.add_edge(u, if j < u { j } else { j + 1 });
+            j = j.saturating_add(skip());
+        }
+    } else {
+        // the pairs are (u, v) with u < v
+        let (mut u, mut v) = (skip() - 1, 1);
+        loop {
+            while v < n && u >= v {
+                u -= v;
+                v += 1;
+            }
+            if v == n {
+                break;
+            }
+            b.add_edge(u, v);
+            u = u.saturating_add(skip());
+        }
+    }
+
+    b
+}
+
 fn regular<G, R>(d: usize, n: usize, mut rng: R) -> Option<G::Builder>
 where
     G: WithBuilder,
@@ -956,6 +1266,43 @@ pub trait BuilderTests {
         }
     }
 
+    fn gnp()
+    where
+        Self::G: WithEdge + VertexList + EdgeList,
+        <Self::G as WithEdge>::Kind: UniformEdgeKind,
+    {
+        use std::collections::HashSet;
+
+        let mut rng = SmallRng::from_entropy();
+        let directed = <Self::G as WithEdge>::Kind::is_directed();
+
+        for n in 0..10usize {
+            let max = if directed {
+                n * n.saturating_sub(1)
+            } else {
+                n * n.saturating_sub(1) / 2
+            };
+            let g = Self::G::new_gnp(n, 0.0, &mut rng);
+            assert_eq!(n, g.num_vertices());
+            assert_eq!(0, g.num_edges());
+
+            let g = Self::G::new_gnp(n, 1.0, &mut rng);
+            assert_eq!(n, g.num_vertices());
+            assert_eq!(max, g.num_edges());
+
+            let g = Self::G::new_gnp(n, 0.5, &mut rng);
+            assert_eq!(n, g.num_vertices());
+            let mut seen = HashSet::new();
+            for (u, v) in g.edges_ends() {
+                assert_ne!(u, v);
+                assert!(seen.insert((u, v)));
+                if !directed {
+                    assert!(seen.insert((v, u)));
+                }
+            }
+        }
+    }
+
     fn gnm_connected()
     where
         Self::G: Incidence + WithVertexProp<Color>,
@@ -1008,6 +1355,7 @@ macro_rules! graph_builder_tests {
             complete_binary_tree,
             gnm,
             gnm_connected,
+            gnp,
             random_cycle,
             random_path,
             random_tree,
@@ -1019,6 +1367,8 @@ macro_rules! graph_builder_tests {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use fera_fun::vec;
+    use std::collections::HashSet;
 
     #[test]
     fn random_tree_mean_diameter() {
@@ -1031,4 +1381,84 @@ mod tests {
         let mean = sum.unwrap() / times;
         assert!(27 == mean || 28 == mean || 29 == mean);
     }
+
+    #[test]
+    fn prufer_code() {
+        assert!(StaticGraph::new_from_prufer_code(&[0, 4]).is_none());
+
+        let g = StaticGraph::new_from_prufer_code(&[]).unwrap();
+        assert_eq!(vec![(0, 1)], vec(g.edges_ends()));
+
+        let g = StaticGraph::new_from_prufer_code(&[4, 4, 3, 4]).unwrap();
+        assert_eq!(
+            set(vec![(0, 4), (1, 4), (2, 3), (3, 4), (4, 5)]),
+            set(g.edges_ends())
+        );
+
+        // by Cayley's formula there are n^(n - 2) labeled trees with n vertices
+        let n = 5;
+        let mut trees = HashSet::new();
+        for i in 0..n * n * n {
+            let code = [i % n, i / n % n, i / n / n];
+            let g = StaticGraph::new_from_prufer_code(&code).unwrap();
+            assert!(g.is_tree());
+            let mut ends = vec(g.edges_ends().map(|(u, v)| (u.min(v), u.max(v))));
+            ends.sort();
+            trees.insert(ends);
+        }
+        assert_eq!(n * n * n, trees.len());
+    }
+
+    #[test]
+    fn grid() {
+        let (g, vertex): (StaticGraph, _) = grid_graph(4, 5, false);
+        assert_eq!(20, g.num_vertices());
+        assert_eq!(4 * 4 + 5 * 3, g.num_edges());
+        for r in 0..4 {
+            for c in 0..5 {
+                let border = (r == 0 || r == 3) as usize + (c == 0 || c == 4) as usize;
+                assert_eq!(4 - border, g.out_degree(vertex(r, c)));
+            }
+        }
+
+        let (g, vertex): (StaticGraph, _) = grid_graph(4, 5, true);
+        assert_eq!(2 * 4 * 5, g.num_edges());
+        assert!(g.vertices().all(|v| g.out_degree(v) == 4));
+        assert!(g.get_edge_by_ends(vertex(3, 2), vertex(0, 2)).is_some());
+        assert!(g.get_edge_by_ends(vertex(1, 4), vertex(1, 0)).is_some());
+
+        for &(rows, cols) in &[(0, 0), (0, 3), (1, 1), (1, 2), (2, 2), (1, 5)] {
+            let (g, _): (StaticGraph, _) = grid_graph(rows, cols, true);
+            assert_eq!(rows * cols, g.num_vertices());
+            let edges = set(g.edges_ends().map(|(u, v)| (u.min(v), u.max(v))));
+            assert_eq!(g.num_edges(), edges.len());
+            assert!(edges.iter().all(|&(u, v)| u != v));
+        }
+    }
+
+    #[test]
+    fn gnp_num_edges() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        // mean 1990 and standard deviation 42.3
+        let m = StaticGraph::new_gnp(200, 0.1, &mut rng).num_edges();
+        assert!(1780 < m && m < 2200, "{}", m);
+        // mean 3980 and standard deviation 59.8
+        let m = StaticDigraph::new_gnp(200, 0.1, &mut rng).num_edges();
+        assert!(3680 < m && m < 4280, "{}", m);
+    }
+
+    #[test]
+    fn gnp_digraph() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 0..10 {
+            let g = StaticDigraph::new_gnp(n, 1.0, &mut rng);
+            assert_eq!(n * n.saturating_sub(1), g.num_edges());
+            for v in g.vertices() {
+                assert!(g.get_edge_by_ends(v, v).is_none());
+            }
+            let g = StaticDigraph::new_gnp(n, 0.5, &mut rng);
+            assert!(g.edges_ends().all(|(u, v)| u != v));
+            assert_eq!(g.num_edges(), set(g.edges_ends()).len());
+        }
+    }
 }
@@ -28,10 +28,13 @@
 //! assert!(g.vertices().all(|v| saw[v]));
 //! # }
 //! ```
+use params::IntoOwned;
 use prelude::*;
 
 use rand::Rng;
 
+use std::cmp::Ordering;
+
 // TODO: specialization of *_iter
 // TODO: remove WithEdge bound and add bounds to methods
 // TODO: ex: g.choose().vertex(), g.choose_with_rng(rng).vertices()
@@ -95,6 +98,55 @@ pub trait Choose: WithEdge {
             rng: rng,
         }
     }
+
+    /// Returns an iterator that produces a sequence of vertices that forms a random walk starting
+    /// at `start`. The first vertex produced is `start`. At each step the walk stays at the current
+    /// vertex with probability `lazy`, otherwise it moves to the target of a random out edge of the
+    /// current vertex. The iterator ends after producing a vertex with no out edges.
+    ///
+    /// Unlike [`random_walk`], which produces the edges of the walk, the vertices produced here
+    /// include the repetitions caused by the lazy steps.
+    ///
+    /// # Panics
+    ///
+    /// If `lazy` is not in the range `[0, 1)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rand;
+    /// #[macro_use] extern crate fera_graph;
+    ///
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::choose::Choose;
+    /// use rand::prelude::*;
+    ///
+    /// # fn main() {
+    /// let g: StaticDigraph = graph!(3, (0, 1), (1, 2));
+    /// let walk: Vec<_> = g.random_vertex_walk(0, 0.0, SmallRng::from_entropy()).collect();
+    /// assert_eq!(vec![0, 1, 2], walk);
+    /// # }
+    /// ```
+    ///
+    /// [`random_walk`]: #method.random_walk
+    fn random_vertex_walk<R: Rng>(
+        &self,
+        start: Vertex<Self>,
+        lazy: f64,
+        rng: R,
+    ) -> RandomVertexWalk<Self, R> {
+        assert!(
+            (0.0..1.0).contains(&lazy),
+            "invalid lazy probability: {} (must be in [0, 1))",
+            lazy
+        );
+        RandomVertexWalk {
+            g: self,
+            next: Some(start),
+            lazy,
+            rng,
+        }
+    }
 }
 
 /// An iterator that produces random selected vertices of a graph.
@@ -225,4 +277,296 @@ where
     }
 }
 
-// TODO: write tests
+/// An iterator that produces a sequence of vertices that forms a lazy random walk.
+///
+/// This `struct` is created by [`Choose::random_vertex_walk`].
+///
+/// [`Choose::random_vertex_walk`]: trait.Choose.html#method.random_vertex_walk
+pub struct RandomVertexWalk<'a, G: 'a + WithVertex, R> {
+    g: &'a G,
+    next: Option<Vertex<G>>,
+    lazy: f64,
+    rng: R,
+}
+
+impl<'a, G, R> Iterator for RandomVertexWalk<'a, G, R>
+where
+    G: 'a + Choose,
+    R: Rng,
+{
+    type Item = Vertex<G>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cur = self.next?;
+        self.next = self.g.choose_out_edge(cur, &mut self.rng).map(|e| {
+            if self.lazy > 0.0 && self.rng.gen::<f64>() < self.lazy {
+                cur
+            } else {
+                self.g.target(e)
+            }
+        });
+        Some(cur)
+    }
+}
+
+// Weighted
+
+/// A graph from which vertices and edges can be randomly selected with probability proportional
+/// to a weight.
+///
+/// Each call to `choose_*_weighted` takes O(V) or O(E) time. Use a [`WeightedSampler`] to
+/// repeatedly select items with the same weights.
+///
+/// [`WeightedSampler`]: struct.WeightedSampler.html
+pub trait ChooseWeighted: WithEdge {
+    /// Returns a random vertex of this graph. The probability of a vertex being selected is
+    /// proportional to its weight.
+    ///
+    /// # Panics
+    ///
+    /// If a weight is negative or not finite or if all weights are zero.
+    fn choose_vertex_weighted<R, P>(&self, rng: R, weights: P) -> Vertex<Self>
+    where
+        Self: VertexList,
+        R: Rng,
+        P: VertexPropGet<Self, f64>,
+    {
+        self.vertex_sampler(weights).sample(rng)
+    }
+
+    /// Returns a random edge of this graph. The probability of an edge being selected is
+    /// proportional to its weight.
+    ///
+    /// # Panics
+    ///
+    /// If a weight is negative or not finite or if all weights are zero.
+    fn choose_edge_weighted<R, P>(&self, rng: R, weights: P) -> Edge<Self>
+    where
+        Self: EdgeList,
+        R: Rng,
+        P: EdgePropGet<Self, f64>,
+    {
+        self.edge_sampler(weights).sample(rng)
+    }
+
+    /// Creates a [`WeightedSampler`] for the vertices of this graph.
+    ///
+    /// [`WeightedSampler`]: struct.WeightedSampler.html
+    fn vertex_sampler<P>(&self, weights: P) -> WeightedSampler<Vertex<Self>>
+    where
+        Self: VertexList,
+        P: VertexPropGet<Self, f64>,
+    {
+        WeightedSampler::new(self.vertices(), weights)
+    }
+
+    /// Creates a [`WeightedSampler`] for the edges of this graph.
+    ///
+    /// [`WeightedSampler`]: struct.WeightedSampler.html
+    fn edge_sampler<P>(&self, weights: P) -> WeightedSampler<Edge<Self>>
+    where
+        Self: EdgeList,
+        P: EdgePropGet<Self, f64>,
+    {
+        WeightedSampler::new(self.edges(), weights)
+    }
+}
+
+impl<G: WithEdge> ChooseWeighted for G {}
+
+/// Randomly selects items with probability proportional to their weights.
+///
+/// The cumulative sum of the weights is computed once in O(n) time, and each selection takes
+/// O(log n) time. Items with weight zero are never selected.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rand;
+/// extern crate fera_graph;
+///
+/// use fera_graph::prelude::*;
+/// use fera_graph::choose::ChooseWeighted;
+/// use rand::prelude::*;
+///
+/// # fn main() {
+/// let g = CompleteGraph::new(4);
+/// let mut w = g.default_vertex_prop(1.0);
+/// w[2] = 0.0;
+/// let sampler = g.vertex_sampler(&w);
+/// let mut rng = SmallRng::from_entropy();
+/// assert!((0..100).all(|_| sampler.sample(&mut rng) != 2));
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WeightedSampler<T> {
+    items: Vec<T>,
+    cum: Vec<f64>,
+    // the index of the last item with positive weight
+    last: usize,
+}
+
+impl<T: Copy> WeightedSampler<T> {
+    /// Creates a new sampler for `items` using `weights` to get the weight of each item.
+    ///
+    /// # Panics
+    ///
+    /// If a weight is negative or not finite or if all weights are zero (which includes the case
+    /// where `items` is empty).
+    pub fn new<I, P>(items: I, weights: P) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoOwned<T>,
+        P: PropGet<T, Output = f64>,
+    {
+        let mut total = 0.0;
+        let mut cum = vec![];
+        let mut vec = vec![];
+        let mut last = 0;
+        for item in items {
+            let item = item.into_owned();
+            let w = weights.get(item);
+            assert!(
+                w >= 0.0 && w.is_finite(),
+                "invalid weight: {} (weights must be finite and non negative)",
+                w
+            );
+            if w > 0.0 {
+                last = vec.len();
+            }
+            total += w;
+            cum.push(total);
+            vec.push(item);
+        }
+        assert!(total > 0.0, "cannot sample: all weights are zero");
+        WeightedSampler {
+            items: vec,
+            cum,
+            last,
+        }
+    }
+
+    /// Returns a random item.
+    pub fn sample<R: Rng>(&self, mut rng: R) -> T {
+        let total = *self.cum.last().unwrap();
+        let x = rng.gen::<f64>() * total;
+        // The first position with cumulative weight greater than x. Items with weight zero have the
+        // same cumulative weight as the previous item, so they are never selected.
+        let i = self
+            .cum
+            .binary_search_by(|c| c.partial_cmp(&x).unwrap().then(Ordering::Less))
+            .unwrap_err();
+        // x < total, but rounding can make i == len, in which case the last item with positive
+        // weight is selected
+        self.items[i.min(self.last)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use props::FnProp;
+    use rand::prelude::*;
+    use rand::rngs::mock::StepRng;
+
+    fn chi_square(counts: &[usize], expected: &[f64]) -> f64 {
+        counts
+            .iter()
+            .zip(expected)
+            .filter(|&(_, &e)| e > 0.0)
+            .map(|(&c, &e)| (c as f64 - e).powi(2) / e)
+            .sum()
+    }
+
+    #[test]
+    fn choose_vertex_weighted() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let g = CompleteGraph::new(5);
+        let w = FnProp(|v: u32| f64::from(v));
+        let n = 10_000;
+        let mut counts = vec![0; 5];
+        for _ in 0..n {
+            counts[g.choose_vertex_weighted(&mut rng, w) as usize] += 1;
+        }
+        assert_eq!(0, counts[0]);
+        let expected: Vec<f64> = (0..5).map(|v| n as f64 * f64::from(v) / 10.0).collect();
+        // 4 degrees of freedom, P(X > 23.5) < 0.0001
+        assert!(chi_square(&counts, &expected) < 23.5);
+    }
+
+    #[test]
+    fn edge_sampler() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let g = StaticGraph::new_complete(4);
+        let mut w = g.default_edge_prop(0.0);
+        let edges: Vec<_> = g.edges().collect();
+        w[edges[1]] = 1.0;
+        w[edges[3]] = 3.0;
+        let sampler = g.edge_sampler(&w);
+        let n = 10_000;
+        let mut counts = vec![0; edges.len()];
+        for _ in 0..n {
+            let e = sampler.sample(&mut rng);
+            counts[edges.iter().position(|&f| f == e).unwrap()] += 1;
+        }
+        assert_eq!(n, counts[1] + counts[3]);
+        let expected = vec![0.0, n as f64 / 4.0, 0.0, 3.0 * n as f64 / 4.0, 0.0, 0.0];
+        // 1 degree of freedom, P(X > 15.1) < 0.0001
+        assert!(chi_square(&counts, &expected) < 15.1);
+    }
+
+    #[test]
+    fn random_vertex_walk() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        // a path with 4 vertices and an isolated vertex
+        let g: StaticGraph = graph!(5, (0, 1), (1, 2), (2, 3));
+        let walk: Vec<_> = g.random_vertex_walk(1, 0.5, &mut rng).take(1000).collect();
+        assert_eq!(1000, walk.len());
+        assert_eq!(1, walk[0]);
+        assert!(walk.iter().all(|&v| v < 4));
+        assert!(walk.windows(2).all(|w| w[0].max(w[1]) - w[0].min(w[1]) <= 1));
+        // with probability 1/2 a step stays at the same vertex
+        let stays = walk.windows(2).filter(|w| w[0] == w[1]).count();
+        assert!(stays > 400 && stays < 600);
+
+        let walk: Vec<_> = g.random_vertex_walk(0, 0.0, &mut rng).take(1000).collect();
+        assert!(walk.windows(2).all(|w| w[0] != w[1]));
+
+        assert_eq!(vec![4], g.random_vertex_walk(4, 0.5, &mut rng).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn random_vertex_walk_dead_end() {
+        let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (1, 3), (3, 1));
+        let mut rng = SmallRng::seed_from_u64(11);
+        for _ in 0..20 {
+            let walk: Vec<_> = g.random_vertex_walk(0, 0.3, &mut rng).collect();
+            assert_eq!(Some(&2), walk.last());
+            assert_eq!(1, walk.iter().filter(|&&v| v == 2).count());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid lazy probability")]
+    fn random_vertex_walk_invalid_lazy() {
+        let g = CompleteGraph::new(3);
+        g.random_vertex_walk(0, 1.0, SmallRng::from_entropy());
+    }
+
+    #[test]
+    fn trailing_zero_weights() {
+        // the largest value generated by gen::<f64>()
+        let mut rng = StepRng::new(u64::MAX, 0);
+        let g = CompleteGraph::new(5);
+        let sampler = g.vertex_sampler(FnProp(|v| if v < 2 { 1.0 } else { 0.0 }));
+        assert_eq!(1, sampler.sample(&mut rng));
+        assert_eq!(1, sampler.last);
+    }
+
+    #[test]
+    #[should_panic(expected = "all weights are zero")]
+    fn all_zero_weights() {
+        let g = CompleteGraph::new(3);
+        g.choose_vertex_weighted(SmallRng::from_entropy(), FnProp(|_| 0.0));
+    }
+}
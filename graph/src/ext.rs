@@ -45,6 +45,9 @@
 use params::IntoOwned;
 use prelude::*;
 
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
 /// Extension trait for slices.
 ///
 /// See the [module documentation] for examples.
@@ -62,6 +65,7 @@ pub trait GraphsSliceExt<T> {
 
     /// Sort a slice using a [property].
     ///
+    /// This sort is stable, that is, items with equal property values keep their relative order.
     /// This functions calls [`slice::sort_by_key`].
     ///
     /// [property]: ../props/index.html
@@ -86,6 +90,31 @@ pub trait GraphsSliceExt<T> {
         for<'a> &'a T: IntoOwned<K>,
         P::Output: Ord;
 
+    /// Sort a slice in descending order using a [property].
+    ///
+    /// This sort is stable, that is, items with equal property values keep their relative order.
+    ///
+    /// [property]: ../props/index.html
+    fn sort_by_prop_desc<P, K>(&mut self, prop: P)
+    where
+        P: PropGet<K>,
+        for<'a> &'a T: IntoOwned<K>,
+        P::Output: Ord;
+
+    /// Returns the `k` items of the slice with the smallest [property] values, in ascending order.
+    /// Items with equal property values are returned in the order they appear in the slice.
+    ///
+    /// This uses a bounded heap and takes `O(n log k)` time, which is faster than sorting the
+    /// slice if `k` is small.
+    ///
+    /// [property]: ../props/index.html
+    fn k_smallest_by_prop<P, K>(&self, k: usize, prop: P) -> Vec<T>
+    where
+        P: PropGet<K>,
+        for<'a> &'a T: IntoOwned<K>,
+        P::Output: Ord,
+        T: Clone;
+
     /// Binary searches a slice that is sorted by a [property].
     ///
     /// This functions calls [`slice::binary_search_by_key`].
@@ -141,6 +170,42 @@ impl<T> GraphsSliceExt<T> for [T] {
         self.sort_unstable_by_key(|item| prop.get(item.into_owned()))
     }
 
+    #[inline]
+    fn sort_by_prop_desc<P, K>(&mut self, prop: P)
+    where
+        P: PropGet<K>,
+        for<'a> &'a T: IntoOwned<K>,
+        P::Output: Ord,
+    {
+        self.sort_by_key(|item| Reverse(prop.get(item.into_owned())))
+    }
+
+    fn k_smallest_by_prop<P, K>(&self, k: usize, prop: P) -> Vec<T>
+    where
+        P: PropGet<K>,
+        for<'a> &'a T: IntoOwned<K>,
+        P::Output: Ord,
+        T: Clone,
+    {
+        if k == 0 {
+            return vec![];
+        }
+        // max heap with the k smallest (value, position) pairs seen so far
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        for (i, item) in self.iter().enumerate() {
+            let key = (prop.get(item.into_owned()), i);
+            if heap.len() < k {
+                heap.push(key);
+            } else if key < *heap.peek().unwrap() {
+                *heap.peek_mut().unwrap() = key;
+            }
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|(_, i)| self[i].clone())
+            .collect()
+    }
+
     #[inline]
     fn binary_search_by_prop<P, K>(&self, prop_value: &P::Output, prop: P) -> Result<usize, usize>
     where
@@ -206,3 +271,48 @@ impl<T> GraphsVecExt<T> for Vec<T> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::vec;
+
+    // vertices 0..8 with a property with many duplicate values
+    fn new() -> (Vec<u32>, DefaultVertexPropMut<CompleteGraph, u32>) {
+        let g = CompleteGraph::new(8);
+        let mut w = g.default_vertex_prop(0);
+        for (v, &x) in g.vertices().zip(&[2, 1, 2, 0, 1, 2, 0, 1]) {
+            w[v] = x;
+        }
+        (vec(g.vertices()), w)
+    }
+
+    #[test]
+    fn sort_by_prop_is_stable() {
+        let (mut v, w) = new();
+        v.sort_by_prop(&w);
+        assert_eq!(vec![3, 6, 1, 4, 7, 0, 2, 5], v);
+
+        let (mut v, w) = new();
+        v.reverse();
+        v.sort_by_prop(&w);
+        assert_eq!(vec![6, 3, 7, 4, 1, 5, 2, 0], v);
+    }
+
+    #[test]
+    fn sort_by_prop_desc() {
+        let (mut v, w) = new();
+        v.sort_by_prop_desc(&w);
+        assert_eq!(vec![0, 2, 5, 1, 4, 7, 3, 6], v);
+    }
+
+    #[test]
+    fn k_smallest_by_prop() {
+        let (v, w) = new();
+        let sorted = v.clone().sorted_by_prop(&w);
+        for k in 0..10 {
+            let n = k.min(v.len());
+            assert_eq!(&sorted[..n], &v.k_smallest_by_prop(k, &w)[..]);
+        }
+    }
+}
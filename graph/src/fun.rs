@@ -6,6 +6,7 @@
 
 use params::IntoOwned;
 use prelude::*;
+use std::cmp::Ordering;
 use std::iter::Sum;
 
 /// Returns the iterator's item with minimum property value or `None` if the iterator is empty.
@@ -74,6 +75,76 @@ where
         .max_by_key(move |&v| prop.get(v.into_owned()))
 }
 
+/// Returns the iterator's item with minimum property value with respect to `compare` or `None` if
+/// the iterator is empty.
+///
+/// This is like [`min_by_prop`], but can be used with property values that are not `Ord`, like
+/// `f64`. If several items have equally minimum property value, the first one is returned.
+///
+/// # Example
+///
+/// ```
+/// use fera_graph::prelude::*;
+/// use fera_graph::min_by_prop_cmp;
+///
+/// let g = CompleteGraph::new(3);
+/// let mut w = g.default_edge_prop(0.0);
+/// for (e, x) in g.edges().zip(&[2.5, 0.5, 1.5]) {
+///     w[e] = *x;
+/// }
+/// let e = min_by_prop_cmp(&w, g.edges(), |a, b| a.partial_cmp(b).unwrap()).unwrap();
+/// assert_eq!(0.5, w[e]);
+/// assert_eq!(None, min_by_prop_cmp(&w, g.edges().take(0), |a, b| a.partial_cmp(b).unwrap()));
+/// ```
+///
+/// [`min_by_prop`]: fn.min_by_prop.html
+#[inline]
+pub fn min_by_prop_cmp<I, P, K, F>(prop: P, iter: I, mut compare: F) -> Option<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Copy + IntoOwned<K>,
+    P: PropGet<K>,
+    F: FnMut(&P::Output, &P::Output) -> Ordering,
+{
+    iter.into_iter()
+        .min_by(move |&a, &b| compare(&prop.get(a.into_owned()), &prop.get(b.into_owned())))
+}
+
+/// Returns the iterator's item with maximum property value with respect to `compare` or `None` if
+/// the iterator is empty.
+///
+/// This is like [`max_by_prop`], but can be used with property values that are not `Ord`, like
+/// `f64`. If several items have equally maximum property value, the last one is returned.
+///
+/// # Example
+///
+/// ```
+/// use fera_graph::prelude::*;
+/// use fera_graph::max_by_prop_cmp;
+///
+/// let g = CompleteGraph::new(3);
+/// let mut w = g.default_edge_prop(0.0);
+/// for (e, x) in g.edges().zip(&[2.5, 0.5, 1.5]) {
+///     w[e] = *x;
+/// }
+/// let e = max_by_prop_cmp(&w, g.edges(), |a, b| a.partial_cmp(b).unwrap()).unwrap();
+/// assert_eq!(2.5, w[e]);
+/// assert_eq!(None, max_by_prop_cmp(&w, g.edges().take(0), |a, b| a.partial_cmp(b).unwrap()));
+/// ```
+///
+/// [`max_by_prop`]: fn.max_by_prop.html
+#[inline]
+pub fn max_by_prop_cmp<I, P, K, F>(prop: P, iter: I, mut compare: F) -> Option<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Copy + IntoOwned<K>,
+    P: PropGet<K>,
+    F: FnMut(&P::Output, &P::Output) -> Ordering,
+{
+    iter.into_iter()
+        .max_by(move |&a, &b| compare(&prop.get(a.into_owned()), &prop.get(b.into_owned())))
+}
+
 /// Returns the minimum property value associated with the iterator's items or `None` if the
 /// iterator is empty.
 ///
@@ -165,3 +236,29 @@ where
         .map(move |v| prop.get(v.into_owned()))
         .sum()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use props::FnProp;
+
+    #[test]
+    fn heaviest_edge() {
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3));
+        let mut w = g.default_edge_prop(0u32);
+        let e = g.edge_by_ends(1, 2);
+        w[e] = 10;
+        assert_eq!(Some(e), max_by_prop(&w, g.edges()));
+        assert_eq!(Some(10), max_prop(&w, g.edges()));
+    }
+
+    #[test]
+    fn highest_degree_vertex() {
+        let g: StaticGraph = graph!(5, (0, 1), (1, 2), (1, 3), (3, 4));
+        let degree = FnProp(|v| g.out_degree(v));
+        assert_eq!(Some(1), max_by_prop(degree, g.vertices()));
+        assert_eq!(Some(0), min_by_prop(degree, g.vertices()));
+        assert_eq!(Some(1), max_by_prop_cmp(degree, g.vertices(), |a, b| a.cmp(b)));
+        assert_eq!(Some(0), min_by_prop_cmp(degree, g.vertices(), |a, b| a.cmp(b)));
+    }
+}
@@ -0,0 +1,378 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use graphs::OutNeighborFromOutEdge;
+use prelude::*;
+use props::{DelegateEdgeProp, DelegateVertexProp, FnProp};
+
+/// A view of a graph that hides the vertices and edges rejected by a predicate.
+///
+/// Unlike [`Subgraph`], no vertex or edge list is created, the wrapped graph items are filtered
+/// while they are iterated. An edge is visible only if the edge and both of its ends are accepted
+/// by the predicates.
+///
+/// The predicates are copied to each iterator, so they are usually references to properties (like
+/// `&DefaultVertexPropMut<G, bool>`) or [`FnProp`]s.
+///
+/// # Examples
+///
+/// ```
+/// use fera_graph::prelude::*;
+/// use fera_graph::props::FnProp;
+///
+/// let g = CompleteGraph::new(5);
+/// let f = g.filter_vertices(FnProp(|v| v != 2));
+/// assert_eq!(vec![0, 1, 3, 4], f.vertices().collect::<Vec<_>>());
+/// assert_eq!(6, f.num_edges());
+/// assert_eq!(3, f.out_degree(0));
+/// ```
+///
+/// [`Subgraph`]: struct.Subgraph.html
+/// [`FnProp`]: ../../props/struct.FnProp.html
+pub struct FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + WithEdge,
+{
+    g: &'a G,
+    vp: VP,
+    ep: EP,
+}
+
+/// A predicate that accepts all vertices or all edges.
+pub type AcceptAll<K> = FnProp<fn(K) -> bool>;
+
+fn accept_all<K>(_: K) -> bool {
+    true
+}
+
+impl<'a, G, VP, EP> FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + WithEdge,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+    /// Creates a new view of `g` with the vertices accepted by `vp` and the edges accepted by
+    /// `ep`.
+    pub fn new(g: &'a G, vp: VP, ep: EP) -> Self {
+        FilteredGraph { g, vp, ep }
+    }
+
+    #[inline]
+    fn filter_iter<I>(&self, iter: I) -> FilterEdgeIter<'a, G, VP, EP, I> {
+        FilterEdgeIter {
+            g: self.g,
+            vp: self.vp,
+            ep: self.ep,
+            iter,
+        }
+    }
+}
+
+// Iterators
+
+/// An iterator over the vertices accepted by a predicate.
+pub struct FilterVertexIter<VP, I> {
+    vp: VP,
+    iter: I,
+}
+
+impl<VP, I> Iterator for FilterVertexIter<VP, I>
+where
+    I: Iterator,
+    VP: PropGet<I::Item, Output = bool>,
+    I::Item: Copy,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let vp = &self.vp;
+        self.iter.find(|&v| vp.get(v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator over the edges accepted by a predicate and whose ends are accepted by another
+/// predicate.
+pub struct FilterEdgeIter<'a, G: 'a, VP, EP, I> {
+    g: &'a G,
+    vp: VP,
+    ep: EP,
+    iter: I,
+}
+
+impl<'a, G, VP, EP, I> Iterator for FilterEdgeIter<'a, G, VP, EP, I>
+where
+    G: 'a + WithEdge,
+    VP: VertexPropGet<G, bool>,
+    EP: EdgePropGet<G, bool>,
+    I: Iterator<Item = Edge<G>>,
+{
+    type Item = Edge<G>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (g, vp, ep) = (self.g, &self.vp, &self.ep);
+        self.iter
+            .find(|&e| ep.get(e) && vp.get(g.source(e)) && vp.get(g.target(e)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+// Traits implementations
+
+impl<'a, G, VP, EP> AsRef<G> for FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + WithEdge,
+{
+    #[inline]
+    fn as_ref(&self) -> &G {
+        self.g
+    }
+}
+
+impl<'a, 'b, G, VP, EP> VertexTypes<'a, FilteredGraph<'b, G, VP, EP>>
+    for FilteredGraph<'b, G, VP, EP>
+where
+    G: 'b + Incidence + VertexList + EdgeList,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+    type VertexIter = FilterVertexIter<VP, VertexIter<'b, G>>;
+    type OutNeighborIter = OutNeighborFromOutEdge<'b, G, OutEdgeIter<'a, Self>>;
+}
+
+impl<'a, G, VP, EP> WithVertex for FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + Incidence + VertexList + EdgeList,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+    type Vertex = Vertex<G>;
+    type OptionVertex = OptionVertex<G>;
+}
+
+impl<'a, 'b, G, VP, EP> EdgeTypes<'a, FilteredGraph<'b, G, VP, EP>>
+    for FilteredGraph<'b, G, VP, EP>
+where
+    G: 'b + Incidence + VertexList + EdgeList,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+    type EdgeIter = FilterEdgeIter<'b, G, VP, EP, EdgeIter<'b, G>>;
+    type OutEdgeIter = FilterEdgeIter<'b, G, VP, EP, OutEdgeIter<'b, G>>;
+}
+
+impl<'a, G, VP, EP> WithEdge for FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + Incidence + VertexList + EdgeList,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+    type Kind = G::Kind;
+    type Edge = Edge<G>;
+    type OptionEdge = OptionEdge<G>;
+
+    fn source(&self, e: Edge<Self>) -> Vertex<Self> {
+        self.g.source(e)
+    }
+
+    fn target(&self, e: Edge<Self>) -> Vertex<Self> {
+        self.g.target(e)
+    }
+
+    fn orientation(&self, e: Edge<Self>) -> Orientation {
+        self.g.orientation(e)
+    }
+
+    fn end_vertices(&self, e: Edge<Self>) -> (Vertex<Self>, Vertex<Self>) {
+        self.g.end_vertices(e)
+    }
+
+    fn opposite(&self, u: Vertex<Self>, e: Edge<Self>) -> Vertex<Self> {
+        self.g.opposite(u, e)
+    }
+
+    fn get_reverse(&self, e: Edge<Self>) -> Option<Edge<Self>> {
+        self.g.get_reverse(e)
+    }
+}
+
+impl<'a, G, VP, EP> VertexList for FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + Incidence + VertexList + EdgeList,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+    fn vertices(&self) -> VertexIter<Self> {
+        FilterVertexIter {
+            vp: self.vp,
+            iter: self.g.vertices(),
+        }
+    }
+}
+
+impl<'a, G, VP, EP> EdgeList for FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + Incidence + VertexList + EdgeList,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+    fn edges(&self) -> EdgeIter<Self> {
+        self.filter_iter(self.g.edges())
+    }
+
+    fn get_edge_by_ends(&self, u: Vertex<Self>, v: Vertex<Self>) -> Option<Edge<Self>> {
+        self.out_edges(u).find(|e| (u, v) == self.ends(*e))
+    }
+}
+
+impl<'a, G, VP, EP> Adjacency for FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + Incidence + VertexList + EdgeList,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+    fn out_neighbors(&self, v: Vertex<Self>) -> OutNeighborIter<Self> {
+        OutNeighborFromOutEdge::new(self.g, self.out_edges(v))
+    }
+}
+
+impl<'a, G, VP, EP> Incidence for FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + Incidence + VertexList + EdgeList,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+    fn out_edges(&self, v: Vertex<Self>) -> OutEdgeIter<Self> {
+        self.filter_iter(self.g.out_edges(v))
+    }
+}
+
+impl<'a, G, VP, EP, T> WithVertexProp<T> for FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + Incidence + VertexList + EdgeList + WithVertexProp<T>,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+    type VertexProp = DelegateVertexProp<G, T>;
+}
+
+impl<'a, G, VP, EP, T> WithEdgeProp<T> for FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + Incidence + VertexList + EdgeList + WithEdgeProp<T>,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+    type EdgeProp = DelegateEdgeProp<G, T>;
+}
+
+impl<'a, G, VP, EP> BasicVertexProps for FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + Incidence + VertexList + EdgeList + BasicProps,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+}
+
+impl<'a, G, VP, EP> BasicEdgeProps for FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + Incidence + VertexList + EdgeList + BasicProps,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+}
+
+impl<'a, G, VP, EP> BasicProps for FilteredGraph<'a, G, VP, EP>
+where
+    G: 'a + Incidence + VertexList + EdgeList + BasicProps,
+    VP: VertexPropGet<G, bool> + Copy,
+    EP: EdgePropGet<G, bool> + Copy,
+{
+}
+
+// Extensions Traits
+
+pub trait WithFilter: WithEdge {
+    /// Creates a view of this graph with the vertices accepted by `vp` and the edges accepted by
+    /// `ep`.
+    fn filter<VP, EP>(&self, vp: VP, ep: EP) -> FilteredGraph<Self, VP, EP>
+    where
+        VP: VertexPropGet<Self, bool> + Copy,
+        EP: EdgePropGet<Self, bool> + Copy,
+    {
+        FilteredGraph::new(self, vp, ep)
+    }
+
+    /// Creates a view of this graph with the vertices accepted by `vp`. This is the lazy
+    /// equivalent of `induced_subgraph`.
+    fn filter_vertices<VP>(&self, vp: VP) -> FilteredGraph<Self, VP, AcceptAll<Edge<Self>>>
+    where
+        VP: VertexPropGet<Self, bool> + Copy,
+    {
+        FilteredGraph::new(self, vp, FnProp(accept_all))
+    }
+
+    /// Creates a view of this graph with all vertices and the edges accepted by `ep`. This is the
+    /// lazy equivalent of `spanning_subgraph`.
+    fn filter_edges<EP>(&self, ep: EP) -> FilteredGraph<Self, AcceptAll<Vertex<Self>>, EP>
+    where
+        EP: EdgePropGet<Self, bool> + Copy,
+    {
+        FilteredGraph::new(self, FnProp(accept_all), ep)
+    }
+}
+
+impl<G: WithEdge> WithFilter for G {}
+
+#[cfg(test)]
+mod tests {
+    use fera_fun::{set, vec};
+    use prelude::*;
+    use props::FnProp;
+    use rand::prelude::*;
+
+    #[test]
+    fn test_filter_vertices() {
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..20 {
+            let g = StaticGraph::new_gnm(n, n * (n - 1) / 4, &mut rng).unwrap();
+            let vin: DefaultVertexPropMut<StaticGraph, bool> =
+                g.vertex_prop_from_fn(|_| rng.gen());
+            let vertices = vec(g.vertices().filter(|&v| vin[v]));
+            let s = g.induced_subgraph(&vertices);
+            let f = g.filter_vertices(&vin);
+            assert_eq!(set(s.vertices()), set(f.vertices()));
+            assert_eq!(s.num_vertices(), f.num_vertices());
+            assert_eq!(set(s.edges()), set(f.edges()));
+            assert_eq!(s.num_edges(), f.num_edges());
+            for &v in &vertices {
+                assert_eq!(set(s.out_edges(v)), set(f.out_edges(v)));
+                assert_eq!(set(s.out_neighbors(v)), set(f.out_neighbors(v)));
+                assert_eq!(s.out_degree(v), f.out_degree(v));
+            }
+        }
+    }
+
+    #[test]
+    fn test_filter_edges() {
+        let g: StaticGraph = graph!(5, (0, 1), (0, 2), (1, 2), (3, 4));
+        let e = vec(g.edges());
+        let e02 = e[1];
+        let f = g.filter_edges(FnProp(|e| e != e02));
+        assert_eq!(vec![0, 1, 2, 3, 4], vec(f.vertices()));
+        assert_eq!(vec![e[0], e[2], e[3]], vec(f.edges()));
+        assert_eq!(vec![e[0]], vec(f.out_edges(0)));
+        assert_eq!(vec![0, 2], vec(f.out_neighbors(1)));
+        assert_eq!(None, f.get_edge_by_ends(2, 0));
+    }
+}
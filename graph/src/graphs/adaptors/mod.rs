@@ -4,11 +4,12 @@
 
 //! Create adapted graphs without modifying the wrapped graph.
 
+mod filter;
+mod reverse;
 mod spanning_subgraph;
 mod subgraph;
 
+pub use self::filter::{AcceptAll, FilterEdgeIter, FilterVertexIter, FilteredGraph, WithFilter};
+pub use self::reverse::{ReverseGraph, ReverseOutNeighborIter, WithReverse};
 pub use self::spanning_subgraph::SpanningSubgraph;
 pub use self::subgraph::{Subgraph, WithSubgraph};
-
-// TODO: add Reversed
-// TODO: add Filtered
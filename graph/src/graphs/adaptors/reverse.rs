@@ -0,0 +1,319 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use prelude::*;
+use props::{DelegateEdgeProp, DelegateVertexProp};
+
+/// A view of a digraph with all edges reversed (also known as the transpose of the digraph).
+///
+/// The vertices and edges of the view are the same as the wrapped digraph, so properties created
+/// for the wrapped digraph can be used with the view. The source of an edge in the view is the
+/// target of the edge in the wrapped digraph and vice versa, and the out edges of a vertex in the
+/// view are the in edges of the vertex in the wrapped digraph (see [`InIncidence`]).
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+///
+/// # fn main() {
+/// let g: StaticDigraph = graph!(3, (0, 1), (0, 2), (1, 2));
+/// let r = g.reverse_view();
+/// let e = g.get_edge_by_ends(0, 1).unwrap();
+/// assert_eq!((1, 0), r.end_vertices(e));
+/// assert_eq!(vec![0, 1], r.out_neighbors(2).collect::<Vec<_>>());
+/// assert_eq!(Some(e), r.get_edge_by_ends(1, 0));
+/// # }
+/// ```
+///
+/// [`InIncidence`]: ../trait.InIncidence.html
+pub struct ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence,
+{
+    g: &'a G,
+}
+
+impl<'a, G> ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence,
+{
+    /// Creates a new reversed view of `g`. No data is copied, so this takes constant time.
+    pub fn new(g: &'a G) -> Self {
+        ReverseGraph { g }
+    }
+}
+
+/// An iterator over the out neighbors of a vertex in a `ReverseGraph`.
+pub struct ReverseOutNeighborIter<'a, G: 'a, I> {
+    g: &'a G,
+    iter: I,
+}
+
+impl<'a, G, I> Iterator for ReverseOutNeighborIter<'a, G, I>
+where
+    G: 'a + WithEdge,
+    I: Iterator<Item = Edge<G>>,
+{
+    type Item = Vertex<G>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|e| self.g.source(e))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, G, I> ExactSizeIterator for ReverseOutNeighborIter<'a, G, I>
+where
+    G: 'a + WithEdge,
+    I: Iterator<Item = Edge<G>> + ExactSizeIterator,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+// Traits implementations
+
+impl<'a, G> AsRef<G> for ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence,
+{
+    #[inline]
+    fn as_ref(&self) -> &G {
+        self.g
+    }
+}
+
+impl<'a, 'b, G> VertexTypes<'a, ReverseGraph<'b, G>> for ReverseGraph<'b, G>
+where
+    G: 'b + Digraph + InIncidence,
+{
+    type VertexIter = VertexIter<'b, G>;
+    type OutNeighborIter = ReverseOutNeighborIter<'b, G, OutEdgeIter<'a, Self>>;
+}
+
+impl<'a, G> WithVertex for ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence,
+{
+    type Vertex = Vertex<G>;
+    type OptionVertex = OptionVertex<G>;
+}
+
+impl<'a, 'b, G> EdgeTypes<'a, ReverseGraph<'b, G>> for ReverseGraph<'b, G>
+where
+    G: 'b + Digraph + InIncidence,
+{
+    type EdgeIter = EdgeIter<'b, G>;
+    type OutEdgeIter = InEdgeIter<'b, G>;
+}
+
+impl<'a, G> WithEdge for ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence,
+{
+    type Kind = G::Kind;
+    type Edge = Edge<G>;
+    type OptionEdge = OptionEdge<G>;
+
+    fn source(&self, e: Edge<Self>) -> Vertex<Self> {
+        self.g.target(e)
+    }
+
+    fn target(&self, e: Edge<Self>) -> Vertex<Self> {
+        self.g.source(e)
+    }
+
+    fn orientation(&self, e: Edge<Self>) -> Orientation {
+        self.g.orientation(e)
+    }
+
+    fn end_vertices(&self, e: Edge<Self>) -> (Vertex<Self>, Vertex<Self>) {
+        let (u, v) = self.g.end_vertices(e);
+        (v, u)
+    }
+
+    fn opposite(&self, u: Vertex<Self>, e: Edge<Self>) -> Vertex<Self> {
+        self.g.opposite(u, e)
+    }
+
+    fn get_reverse(&self, e: Edge<Self>) -> Option<Edge<Self>> {
+        self.g.get_reverse(e)
+    }
+}
+
+impl<'a, G> VertexList for ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence,
+{
+    fn num_vertices(&self) -> usize {
+        self.g.num_vertices()
+    }
+
+    fn vertices(&self) -> VertexIter<Self> {
+        self.g.vertices()
+    }
+}
+
+impl<'a, G> EdgeList for ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence,
+{
+    fn num_edges(&self) -> usize {
+        self.g.num_edges()
+    }
+
+    fn edges(&self) -> EdgeIter<Self> {
+        self.g.edges()
+    }
+
+    fn get_edge_by_ends(&self, u: Vertex<Self>, v: Vertex<Self>) -> Option<Edge<Self>> {
+        self.g.get_edge_by_ends(v, u)
+    }
+}
+
+impl<'a, G> Adjacency for ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence,
+{
+    fn out_neighbors(&self, v: Vertex<Self>) -> OutNeighborIter<Self> {
+        ReverseOutNeighborIter {
+            g: self.g,
+            iter: self.out_edges(v),
+        }
+    }
+
+    fn out_degree(&self, v: Vertex<Self>) -> usize {
+        self.g.in_degree(v)
+    }
+}
+
+impl<'a, G> Incidence for ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence,
+{
+    fn out_edges(&self, v: Vertex<Self>) -> OutEdgeIter<Self> {
+        self.g.in_edges(v)
+    }
+}
+
+impl<'a, 'b, G> InEdgeTypes<'a, ReverseGraph<'b, G>> for ReverseGraph<'b, G>
+where
+    G: 'b + Digraph + InIncidence,
+{
+    type InEdgeIter = OutEdgeIter<'b, G>;
+}
+
+impl<'a, G> InIncidence for ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence,
+{
+    fn in_edges(&self, v: Vertex<Self>) -> InEdgeIter<Self> {
+        self.g.out_edges(v)
+    }
+
+    fn in_degree(&self, v: Vertex<Self>) -> usize {
+        self.g.out_degree(v)
+    }
+}
+
+impl<'a, G, T> WithVertexProp<T> for ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence + WithVertexProp<T>,
+{
+    type VertexProp = DelegateVertexProp<G, T>;
+}
+
+impl<'a, G, T> WithEdgeProp<T> for ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence + WithEdgeProp<T>,
+{
+    type EdgeProp = DelegateEdgeProp<G, T>;
+}
+
+impl<'a, G> BasicVertexProps for ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence,
+{
+}
+
+impl<'a, G> BasicEdgeProps for ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence,
+{
+}
+
+impl<'a, G> BasicProps for ReverseGraph<'a, G>
+where
+    G: 'a + Digraph + InIncidence,
+{
+}
+
+// Extensions Traits
+
+pub trait WithReverse: Digraph + InIncidence {
+    /// Creates a view of this digraph with all edges reversed. See [`ReverseGraph`].
+    ///
+    /// [`ReverseGraph`]: struct.ReverseGraph.html
+    fn reverse_view(&self) -> ReverseGraph<Self> {
+        ReverseGraph::new(self)
+    }
+}
+
+impl<G: Digraph + InIncidence> WithReverse for G {}
+
+#[cfg(test)]
+mod tests {
+    use fera_fun::{set, vec};
+    use prelude::*;
+
+    fn new_digraph() -> StaticDigraph {
+        graph!(4, (0, 1), (1, 2), (2, 1), (2, 0), (3, 2), (3, 3))
+    }
+
+    #[test]
+    fn test_reverse_view() {
+        let g = new_digraph();
+        let r = g.reverse_view();
+        assert_eq!(vec(g.vertices()), vec(r.vertices()));
+        assert_eq!(vec(g.edges()), vec(r.edges()));
+        for (e, u, v) in g.edges_with_ends() {
+            assert_eq!((v, u), r.ends(e));
+            assert_eq!(Some(e), r.get_edge_by_ends(v, u));
+        }
+        assert_eq!(set(vec![2]), set(r.out_neighbors(0)));
+        assert_eq!(set(vec![0, 2]), set(r.out_neighbors(1)));
+        assert_eq!(set(vec![1, 3]), set(r.out_neighbors(2)));
+        assert_eq!(set(vec![3]), set(r.out_neighbors(3)));
+        for v in g.vertices() {
+            for e in r.out_edges(v) {
+                assert_eq!(v, g.target(e));
+            }
+        }
+    }
+
+    #[test]
+    fn test_reverse_view_reverse_view() {
+        let g = new_digraph();
+        let r = g.reverse_view();
+        let rr = r.reverse_view();
+        assert_eq!(vec(g.vertices()), vec(rr.vertices()));
+        assert_eq!(vec(g.edges_with_ends()), vec(rr.edges_with_ends()));
+        for v in g.vertices() {
+            assert_eq!(set(g.out_edges(v)), set(rr.out_edges(v)));
+            assert_eq!(set(g.out_neighbors(v)), set(rr.out_neighbors(v)));
+            assert_eq!(g.out_degree(v), rr.out_degree(v));
+            assert_eq!(set(g.in_edges(v)), set(rr.in_edges(v)));
+            assert_eq!(g.in_degree(v), r.out_degree(v));
+        }
+    }
+}
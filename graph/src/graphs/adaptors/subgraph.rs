@@ -15,7 +15,18 @@ use std::slice;
 
 use rand::Rng;
 
-// TODO: Allow a subgraph be reused
+/// A subgraph of a graph `G`, created by [`WithSubgraph::induced_subgraph`] and
+/// [`WithSubgraph::edge_induced_subgraph`].
+///
+/// The subgraph borrows the graph for `'a` and `G` does not need to be `'static`, so a subgraph
+/// can be created over a local graph or over another subgraph. Only the vertex and edge types of
+/// `G` must be `'static`, which is required by all graphs, because the iterator types are defined
+/// by [`VertexTypes`] and [`EdgeTypes`] for every lifetime.
+///
+/// [`WithSubgraph::induced_subgraph`]: trait.WithSubgraph.html#tymethod.induced_subgraph
+/// [`WithSubgraph::edge_induced_subgraph`]: trait.WithSubgraph.html#tymethod.edge_induced_subgraph
+/// [`VertexTypes`]: ../trait.VertexTypes.html
+/// [`EdgeTypes`]: ../trait.EdgeTypes.html
 // TODO: delegate all (possible) methods to g
 // TODO: remove Graph bound to allow directed graphs
 pub struct Subgraph<'a, G>
@@ -28,6 +39,49 @@ where
     inc: DefaultVertexPropMut<G, Vec<Edge<G>>>,
 }
 
+impl<'a, G> Subgraph<'a, G>
+where
+    G: 'a + Graph,
+{
+    /// Removes all vertices and edges from the subgraph.
+    ///
+    /// The memory used to store the incidence lists is kept, so the subgraph can be reused
+    /// without allocating a new vertex property.
+    pub fn clear(&mut self) {
+        for &v in &self.vertices {
+            self.inc[v].clear();
+        }
+        self.vertices.clear();
+        self.edges.clear();
+    }
+
+    /// Replaces the subgraph vertices and edges with the subgraph induced by `edges`.
+    ///
+    /// This is equivalent to `g.edge_induced_subgraph(edges)`, but reuses the memory already
+    /// allocated by this subgraph.
+    pub fn set_edges<I>(&mut self, edges: I)
+    where
+        I: IntoIterator,
+        I::Item: IntoOwned<Edge<G>>,
+    {
+        self.clear();
+        for e in edges {
+            let e = e.into_owned();
+            let (u, v) = self.g.ends(e);
+            // a vertex is in the subgraph iff it has at least one incident edge
+            if self.inc[u].is_empty() {
+                self.vertices.push(u);
+            }
+            self.inc[u].push(e);
+            if self.inc[v].is_empty() {
+                self.vertices.push(v);
+            }
+            self.inc[v].push(self.g.reverse(e));
+            self.edges.push(e);
+        }
+    }
+}
+
 // Traits implementations
 
 impl<'a, G> AsRef<G> for Subgraph<'a, G>
@@ -127,7 +181,7 @@ where
     }
 
     fn get_edge_by_ends(&self, u: Vertex<Self>, v: Vertex<Self>) -> Option<Edge<Self>> {
-        self.out_edges(u).find(|e| (u, v) == self.ends(*e))
+        self.out_edges(u).find(|&e| self.target(e) == v)
     }
 }
 
@@ -272,30 +326,14 @@ impl<G: Graph> WithSubgraph<G> for G {
         I: IntoIterator,
         I::Item: IntoOwned<Edge<G>>,
     {
-        // FIXME: should be O(edges), but is O(V) + O(edges)
-        let edges = vec(edges.into_iter().map(IntoOwned::into_owned));
-        let mut vin = self.default_vertex_prop(false);
-        let mut vertices = vec![];
-        let mut inc = self.default_vertex_prop(Vec::<Edge<G>>::new());
-        for (e, u, v) in self.with_ends(&edges) {
-            if !vin[u] {
-                vin[u] = true;
-                vertices.push(u);
-            }
-            if !vin[v] {
-                vin[v] = true;
-                vertices.push(v);
-            }
-            inc[u].push(e);
-            inc[v].push(self.reverse(e));
-        }
-
-        Subgraph {
+        let mut sub = Subgraph {
             g: self,
-            vertices: vertices,
-            edges: edges,
-            inc: inc,
-        }
+            vertices: vec![],
+            edges: vec![],
+            inc: self.default_vertex_prop(Vec::<Edge<G>>::new()),
+        };
+        sub.set_edges(edges);
+        sub
     }
 
     fn induced_subgraph<I>(&self, vertices: I) -> Subgraph<G>
@@ -304,18 +342,24 @@ impl<G: Graph> WithSubgraph<G> for G {
         I: IntoIterator,
         I::Item: IntoOwned<Vertex<G>>,
     {
+        // Only the out edges of the selected vertices are visited (instead of all edges of g).
+        // An edge is added to the subgraph when it is first found, with the orientation of the
+        // out edge of the selected vertex that comes first in vertices.
         let vertices = vec(vertices.into_iter().map(IntoOwned::into_owned));
-        let mut vs = self.default_vertex_prop(false);
+        let mut vin = self.default_vertex_prop(false);
+        let mut ein = self.default_edge_prop(false);
         let mut edges = vec![];
         let mut inc = self.default_vertex_prop(Vec::<Edge<G>>::new());
-        for &v in &vertices {
-            vs[v] = true;
-        }
-        for (e, u, v) in self.edges_with_ends() {
-            if vs[u] && vs[v] {
-                edges.push(e);
-                inc[u].push(e);
-                inc[v].push(self.reverse(e));
+        vin.set_values(&vertices, true);
+        for &u in &vertices {
+            for e in self.out_edges(u) {
+                if vin[self.target(e)] {
+                    if !ein[e] {
+                        ein[e] = true;
+                        edges.push(e);
+                    }
+                    inc[u].push(e);
+                }
             }
         }
 
@@ -328,8 +372,6 @@ impl<G: Graph> WithSubgraph<G> for G {
     }
 }
 
-// TODO: write benchs and optimize
-
 #[cfg(test)]
 mod tests {
     use fera_fun::{set, vec};
@@ -384,4 +426,57 @@ mod tests {
         assert_eq!(set(vec![e01, e12]), set(s.out_edges(1)));
         assert_eq!(set(vec![e02, e12]), set(s.out_edges(2)));
     }
+
+    #[test]
+    fn test_induced_subgraph_loops_and_parallel_edges() {
+        let g: StaticGraph = graph!(4, (0, 1), (1, 1), (2, 1), (1, 2), (3, 3), (0, 3), (2, 2));
+        let e = vec(g.edges());
+        let s = g.induced_subgraph(vec![2, 1, 3]);
+        assert_eq!(set(vec![e[1], e[2], e[3], e[4], e[6]]), set(s.edges()));
+        // the edges are oriented from the end that comes first in the vertices list
+        let ends = vec(s.edges().map(|e| s.end_vertices(e)));
+        assert_eq!(vec![(2, 1), (2, 1), (2, 2), (1, 1), (3, 3)], ends);
+        assert_eq!(4, s.out_degree(1));
+        assert_eq!(4, s.out_degree(2));
+        assert_eq!(2, s.out_degree(3));
+        assert_eq!(Some(e[1]), s.get_edge_by_ends(1, 1));
+        assert_eq!(None, s.get_edge_by_ends(0, 1));
+    }
+
+    #[test]
+    fn test_subgraph_of_non_static_graph() {
+        fn count_edges<G: IncidenceGraph>(g: &G) -> usize {
+            g.induced_subgraph(vec(g.vertices())).num_edges()
+        }
+
+        let (g, e01, e02, e12, _) = new_graph();
+        // s borrows g, so its type is not 'static
+        let s = g.induced_subgraph(vec![0, 1, 2]);
+        let t = s.induced_subgraph(vec![0, 1]);
+        assert_eq!(vec![0, 1], vec(t.vertices()));
+        assert_eq!(vec![e01], vec(t.edges()));
+        let t = s.edge_induced_subgraph(vec![e02, e12]);
+        assert_eq!(set(vec![0, 1, 2]), set(t.vertices()));
+        assert_eq!(3, count_edges(&s));
+        assert_eq!(2, count_edges(&t));
+    }
+
+    #[test]
+    fn test_set_edges() {
+        let (g, e01, e02, e12, e34) = new_graph();
+        let mut s = g.edge_induced_subgraph(vec![e01, e02]);
+        for edges in &[vec![e12, e34], vec![e01], vec![], vec![e01, e02, e12, e34]] {
+            s.set_edges(edges);
+            let exp = g.edge_induced_subgraph(edges);
+            assert_eq!(vec(exp.vertices()), vec(s.vertices()));
+            assert_eq!(vec(exp.edges()), vec(s.edges()));
+            for v in g.vertices() {
+                assert_eq!(vec(exp.out_edges(v)), vec(s.out_edges(v)));
+            }
+        }
+        s.clear();
+        assert_eq!(0, s.num_vertices());
+        assert_eq!(0, s.num_edges());
+        assert!(g.vertices().all(|v| s.out_degree(v) == 0));
+    }
 }
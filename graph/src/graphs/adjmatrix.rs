@@ -0,0 +1,504 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A graph backed by an adjacency matrix.
+//!
+//! # Examples
+//!
+//! ```
+//! #[macro_use] extern crate fera_graph;
+//! use fera_graph::prelude::*;
+//! use fera_graph::graphs::adjmatrix::AdjMatrixGraph;
+//!
+//! # fn main() {
+//! let g: AdjMatrixGraph = graph!(4, (0, 1), (1, 2), (2, 0));
+//! assert!(g.get_edge_by_ends(1, 0).is_some());
+//! assert!(g.get_edge_by_ends(0, 3).is_none());
+//! assert_eq!(vec![0, 2], g.out_neighbors(1).collect::<Vec<_>>());
+//!
+//! // converting from another graph
+//! let s: StaticGraph = graph!(3, (0, 2), (1, 2));
+//! let m = AdjMatrixGraph::from_graph(&s);
+//! assert_eq!(2, m.num_edges());
+//! assert!(m.get_edge_by_ends(2, 1).is_some());
+//! # }
+//! ```
+
+use builder::{Builder, WithBuilder};
+use graphs::adjset::{AdjSetEdge, DirectedEdge, UndirectedEdge};
+use graphs::static_::SVertexIndexProp;
+use prelude::*;
+use props::{HashMapProp, VecVertexProp};
+
+use fera_optional::OptionalMax;
+
+use std::iter::Enumerate;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::slice;
+
+pub type AdjMatrixGraph = AdjMatrix<Undirected>;
+
+pub type AdjMatrixDigraph = AdjMatrix<Directed>;
+
+/// The edge kinds supported by [`AdjMatrix`].
+///
+/// [`AdjMatrix`]: struct.AdjMatrix.html
+pub trait AdjMatrixEdgeKind: 'static + UniformEdgeKind {
+    type Edge: AdjSetEdge<u32>;
+}
+
+impl AdjMatrixEdgeKind for Directed {
+    type Edge = DirectedEdge<u32>;
+}
+
+impl AdjMatrixEdgeKind for Undirected {
+    type Edge = UndirectedEdge<u32>;
+}
+
+/// A graph backed by an adjacency matrix.
+///
+/// The matrix has one entry for each pair of vertices, so checking if there is an edge between two
+/// vertices (`get_edge_by_ends`) takes constant time, iterating over the out neighbors of a vertex
+/// takes `O(n)` time and iterating over the edges takes `O(n^2)` time. The matrix uses `n^2` bytes
+/// independently of the number of edges, so this type is only suitable for dense graphs (for sparse
+/// graphs [`StaticGraph`] uses much less memory).
+///
+/// Loops are supported, but parallel edges are not. The vertices are `0..n` and cannot be added or
+/// removed after the graph is created. Vertex properties are backed by a `Vec` and edge properties
+/// by a `HashMap`.
+///
+/// See the [module documentation] for an example.
+///
+/// [`StaticGraph`]: ../static_/type.StaticGraph.html
+/// [module documentation]: index.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdjMatrix<K: AdjMatrixEdgeKind> {
+    n: usize,
+    adj: Vec<bool>,
+    degree: Vec<usize>,
+    num_edges: usize,
+    _marker: PhantomData<K>,
+}
+
+impl<K: AdjMatrixEdgeKind> AdjMatrix<K> {
+    /// Creates a new graph with `n` vertices and no edges.
+    pub fn new(n: usize) -> Self {
+        assert!(n < u32::max_value() as usize, "too many vertices");
+        AdjMatrix {
+            n,
+            adj: vec![false; n * n],
+            degree: vec![0; n],
+            num_edges: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new graph with the vertices and edges of `g`. The vertex `v` of `g` corresponds
+    /// to the vertex `g.vertex_index().get(v)` of the new graph. Parallel edges of `g` are added
+    /// only once.
+    pub fn from_graph<G>(g: &G) -> Self
+    where
+        G: VertexList + EdgeList<Kind = K> + WithVertexIndexProp,
+    {
+        let index = g.vertex_index();
+        let mut m = Self::new(g.num_vertices());
+        for (u, v) in g.edges_ends() {
+            let (u, v) = (index.get(u) as u32, index.get(v) as u32);
+            if !m.contains(u, v) {
+                m.add_edge(u, v);
+            }
+        }
+        m
+    }
+
+    /// Adds the edge `(u, v)` and returns it.
+    ///
+    /// # Panics
+    ///
+    /// If `u` or `v` is not a vertex of the graph or if the graph already has the edge `(u, v)`.
+    pub fn add_edge(&mut self, u: u32, v: u32) -> K::Edge {
+        assert!(
+            (u as usize) < self.n && (v as usize) < self.n,
+            "invalid vertex"
+        );
+        assert!(!self.contains(u, v), "parallel edges are not supported");
+        self.set(u, v);
+        if K::is_undirected() && u != v {
+            self.set(v, u);
+        }
+        self.num_edges += 1;
+        K::Edge::new(u, v)
+    }
+
+    fn set(&mut self, u: u32, v: u32) {
+        let i = self.pos(u, v);
+        self.adj[i] = true;
+        self.degree[u as usize] += 1;
+    }
+
+    fn contains(&self, u: u32, v: u32) -> bool {
+        (u as usize) < self.n && (v as usize) < self.n && self.adj[self.pos(u, v)]
+    }
+
+    fn pos(&self, u: u32, v: u32) -> usize {
+        u as usize * self.n + v as usize
+    }
+
+    fn row(&self, u: u32) -> &[bool] {
+        let start = self.pos(u, 0);
+        &self.adj[start..start + self.n]
+    }
+}
+
+// Builder
+
+impl<K: AdjMatrixEdgeKind> WithBuilder for AdjMatrix<K> {
+    type Builder = AdjMatrixBuilder<K>;
+}
+
+pub struct AdjMatrixBuilder<K: AdjMatrixEdgeKind> {
+    g: AdjMatrix<K>,
+    edges: Vec<K::Edge>,
+}
+
+impl<K: AdjMatrixEdgeKind> Builder for AdjMatrixBuilder<K> {
+    type Graph = AdjMatrix<K>;
+
+    fn new(num_vertices: usize, num_edges: usize) -> Self {
+        AdjMatrixBuilder {
+            g: AdjMatrix::new(num_vertices),
+            edges: Vec::with_capacity(num_edges),
+        }
+    }
+
+    fn add_edge(&mut self, u: usize, v: usize) {
+        let e = self.g.add_edge(u as u32, v as u32);
+        self.edges.push(e);
+    }
+
+    fn finalize(self) -> Self::Graph {
+        self.g
+    }
+
+    fn finalize_(
+        self,
+    ) -> (
+        Self::Graph,
+        Vec<Vertex<Self::Graph>>,
+        Vec<Edge<Self::Graph>>,
+    ) {
+        let vertices = (0..self.g.n as u32).collect();
+        (self.g, vertices, self.edges)
+    }
+}
+
+// Graph implementation
+
+impl<K: AdjMatrixEdgeKind> WithVertex for AdjMatrix<K> {
+    type Vertex = u32;
+    type OptionVertex = OptionalMax<u32>;
+}
+
+impl<K: AdjMatrixEdgeKind> WithEdge for AdjMatrix<K> {
+    type Kind = K;
+    type Edge = K::Edge;
+    type OptionEdge = Option<K::Edge>;
+
+    fn orientation(&self, _e: Edge<Self>) -> Orientation {
+        K::orientation()
+    }
+
+    fn source(&self, e: Edge<Self>) -> Vertex<Self> {
+        e.source()
+    }
+
+    fn target(&self, e: Edge<Self>) -> Vertex<Self> {
+        e.target()
+    }
+
+    fn get_reverse(&self, e: Edge<Self>) -> Option<Edge<Self>> {
+        if K::is_undirected() {
+            Some(K::Edge::new(e.target(), e.source()))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, K: AdjMatrixEdgeKind> VertexTypes<'a, AdjMatrix<K>> for AdjMatrix<K> {
+    type VertexIter = Range<u32>;
+    type OutNeighborIter = AdjMatrixOutNeighborIter<'a>;
+}
+
+impl<'a, K: AdjMatrixEdgeKind> EdgeTypes<'a, AdjMatrix<K>> for AdjMatrix<K> {
+    type EdgeIter = AdjMatrixEdgeIter<'a, K>;
+    type OutEdgeIter = AdjMatrixOutEdgeIter<'a, K>;
+}
+
+impl<K: AdjMatrixEdgeKind> VertexList for AdjMatrix<K> {
+    fn num_vertices(&self) -> usize {
+        self.n
+    }
+
+    fn vertices(&self) -> VertexIter<Self> {
+        0..self.n as u32
+    }
+}
+
+impl<K: AdjMatrixEdgeKind> EdgeList for AdjMatrix<K> {
+    fn num_edges(&self) -> usize {
+        self.num_edges
+    }
+
+    fn edges(&self) -> EdgeIter<Self> {
+        AdjMatrixEdgeIter {
+            g: self,
+            u: 0,
+            iter: self.out_neighbors(0),
+            rem: self.num_edges,
+        }
+    }
+
+    fn get_edge_by_ends(&self, u: Vertex<Self>, v: Vertex<Self>) -> Option<Edge<Self>> {
+        if self.contains(u, v) {
+            Some(K::Edge::new(u, v))
+        } else {
+            None
+        }
+    }
+}
+
+impl<K: AdjMatrixEdgeKind> Adjacency for AdjMatrix<K> {
+    fn out_neighbors(&self, v: Vertex<Self>) -> OutNeighborIter<Self> {
+        let row = if (v as usize) < self.n {
+            self.row(v)
+        } else {
+            assert!(self.n == 0 && v == 0, "invalid vertex");
+            &[]
+        };
+        AdjMatrixOutNeighborIter {
+            iter: row.iter().enumerate(),
+            rem: row.iter().filter(|&&x| x).count(),
+        }
+    }
+
+    fn out_degree(&self, v: Vertex<Self>) -> usize {
+        self.degree[v as usize]
+    }
+}
+
+impl<K: AdjMatrixEdgeKind> Incidence for AdjMatrix<K> {
+    fn out_edges(&self, v: Vertex<Self>) -> OutEdgeIter<Self> {
+        AdjMatrixOutEdgeIter {
+            source: v,
+            iter: self.out_neighbors(v),
+            _marker: PhantomData,
+        }
+    }
+}
+
+// Iterators
+
+pub struct AdjMatrixOutNeighborIter<'a> {
+    iter: Enumerate<slice::Iter<'a, bool>>,
+    rem: usize,
+}
+
+impl<'a> Iterator for AdjMatrixOutNeighborIter<'a> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        for (v, &adj) in &mut self.iter {
+            if adj {
+                self.rem -= 1;
+                return Some(v as u32);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rem, Some(self.rem))
+    }
+}
+
+impl<'a> ExactSizeIterator for AdjMatrixOutNeighborIter<'a> {}
+
+pub struct AdjMatrixOutEdgeIter<'a, K> {
+    source: u32,
+    iter: AdjMatrixOutNeighborIter<'a>,
+    _marker: PhantomData<K>,
+}
+
+impl<'a, K: AdjMatrixEdgeKind> Iterator for AdjMatrixOutEdgeIter<'a, K> {
+    type Item = K::Edge;
+
+    fn next(&mut self) -> Option<K::Edge> {
+        let source = self.source;
+        self.iter.next().map(|v| K::Edge::new(source, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K: AdjMatrixEdgeKind> ExactSizeIterator for AdjMatrixOutEdgeIter<'a, K> {}
+
+pub struct AdjMatrixEdgeIter<'a, K: 'a + AdjMatrixEdgeKind> {
+    g: &'a AdjMatrix<K>,
+    u: u32,
+    iter: AdjMatrixOutNeighborIter<'a>,
+    rem: usize,
+}
+
+impl<'a, K: AdjMatrixEdgeKind> Iterator for AdjMatrixEdgeIter<'a, K> {
+    type Item = K::Edge;
+
+    fn next(&mut self) -> Option<K::Edge> {
+        while self.rem > 0 {
+            if let Some(v) = self.iter.next() {
+                // each undirected edge is produced once, from its smallest end
+                if K::is_directed() || self.u <= v {
+                    self.rem -= 1;
+                    return Some(K::Edge::new(self.u, v));
+                }
+            } else {
+                self.u += 1;
+                self.iter = self.g.out_neighbors(self.u);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rem, Some(self.rem))
+    }
+}
+
+impl<'a, K: AdjMatrixEdgeKind> ExactSizeIterator for AdjMatrixEdgeIter<'a, K> {}
+
+// Props
+
+impl<K: AdjMatrixEdgeKind> WithVertexIndexProp for AdjMatrix<K> {
+    type VertexIndexProp = SVertexIndexProp;
+
+    fn vertex_index(&self) -> VertexIndexProp<Self> {
+        SVertexIndexProp
+    }
+}
+
+impl<K: AdjMatrixEdgeKind, T> WithVertexProp<T> for AdjMatrix<K> {
+    type VertexProp = VecVertexProp<Self, T>;
+}
+
+impl<K: AdjMatrixEdgeKind> BasicVertexProps for AdjMatrix<K> {}
+
+impl<K: AdjMatrixEdgeKind, T: Clone> WithEdgeProp<T> for AdjMatrix<K> {
+    type EdgeProp = HashMapProp<K::Edge, T>;
+}
+
+impl<K: AdjMatrixEdgeKind> BasicEdgeProps for AdjMatrix<K> {}
+
+impl<K: AdjMatrixEdgeKind> BasicProps for AdjMatrix<K> {}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    pub use super::{AdjMatrixDigraph, AdjMatrixGraph};
+    pub use prelude::*;
+    use tests::GraphTests;
+
+    macro_rules! test {
+        ($m:ident, $g:ident) => {
+            mod $m {
+                pub use super::*;
+
+                struct Test;
+
+                impl GraphTests for Test {
+                    type G = $g;
+
+                    fn new() -> (Self::G, Vec<Vertex<Self::G>>, Vec<Edge<Self::G>>) {
+                        Self::new_with_builder()
+                    }
+                }
+
+                graph_tests!{Test}
+
+                mod with_builder {
+                    use super::*;
+                    use builder::BuilderTests;
+
+                    struct Test;
+
+                    impl BuilderTests for Test {
+                        type G = AdjMatrixGraph;
+                    }
+
+                    graph_builder_tests!{Test}
+                }
+            }
+        };
+    }
+
+    test!(directed, AdjMatrixDigraph);
+    test!(undirected, AdjMatrixGraph);
+
+    mod matrix {
+        use super::*;
+        use fera_fun::{set, vec};
+        use rand::prelude::*;
+
+        #[test]
+        fn loops() {
+            let g: AdjMatrixGraph = graph!(3, (0, 0), (0, 1), (2, 2));
+            assert_eq!(3, g.num_edges());
+            assert_eq!(vec![(0, 0), (0, 1), (2, 2)], vec(g.edges_ends()));
+            assert_eq!(vec![0, 1], vec(g.out_neighbors(0)));
+            assert_eq!(2, g.out_degree(0));
+            assert_eq!(1, g.out_degree(2));
+        }
+
+        #[test]
+        fn directed() {
+            let g: AdjMatrixDigraph = graph!(3, (0, 1), (1, 0), (2, 1));
+            assert!(g.get_edge_by_ends(1, 2).is_none());
+            assert_eq!(vec![(0, 1), (1, 0), (2, 1)], vec(g.edges_ends()));
+            assert_ne!(g.edge_by_ends(0, 1), g.edge_by_ends(1, 0));
+            assert_eq!(None, g.get_reverse(g.edge_by_ends(0, 1)));
+        }
+
+        #[test]
+        #[should_panic]
+        fn parallel_edges() {
+            let _: AdjMatrixGraph = graph!(2, (0, 1), (1, 0));
+        }
+
+        #[test]
+        fn from_graph() {
+            let mut rng = SmallRng::from_entropy();
+            for n in 0..20usize {
+                let m = n * n.saturating_sub(1) / 4;
+                let g = StaticGraph::new_gnm(n, m, &mut rng).unwrap();
+                let a = AdjMatrixGraph::from_graph(&g);
+                assert_eq!(g.num_vertices(), a.num_vertices());
+                assert_eq!(g.num_edges(), a.num_edges());
+                assert_eq!(g.edges().len(), a.edges().len());
+                for u in g.vertices() {
+                    assert_eq!(set(g.out_neighbors(u)), set(a.out_neighbors(u)));
+                    assert_eq!(g.out_degree(u), a.out_degree(u));
+                    for v in g.vertices() {
+                        assert_eq!(
+                            g.get_edge_by_ends(u, v).is_some(),
+                            a.get_edge_by_ends(u, v).is_some()
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -37,6 +37,74 @@ impl<K: CompleteEdgeKind> Complete<K> {
             _marker: PhantomData,
         }
     }
+
+    /// Creates a new complete graph with `n` vertices and an edge property that gives the weight
+    /// of the edge `(u, v)` by calling `f(u, v)`. See [`weights`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fera_graph::prelude::*;
+    /// use fera_graph::algs::Kruskal;
+    ///
+    /// // points in a line
+    /// let x = [3i32, 0, 7, 1];
+    /// let (g, w) = CompleteGraph::with_weights(4, |u, v| (x[u as usize] - x[v as usize]).abs());
+    /// let tree: Vec<_> = g.kruskal_mst(&w).into_iter().collect();
+    /// assert_eq!(7, tree.iter().map(|&e| w.get(e)).sum::<i32>());
+    /// ```
+    ///
+    /// [`weights`]: #method.weights
+    pub fn with_weights<F, W>(n: CVertex, f: F) -> (Self, CompleteWeights<K, F>)
+    where
+        F: Fn(CVertex, CVertex) -> W,
+    {
+        let g = Self::new(n);
+        let w = g.weights(f);
+        (g, w)
+    }
+
+    /// Creates an edge property that gives the weight of the edge `(u, v)` by calling `f(u, v)`.
+    ///
+    /// The weights are not stored, so this can be used to run algorithms (like minimum spanning
+    /// tree or shortest paths) on a complete graph without using O(n²) memory for the weights.
+    pub fn weights<F, W>(&self, f: F) -> CompleteWeights<K, F>
+    where
+        F: Fn(CVertex, CVertex) -> W,
+    {
+        CompleteWeights {
+            n: self.n,
+            f,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// An edge property of a complete graph backed by a function of the ends of the edges.
+///
+/// This is created by [`Complete::weights`] and [`Complete::with_weights`].
+///
+/// [`Complete::weights`]: struct.Complete.html#method.weights
+/// [`Complete::with_weights`]: struct.Complete.html#method.with_weights
+#[derive(Clone, Copy)]
+pub struct CompleteWeights<K, F> {
+    n: CVertex,
+    f: F,
+    _marker: PhantomData<K>,
+}
+
+impl<K, F, W> PropGet<K::Edge> for CompleteWeights<K, F>
+where
+    K: CompleteEdgeKind,
+    F: Fn(CVertex, CVertex) -> W,
+{
+    type Output = W;
+
+    #[inline]
+    fn get(&self, e: K::Edge) -> W {
+        let (u, v) = K::Edge::ends(e, self.n);
+        (self.f)(u, v)
+    }
 }
 
 pub trait CompleteEdgeKind: UniformEdgeKind {
@@ -537,6 +605,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn weights_prim() {
+        use algs::{Kruskal, Prim};
+        use fera_fun::vec;
+        use rand::prelude::*;
+
+        let mut rng = SmallRng::from_entropy();
+        for n in 1..30 {
+            let points = vec((0..n).map(|_| (rng.gen_range(0, 100), rng.gen_range(0, 100))));
+            // the squared distance gives the same trees as the euclidean distance
+            let dist = |u: CVertex, v: CVertex| {
+                let ((a, b), (c, d)): ((i64, i64), (i64, i64)) =
+                    (points[u as usize], points[v as usize]);
+                (a - c) * (a - c) + (b - d) * (b - d)
+            };
+            let (g, w) = CompleteGraph::with_weights(n, dist);
+            let tree = vec(g.prim(&w));
+            assert_eq!(n as usize - 1, tree.len());
+            let total: i64 = tree.iter().map(|&e| w.get(e)).sum();
+
+            // compare with kruskal on a graph with stored weights
+            let edges = g.edges_ends().map(|(u, v)| (u as usize, v as usize));
+            let s = StaticGraph::new_with_edges(n as usize, edges);
+            let mut sw = s.default_edge_prop(0);
+            for (e, f) in s.edges().zip(g.edges()) {
+                sw[e] = w.get(f);
+            }
+            let expected: i64 = s.kruskal_mst(&sw).into_iter().map(|e| sw[e]).sum();
+            assert_eq!(expected, total);
+        }
+    }
+
+    #[test]
+    fn weights_directed() {
+        let (g, w) = CompleteDigraph::with_weights(3, |u, v| 10 * u + v);
+        assert_eq!(1, w.get(g.edge_by_ends(0, 1)));
+        assert_eq!(10, w.get(g.edge_by_ends(1, 0)));
+        assert_eq!(21, w.get(g.edge_by_ends(2, 1)));
+    }
+
     #[test]
     fn edge_impl() {
         for n in 2..10 {
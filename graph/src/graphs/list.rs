@@ -0,0 +1,786 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A graph that supports adding and removing vertices and edges.
+//!
+//! # Examples
+//!
+//! ```
+//! use fera_graph::prelude::*;
+//!
+//! let mut g = ListGraph::new();
+//! let a = g.add_vertex();
+//! let b = g.add_vertex();
+//! let c = g.add_vertex();
+//! let ab = g.add_edge(a, b);
+//! let bc = g.add_edge(b, c);
+//! assert_eq!(2, g.out_degree(b));
+//!
+//! g.remove_vertex(a);
+//! assert!(!g.contains_edge(ab));
+//! assert_eq!(vec![b, c], g.vertices().collect::<Vec<_>>());
+//! assert_eq!(vec![bc], g.edges().collect::<Vec<_>>());
+//! assert_eq!(1, g.out_degree(b));
+//! ```
+
+use graphs::common::OutNeighborFromOutEdge;
+use prelude::*;
+use props::HashMapProp;
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::iter::{Cloned, Enumerate};
+use std::marker::PhantomData;
+use std::slice;
+
+pub type ListGraph = List<Undirected>;
+
+pub type ListDigraph = List<Directed>;
+
+/// The edge kinds supported by [`List`].
+///
+/// [`List`]: struct.List.html
+pub trait ListEdgeKind: 'static + UniformEdgeKind {}
+
+impl ListEdgeKind for Directed {}
+
+impl ListEdgeKind for Undirected {}
+
+/// A vertex of a [`List`] graph.
+///
+/// A vertex remains valid until it is removed from the graph. The handle of a removed vertex is
+/// never equal to the handle of a vertex added afterwards.
+///
+/// [`List`]: struct.List.html
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ListVertex {
+    index: u32,
+    gen: u32,
+}
+
+/// An edge of a [`List`] graph.
+///
+/// An edge remains valid until it is removed from the graph. The handle of a removed edge is never
+/// equal to the handle of an edge added afterwards. In an undirected graph an edge and its reverse
+/// are equal.
+///
+/// [`List`]: struct.List.html
+#[derive(Copy, Clone, Debug, Eq)]
+pub struct ListEdge {
+    index: u32,
+    gen: u32,
+    rev: bool,
+}
+
+impl ListEdge {
+    fn reverse(self) -> Self {
+        ListEdge {
+            rev: !self.rev,
+            ..self
+        }
+    }
+
+    fn key(&self) -> (u32, u32) {
+        (self.index, self.gen)
+    }
+}
+
+impl PartialEq for ListEdge {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl PartialOrd for ListEdge {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ListEdge {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl Hash for ListEdge {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.key().hash(state)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct VertexSlot {
+    gen: u32,
+    alive: bool,
+    out: Vec<ListEdge>,
+    // The in edges are only stored for directed graphs
+    in_: Vec<ListEdge>,
+}
+
+#[derive(Clone, Debug)]
+struct EdgeSlot {
+    gen: u32,
+    alive: bool,
+    source: ListVertex,
+    target: ListVertex,
+}
+
+/// A graph backed by adjacency lists that supports adding and removing vertices and edges.
+///
+/// Adding a vertex or an edge takes constant amortized time, removing an edge `(u, v)` takes
+/// `O(d(u) + d(v))` time and removing a vertex `v` takes `O(d(v) + sum of d(u))` time, where the
+/// sum is over the neighbors `u` of `v`. Loops and parallel edges are supported.
+///
+/// The handles of the vertices and edges remain valid until they are removed, independently of
+/// other additions and removals. The vertices and edges properties are backed by hash maps, so
+/// they can be used with vertices and edges added after the property creation.
+///
+/// See the [module documentation] for an example.
+///
+/// [module documentation]: index.html
+#[derive(Clone, Debug)]
+pub struct List<K: ListEdgeKind> {
+    vertices: Vec<VertexSlot>,
+    edges: Vec<EdgeSlot>,
+    free_vertices: Vec<u32>,
+    free_edges: Vec<u32>,
+    num_vertices: usize,
+    num_edges: usize,
+    _marker: PhantomData<K>,
+}
+
+impl<K: ListEdgeKind> Default for List<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: ListEdgeKind> List<K> {
+    /// Creates a new graph without vertices.
+    pub fn new() -> Self {
+        List {
+            vertices: vec![],
+            edges: vec![],
+            free_vertices: vec![],
+            free_edges: vec![],
+            num_vertices: 0,
+            num_edges: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns `true` if `v` is a vertex of this graph, that is, `v` was added and was not
+    /// removed.
+    pub fn contains_vertex(&self, v: ListVertex) -> bool {
+        self.vertices
+            .get(v.index as usize)
+            .map(|s| s.alive && s.gen == v.gen)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if `e` is an edge of this graph, that is, `e` was added and was not
+    /// removed.
+    pub fn contains_edge(&self, e: ListEdge) -> bool {
+        self.edges
+            .get(e.index as usize)
+            .map(|s| s.alive && s.gen == e.gen)
+            .unwrap_or(false)
+    }
+
+    /// Adds a new vertex to the graph and returns it.
+    pub fn add_vertex(&mut self) -> ListVertex {
+        let index = match self.free_vertices.pop() {
+            Some(index) => index,
+            None => {
+                self.vertices.push(VertexSlot::default());
+                to_u32(self.vertices.len() - 1)
+            }
+        };
+        let slot = &mut self.vertices[index as usize];
+        slot.alive = true;
+        self.num_vertices += 1;
+        ListVertex {
+            index,
+            gen: slot.gen,
+        }
+    }
+
+    /// Adds the edge `(u, v)` to the graph and returns it.
+    ///
+    /// # Panics
+    ///
+    /// If `u` or `v` is not a vertex of the graph.
+    pub fn add_edge(&mut self, u: ListVertex, v: ListVertex) -> ListEdge {
+        self.check_vertex(u);
+        self.check_vertex(v);
+        let index = match self.free_edges.pop() {
+            Some(index) => index,
+            None => {
+                self.edges.push(EdgeSlot {
+                    gen: 0,
+                    alive: false,
+                    source: u,
+                    target: v,
+                });
+                to_u32(self.edges.len() - 1)
+            }
+        };
+        let slot = &mut self.edges[index as usize];
+        slot.alive = true;
+        slot.source = u;
+        slot.target = v;
+        let e = ListEdge {
+            index,
+            gen: slot.gen,
+            rev: false,
+        };
+        self.vertices[u.index as usize].out.push(e);
+        if K::is_undirected() {
+            self.vertices[v.index as usize].out.push(e.reverse());
+        } else {
+            self.vertices[v.index as usize].in_.push(e);
+        }
+        self.num_edges += 1;
+        e
+    }
+
+    /// Removes the edge `e` from the graph.
+    ///
+    /// # Panics
+    ///
+    /// If `e` is not an edge of the graph.
+    pub fn remove_edge(&mut self, e: ListEdge) {
+        assert!(self.contains_edge(e), "invalid edge: {:?}", e);
+        let (u, v) = {
+            let slot = &mut self.edges[e.index as usize];
+            slot.alive = false;
+            slot.gen = slot.gen.wrapping_add(1);
+            (slot.source, slot.target)
+        };
+        remove_item(&mut self.vertices[u.index as usize].out, e);
+        if K::is_undirected() {
+            remove_item(&mut self.vertices[v.index as usize].out, e);
+        } else {
+            remove_item(&mut self.vertices[v.index as usize].in_, e);
+        }
+        self.free_edges.push(e.index);
+        self.num_edges -= 1;
+    }
+
+    /// Removes the vertex `v` and all edges incident to `v` from the graph.
+    ///
+    /// # Panics
+    ///
+    /// If `v` is not a vertex of the graph.
+    pub fn remove_vertex(&mut self, v: ListVertex) {
+        self.check_vertex(v);
+        let i = v.index as usize;
+        while let Some(&e) = self.vertices[i].out.last() {
+            self.remove_edge(e);
+        }
+        while let Some(&e) = self.vertices[i].in_.last() {
+            self.remove_edge(e);
+        }
+        let slot = &mut self.vertices[i];
+        slot.alive = false;
+        slot.gen = slot.gen.wrapping_add(1);
+        self.free_vertices.push(v.index);
+        self.num_vertices -= 1;
+    }
+
+    fn check_vertex(&self, v: ListVertex) {
+        assert!(self.contains_vertex(v), "invalid vertex: {:?}", v);
+    }
+
+    fn edge_slot(&self, e: ListEdge) -> &EdgeSlot {
+        let slot = &self.edges[e.index as usize];
+        debug_assert!(slot.alive && slot.gen == e.gen, "invalid edge: {:?}", e);
+        slot
+    }
+
+    fn vertex_slot(&self, v: ListVertex) -> &VertexSlot {
+        let slot = &self.vertices[v.index as usize];
+        debug_assert!(slot.alive && slot.gen == v.gen, "invalid vertex: {:?}", v);
+        slot
+    }
+}
+
+fn to_u32(i: usize) -> u32 {
+    assert!(i < u32::max_value() as usize, "too many items");
+    i as u32
+}
+
+// Removes one occurrence of `e` from `edges`.
+fn remove_item(edges: &mut Vec<ListEdge>, e: ListEdge) {
+    let i = edges.iter().position(|&x| x == e).unwrap();
+    edges.swap_remove(i);
+}
+
+// Builder
+
+impl<K: ListEdgeKind> WithBuilder for List<K> {
+    type Builder = ListBuilder<K>;
+}
+
+pub struct ListBuilder<K: ListEdgeKind> {
+    g: List<K>,
+    vertices: Vec<ListVertex>,
+    edges: Vec<ListEdge>,
+}
+
+impl<K: ListEdgeKind> Builder for ListBuilder<K> {
+    type Graph = List<K>;
+
+    fn new(num_vertices: usize, num_edges: usize) -> Self {
+        let mut g = List::new();
+        g.vertices.reserve(num_vertices);
+        g.edges.reserve(num_edges);
+        let vertices = (0..num_vertices).map(|_| g.add_vertex()).collect();
+        ListBuilder {
+            g,
+            vertices,
+            edges: Vec::with_capacity(num_edges),
+        }
+    }
+
+    fn add_edge(&mut self, u: usize, v: usize) {
+        let e = self.g.add_edge(self.vertices[u], self.vertices[v]);
+        self.edges.push(e);
+    }
+
+    fn finalize(self) -> Self::Graph {
+        self.g
+    }
+
+    fn finalize_(
+        self,
+    ) -> (
+        Self::Graph,
+        Vec<Vertex<Self::Graph>>,
+        Vec<Edge<Self::Graph>>,
+    ) {
+        (self.g, self.vertices, self.edges)
+    }
+}
+
+// Graph implementation
+
+impl<K: ListEdgeKind> WithVertex for List<K> {
+    type Vertex = ListVertex;
+    type OptionVertex = Option<ListVertex>;
+}
+
+impl<K: ListEdgeKind> WithEdge for List<K> {
+    type Kind = K;
+    type Edge = ListEdge;
+    type OptionEdge = Option<ListEdge>;
+
+    fn orientation(&self, _e: Edge<Self>) -> Orientation {
+        K::orientation()
+    }
+
+    fn source(&self, e: Edge<Self>) -> Vertex<Self> {
+        let slot = self.edge_slot(e);
+        if e.rev {
+            slot.target
+        } else {
+            slot.source
+        }
+    }
+
+    fn target(&self, e: Edge<Self>) -> Vertex<Self> {
+        let slot = self.edge_slot(e);
+        if e.rev {
+            slot.source
+        } else {
+            slot.target
+        }
+    }
+
+    fn get_reverse(&self, e: Edge<Self>) -> Option<Edge<Self>> {
+        if K::is_undirected() {
+            Some(e.reverse())
+        } else {
+            let (u, v) = self.ends(e);
+            self.get_edge_by_ends(v, u)
+        }
+    }
+}
+
+impl<'a, K: ListEdgeKind> VertexTypes<'a, List<K>> for List<K> {
+    type VertexIter = ListVertexIter<'a>;
+    type OutNeighborIter = OutNeighborFromOutEdge<'a, Self, OutEdgeIter<'a, Self>>;
+}
+
+impl<'a, K: ListEdgeKind> EdgeTypes<'a, List<K>> for List<K> {
+    type EdgeIter = ListEdgeIter<'a>;
+    type OutEdgeIter = Cloned<slice::Iter<'a, ListEdge>>;
+}
+
+impl<K: ListEdgeKind> VertexList for List<K> {
+    fn num_vertices(&self) -> usize {
+        self.num_vertices
+    }
+
+    fn vertices(&self) -> VertexIter<Self> {
+        ListVertexIter {
+            iter: self.vertices.iter().enumerate(),
+            rem: self.num_vertices,
+        }
+    }
+}
+
+impl<K: ListEdgeKind> EdgeList for List<K> {
+    fn num_edges(&self) -> usize {
+        self.num_edges
+    }
+
+    fn edges(&self) -> EdgeIter<Self> {
+        ListEdgeIter {
+            iter: self.edges.iter().enumerate(),
+            rem: self.num_edges,
+        }
+    }
+
+    fn get_edge_by_ends(&self, u: Vertex<Self>, v: Vertex<Self>) -> Option<Edge<Self>> {
+        self.vertex_slot(u)
+            .out
+            .iter()
+            .find(|&&e| self.target(e) == v)
+            .cloned()
+    }
+}
+
+impl<K: ListEdgeKind> Adjacency for List<K> {
+    fn out_neighbors(&self, v: Vertex<Self>) -> OutNeighborIter<Self> {
+        OutNeighborFromOutEdge::new(self, self.out_edges(v))
+    }
+
+    fn out_degree(&self, v: Vertex<Self>) -> usize {
+        self.vertex_slot(v).out.len()
+    }
+}
+
+impl<K: ListEdgeKind> Incidence for List<K> {
+    fn out_edges(&self, v: Vertex<Self>) -> OutEdgeIter<Self> {
+        self.vertex_slot(v).out.iter().cloned()
+    }
+}
+
+impl<'a, K: ListEdgeKind> InEdgeTypes<'a, List<K>> for List<K> {
+    type InEdgeIter = Cloned<slice::Iter<'a, ListEdge>>;
+}
+
+impl<K: ListEdgeKind> InIncidence for List<K> {
+    fn in_edges(&self, v: Vertex<Self>) -> InEdgeIter<Self> {
+        let slot = self.vertex_slot(v);
+        if K::is_undirected() {
+            slot.out.iter().cloned()
+        } else {
+            slot.in_.iter().cloned()
+        }
+    }
+
+    fn in_degree(&self, v: Vertex<Self>) -> usize {
+        let slot = self.vertex_slot(v);
+        if K::is_undirected() {
+            slot.out.len()
+        } else {
+            slot.in_.len()
+        }
+    }
+}
+
+// Iterators
+
+pub struct ListVertexIter<'a> {
+    iter: Enumerate<slice::Iter<'a, VertexSlot>>,
+    rem: usize,
+}
+
+impl<'a> Iterator for ListVertexIter<'a> {
+    type Item = ListVertex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (i, slot) in &mut self.iter {
+            if slot.alive {
+                self.rem -= 1;
+                return Some(ListVertex {
+                    index: i as u32,
+                    gen: slot.gen,
+                });
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rem, Some(self.rem))
+    }
+}
+
+impl<'a> ExactSizeIterator for ListVertexIter<'a> {}
+
+pub struct ListEdgeIter<'a> {
+    iter: Enumerate<slice::Iter<'a, EdgeSlot>>,
+    rem: usize,
+}
+
+impl<'a> Iterator for ListEdgeIter<'a> {
+    type Item = ListEdge;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (i, slot) in &mut self.iter {
+            if slot.alive {
+                self.rem -= 1;
+                return Some(ListEdge {
+                    index: i as u32,
+                    gen: slot.gen,
+                    rev: false,
+                });
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rem, Some(self.rem))
+    }
+}
+
+impl<'a> ExactSizeIterator for ListEdgeIter<'a> {}
+
+// Props
+
+impl<K: ListEdgeKind, T: Clone> WithVertexProp<T> for List<K> {
+    type VertexProp = HashMapProp<ListVertex, T>;
+}
+
+impl<K: ListEdgeKind> BasicVertexProps for List<K> {}
+
+impl<K: ListEdgeKind, T: Clone> WithEdgeProp<T> for List<K> {
+    type EdgeProp = HashMapProp<ListEdge, T>;
+}
+
+impl<K: ListEdgeKind> BasicEdgeProps for List<K> {}
+
+impl<K: ListEdgeKind> BasicProps for List<K> {}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    pub use super::{List, ListDigraph, ListEdge, ListEdgeKind, ListGraph, ListVertex};
+    pub use prelude::*;
+    use tests::GraphTests;
+
+    macro_rules! test {
+        ($m:ident, $g:ident) => {
+            mod $m {
+                pub use super::*;
+
+                struct Test;
+
+                impl GraphTests for Test {
+                    type G = $g;
+
+                    fn new() -> (Self::G, Vec<Vertex<Self::G>>, Vec<Edge<Self::G>>) {
+                        Self::new_with_builder()
+                    }
+                }
+
+                graph_tests!{Test}
+
+                mod with_builder {
+                    use super::*;
+                    use builder::BuilderTests;
+
+                    struct Test;
+
+                    impl BuilderTests for Test {
+                        type G = ListGraph;
+                    }
+
+                    graph_builder_tests!{Test}
+                }
+            }
+        };
+    }
+
+    test!(directed, ListDigraph);
+    test!(undirected, ListGraph);
+
+    mod add_remove {
+        use super::*;
+        use fera_fun::{set, vec};
+        use rand::prelude::*;
+
+        #[test]
+        fn remove_edge() {
+            let mut g = ListGraph::new();
+            let v = vec((0..4).map(|_| g.add_vertex()));
+            let e01 = g.add_edge(v[0], v[1]);
+            let e12 = g.add_edge(v[1], v[2]);
+            let e22 = g.add_edge(v[2], v[2]);
+            let e21 = g.add_edge(v[2], v[1]);
+            assert_eq!(4, g.num_edges());
+            assert_eq!(3, g.out_degree(v[1]));
+            assert_eq!(4, g.out_degree(v[2]));
+
+            g.remove_edge(e12);
+            assert!(!g.contains_edge(e12));
+            assert_eq!(3, g.num_edges());
+            assert_eq!(2, g.out_degree(v[1]));
+            assert_eq!(3, g.out_degree(v[2]));
+            assert_eq!(Some(e21), g.get_edge_by_ends(v[1], v[2]));
+
+            g.remove_edge(g.reverse(e22));
+            assert_eq!(1, g.out_degree(v[2]));
+            assert_eq!(set(vec![e01, e21]), set(g.edges()));
+
+            // the slot of e12 is reused, but the handles are different
+            let e03 = g.add_edge(v[0], v[3]);
+            assert_ne!(e12, e03);
+            assert!(!g.contains_edge(e12));
+            assert_eq!((v[0], v[3]), g.ends(e03));
+        }
+
+        #[test]
+        fn remove_vertex() {
+            let mut g = ListDigraph::new();
+            let v = vec((0..4).map(|_| g.add_vertex()));
+            let e01 = g.add_edge(v[0], v[1]);
+            g.add_edge(v[1], v[2]);
+            g.add_edge(v[3], v[1]);
+            g.add_edge(v[1], v[1]);
+            let e23 = g.add_edge(v[2], v[3]);
+
+            g.remove_vertex(v[1]);
+            assert!(!g.contains_vertex(v[1]));
+            assert_eq!(3, g.num_vertices());
+            assert_eq!(vec![v[0], v[2], v[3]], vec(g.vertices()));
+            assert_eq!(vec![e23], vec(g.edges()));
+            assert!(!g.contains_edge(e01));
+            assert_eq!(0, g.out_degree(v[0]));
+            assert_eq!(0, g.out_degree(v[3]));
+            assert_eq!(1, g.in_degree(v[3]));
+
+            let u = g.add_vertex();
+            assert_ne!(v[1], u);
+            assert!(g.contains_vertex(u));
+            assert!(!g.contains_vertex(v[1]));
+        }
+
+        #[test]
+        #[should_panic]
+        fn remove_removed_vertex() {
+            let mut g = ListGraph::new();
+            let u = g.add_vertex();
+            g.remove_vertex(u);
+            g.remove_vertex(u);
+        }
+
+        #[test]
+        #[should_panic]
+        fn add_edge_removed_vertex() {
+            let mut g = ListGraph::new();
+            let u = g.add_vertex();
+            let v = g.add_vertex();
+            g.remove_vertex(u);
+            g.add_edge(u, v);
+        }
+
+        #[test]
+        fn props() {
+            let mut g = ListGraph::new();
+            let u = g.add_vertex();
+            let mut p = g.default_vertex_prop(0);
+            p[u] = 1;
+            let v = g.add_vertex();
+            assert_eq!(0, p[v]);
+            let e = g.add_edge(u, v);
+            let mut w = g.default_edge_prop(0);
+            w[e] = 5;
+            assert_eq!(5, w[g.reverse(e)]);
+        }
+
+        fn check<K: ListEdgeKind>(
+            g: &List<K>,
+            vertices: &[ListVertex],
+            edges: &[(ListEdge, ListVertex, ListVertex)],
+        ) {
+            use std::collections::HashMap;
+
+            assert_eq!(set(vertices.iter().cloned()), set(g.vertices()));
+            assert_eq!(vertices.len(), g.num_vertices());
+            assert_eq!(set(edges.iter().map(|t| t.0)), set(g.edges()));
+            assert_eq!(edges.len(), g.num_edges());
+
+            let mut out = HashMap::new();
+            let mut in_ = HashMap::new();
+            for &(e, u, v) in edges {
+                assert_eq!((u, v), g.ends(e));
+                *out.entry(u).or_insert(0) += 1;
+                *in_.entry(v).or_insert(0) += 1;
+            }
+            for &v in vertices {
+                let d_out = out.get(&v).cloned().unwrap_or(0);
+                let d_in = in_.get(&v).cloned().unwrap_or(0);
+                if K::is_undirected() {
+                    assert_eq!(d_out + d_in, g.out_degree(v));
+                } else {
+                    assert_eq!(d_out, g.out_degree(v));
+                    assert_eq!(d_in, g.in_degree(v));
+                    assert!(g.in_edges(v).all(|e| g.target(e) == v));
+                }
+                assert!(g.out_edges(v).all(|e| g.source(e) == v));
+            }
+        }
+
+        fn random_operations<K: ListEdgeKind>() {
+            let mut rng = SmallRng::from_entropy();
+            let mut g = List::<K>::new();
+            let mut vertices = vec![];
+            let mut edges = vec![];
+            for _ in 0..500 {
+                match rng.gen_range(0, 10) {
+                    op if op < 3 => vertices.push(g.add_vertex()),
+                    3 if !vertices.is_empty() => {
+                        let i = rng.gen_range(0, vertices.len());
+                        let v = vertices.swap_remove(i);
+                        g.remove_vertex(v);
+                        edges.retain(|&(_, a, b)| a != v && b != v);
+                    }
+                    op if op < 7 && !edges.is_empty() => {
+                        let i = rng.gen_range(0, edges.len());
+                        let (e, _, _) = edges.swap_remove(i);
+                        g.remove_edge(e);
+                        assert!(!g.contains_edge(e));
+                    }
+                    _ if !vertices.is_empty() => {
+                        let u = *vertices.choose(&mut rng).unwrap();
+                        let v = *vertices.choose(&mut rng).unwrap();
+                        edges.push((g.add_edge(u, v), u, v));
+                    }
+                    _ => {}
+                }
+                check(&g, &vertices, &edges);
+            }
+        }
+
+        #[test]
+        fn random_operations_undirected() {
+            random_operations::<Undirected>();
+        }
+
+        #[test]
+        fn random_operations_directed() {
+            random_operations::<Directed>();
+        }
+    }
+}
@@ -0,0 +1,468 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A graph with directed and undirected edges.
+//!
+//! # Examples
+//!
+//! ```
+//! use fera_graph::prelude::*;
+//! use fera_graph::graphs::mixed::MixedGraph;
+//!
+//! let mut b = MixedGraph::builder(3);
+//! b.add_directed_edge(0, 1);
+//! b.add_undirected_edge(1, 2);
+//! let g = b.finalize();
+//!
+//! let e01 = g.edge_by_ends(0, 1);
+//! let e12 = g.edge_by_ends(1, 2);
+//! assert!(g.is_directed_edge(e01));
+//! assert!(g.is_undirected_edge(e12));
+//!
+//! // the directed edge (0, 1) can only be traversed from 0
+//! assert_eq!(vec![1], g.out_neighbors(0).collect::<Vec<_>>());
+//! assert_eq!(vec![2], g.out_neighbors(1).collect::<Vec<_>>());
+//! // the undirected edge (1, 2) can be traversed from 1 and 2
+//! assert_eq!(vec![1], g.out_neighbors(2).collect::<Vec<_>>());
+//! assert_eq!(Some(e12), g.get_edge_by_ends(2, 1));
+//! assert_eq!(None, g.get_edge_by_ends(1, 0));
+//! ```
+
+use graphs::common::OutNeighborFromOutEdge;
+use graphs::static_::SVertexIndexProp;
+use prelude::*;
+use props::{VecEdgeProp, VecVertexProp};
+
+use fera_optional::OptionalMax;
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::iter::Cloned;
+use std::ops::Range;
+use std::slice;
+
+/// An edge of a [`MixedGraph`].
+///
+/// An undirected edge and its reverse are equal.
+///
+/// [`MixedGraph`]: struct.MixedGraph.html
+#[derive(Copy, Clone, Debug, Eq)]
+pub struct MixedEdge {
+    index: u32,
+    rev: bool,
+}
+
+impl MixedEdge {
+    fn new(index: usize) -> Self {
+        MixedEdge {
+            index: index as u32,
+            rev: false,
+        }
+    }
+
+    fn reverse(self) -> Self {
+        MixedEdge {
+            index: self.index,
+            rev: !self.rev,
+        }
+    }
+}
+
+impl PartialEq for MixedEdge {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl PartialOrd for MixedEdge {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MixedEdge {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index.cmp(&other.index)
+    }
+}
+
+impl Hash for MixedEdge {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.index.hash(state)
+    }
+}
+
+/// A graph where each edge can be directed or undirected.
+///
+/// The out edges of a vertex `v` are the directed edges with source `v` and the undirected edges
+/// incident to `v` (oriented so that `v` is the source). Similarly, the in edges of `v` are the
+/// directed edges with target `v` and the undirected edges incident to `v` (oriented so that `v`
+/// is the target). An undirected loop is an out edge (and an in edge) of its vertex twice, like
+/// in [`StaticGraph`].
+///
+/// A `MixedGraph` is created using a [`MixedBuilder`] and cannot be changed after it is created.
+/// See the [module documentation] for an example.
+///
+/// [`StaticGraph`]: ../static_/type.StaticGraph.html
+/// [`MixedBuilder`]: struct.MixedBuilder.html
+/// [module documentation]: index.html
+#[derive(Clone, Debug)]
+pub struct MixedGraph {
+    ends: Vec<(u32, u32)>,
+    orientation: Vec<Orientation>,
+    out: Vec<Vec<MixedEdge>>,
+    in_: Vec<Vec<MixedEdge>>,
+}
+
+impl MixedGraph {
+    /// Creates a builder for a graph with `num_vertices` vertices (`0..num_vertices`).
+    pub fn builder(num_vertices: usize) -> MixedBuilder {
+        assert!(
+            num_vertices < u32::max_value() as usize,
+            "too many vertices"
+        );
+        MixedBuilder {
+            g: MixedGraph {
+                ends: vec![],
+                orientation: vec![],
+                out: vec![vec![]; num_vertices],
+                in_: vec![vec![]; num_vertices],
+            },
+        }
+    }
+
+    fn add_edge(&mut self, u: usize, v: usize, orientation: Orientation) {
+        let n = self.out.len();
+        assert!(u < n && v < n, "invalid vertex");
+        assert!(
+            self.ends.len() < u32::max_value() as usize,
+            "too many edges"
+        );
+        let e = MixedEdge::new(self.ends.len());
+        self.ends.push((u as u32, v as u32));
+        self.orientation.push(orientation);
+        self.out[u].push(e);
+        self.in_[v].push(e);
+        if orientation.is_undirected() {
+            self.out[v].push(e.reverse());
+            self.in_[u].push(e.reverse());
+        }
+    }
+}
+
+/// A builder for [`MixedGraph`].
+///
+/// [`MixedGraph`]: struct.MixedGraph.html
+pub struct MixedBuilder {
+    g: MixedGraph,
+}
+
+impl MixedBuilder {
+    /// Adds the directed edge `(u, v)`.
+    ///
+    /// # Panics
+    ///
+    /// If `u` or `v` is not a valid vertex.
+    pub fn add_directed_edge(&mut self, u: usize, v: usize) {
+        self.g.add_edge(u, v, Orientation::Directed)
+    }
+
+    /// Adds the undirected edge `(u, v)`.
+    ///
+    /// # Panics
+    ///
+    /// If `u` or `v` is not a valid vertex.
+    pub fn add_undirected_edge(&mut self, u: usize, v: usize) {
+        self.g.add_edge(u, v, Orientation::Undirected)
+    }
+
+    /// Creates the graph.
+    pub fn finalize(self) -> MixedGraph {
+        self.g
+    }
+}
+
+// Graph implementation
+
+impl WithVertex for MixedGraph {
+    type Vertex = u32;
+    type OptionVertex = OptionalMax<u32>;
+}
+
+impl WithEdge for MixedGraph {
+    type Kind = Mixed;
+    type Edge = MixedEdge;
+    type OptionEdge = Option<MixedEdge>;
+
+    fn orientation(&self, e: Edge<Self>) -> Orientation {
+        self.orientation[e.index as usize]
+    }
+
+    fn source(&self, e: Edge<Self>) -> Vertex<Self> {
+        let (u, v) = self.ends[e.index as usize];
+        if e.rev {
+            v
+        } else {
+            u
+        }
+    }
+
+    fn target(&self, e: Edge<Self>) -> Vertex<Self> {
+        let (u, v) = self.ends[e.index as usize];
+        if e.rev {
+            u
+        } else {
+            v
+        }
+    }
+
+    fn get_reverse(&self, e: Edge<Self>) -> Option<Edge<Self>> {
+        if self.is_undirected_edge(e) {
+            Some(e.reverse())
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> VertexTypes<'a, MixedGraph> for MixedGraph {
+    type VertexIter = Range<u32>;
+    type OutNeighborIter = OutNeighborFromOutEdge<'a, Self, OutEdgeIter<'a, Self>>;
+}
+
+impl<'a> EdgeTypes<'a, MixedGraph> for MixedGraph {
+    type EdgeIter = MixedEdgeIter;
+    type OutEdgeIter = Cloned<slice::Iter<'a, MixedEdge>>;
+}
+
+impl VertexList for MixedGraph {
+    fn num_vertices(&self) -> usize {
+        self.out.len()
+    }
+
+    fn vertices(&self) -> VertexIter<Self> {
+        0..self.out.len() as u32
+    }
+}
+
+impl EdgeList for MixedGraph {
+    fn num_edges(&self) -> usize {
+        self.ends.len()
+    }
+
+    fn edges(&self) -> EdgeIter<Self> {
+        MixedEdgeIter(0..self.ends.len())
+    }
+
+    fn get_edge_by_ends(&self, u: Vertex<Self>, v: Vertex<Self>) -> Option<Edge<Self>> {
+        self.out
+            .get(u as usize)
+            .and_then(|out| out.iter().find(|&&e| self.target(e) == v).cloned())
+    }
+}
+
+impl Adjacency for MixedGraph {
+    fn out_neighbors(&self, v: Vertex<Self>) -> OutNeighborIter<Self> {
+        OutNeighborFromOutEdge::new(self, self.out_edges(v))
+    }
+
+    fn out_degree(&self, v: Vertex<Self>) -> usize {
+        self.out[v as usize].len()
+    }
+}
+
+impl Incidence for MixedGraph {
+    fn out_edges(&self, v: Vertex<Self>) -> OutEdgeIter<Self> {
+        self.out[v as usize].iter().cloned()
+    }
+}
+
+impl<'a> InEdgeTypes<'a, MixedGraph> for MixedGraph {
+    type InEdgeIter = Cloned<slice::Iter<'a, MixedEdge>>;
+}
+
+impl InIncidence for MixedGraph {
+    fn in_edges(&self, v: Vertex<Self>) -> InEdgeIter<Self> {
+        self.in_[v as usize].iter().cloned()
+    }
+
+    fn in_degree(&self, v: Vertex<Self>) -> usize {
+        self.in_[v as usize].len()
+    }
+}
+
+// Iterators
+
+/// An iterator over the edges of a [`MixedGraph`].
+///
+/// [`MixedGraph`]: struct.MixedGraph.html
+#[derive(Clone, Debug)]
+pub struct MixedEdgeIter(Range<usize>);
+
+impl Iterator for MixedEdgeIter {
+    type Item = MixedEdge;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(MixedEdge::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for MixedEdgeIter {}
+
+impl DoubleEndedIterator for MixedEdgeIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(MixedEdge::new)
+    }
+}
+
+// Props
+
+#[derive(Clone, Debug)]
+pub struct MixedEdgeIndexProp;
+
+impl PropGet<MixedEdge> for MixedEdgeIndexProp {
+    type Output = usize;
+
+    fn get(&self, e: MixedEdge) -> usize {
+        e.index as usize
+    }
+}
+
+impl WithVertexIndexProp for MixedGraph {
+    type VertexIndexProp = SVertexIndexProp;
+
+    fn vertex_index(&self) -> VertexIndexProp<Self> {
+        SVertexIndexProp
+    }
+}
+
+impl WithEdgeIndexProp for MixedGraph {
+    type EdgeIndexProp = MixedEdgeIndexProp;
+
+    fn edge_index(&self) -> EdgeIndexProp<Self> {
+        MixedEdgeIndexProp
+    }
+}
+
+impl<T> WithVertexProp<T> for MixedGraph {
+    type VertexProp = VecVertexProp<Self, T>;
+}
+
+impl BasicVertexProps for MixedGraph {}
+
+impl<T> WithEdgeProp<T> for MixedGraph {
+    type EdgeProp = VecEdgeProp<Self, T>;
+}
+
+impl BasicEdgeProps for MixedGraph {}
+
+impl BasicProps for MixedGraph {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::{set, vec};
+
+    fn new() -> MixedGraph {
+        let mut b = MixedGraph::builder(5);
+        b.add_directed_edge(0, 1); // 0
+        b.add_undirected_edge(1, 2); // 1
+        b.add_directed_edge(2, 1); // 2
+        b.add_undirected_edge(3, 3); // 3
+        b.add_directed_edge(3, 3); // 4
+        b.add_undirected_edge(4, 0); // 5
+        b.finalize()
+    }
+
+    #[test]
+    fn basic() {
+        let g = new();
+        let e = vec(g.edges());
+        assert_eq!(5, g.num_vertices());
+        assert_eq!(6, g.num_edges());
+        assert_eq!(
+            vec![true, false, true, false, true, false],
+            vec(e.iter().map(|&e| g.is_directed_edge(e)))
+        );
+        assert!(e
+            .iter()
+            .all(|&e| g.is_undirected_edge(e) != g.is_directed_edge(e)));
+        assert_eq!(
+            vec![(0, 1), (1, 2), (2, 1), (3, 3), (3, 3), (4, 0)],
+            vec(g.edges_ends())
+        );
+        assert_eq!(Some(e[1].reverse()), g.get_reverse(e[1]));
+        assert_eq!(e[1], e[1].reverse());
+        assert_eq!(None, g.get_reverse(e[0]));
+    }
+
+    #[test]
+    fn incidence() {
+        let g = new();
+        let e = vec(g.edges());
+        assert_eq!(set(vec![e[0], e[5]]), set(g.out_edges(0)));
+        assert_eq!(set(vec![e[5]]), set(g.in_edges(0)));
+        assert_eq!(vec![4], vec(g.in_edges(0).map(|e| g.source(e))));
+        assert_eq!(set(vec![1, 4]), set(g.out_neighbors(0)));
+
+        assert_eq!(vec![2], vec(g.out_neighbors(1)));
+        assert_eq!(set(vec![e[0], e[1], e[2]]), set(g.in_edges(1)));
+        assert_eq!(set(vec![1]), set(g.out_neighbors(2)));
+        assert_eq!(2, g.out_degree(2));
+
+        // undirected loop twice, directed loop once
+        assert_eq!(3, g.out_degree(3));
+        assert_eq!(3, g.in_degree(3));
+
+        for v in g.vertices() {
+            assert!(g.out_edges(v).all(|e| g.source(e) == v));
+            assert!(g.in_edges(v).all(|e| g.target(e) == v));
+        }
+        assert_eq!(
+            g.vertices().map(|v| g.out_degree(v)).sum::<usize>(),
+            g.vertices().map(|v| g.in_degree(v)).sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn get_edge_by_ends() {
+        let g = new();
+        let e = vec(g.edges());
+        assert_eq!(Some(e[0]), g.get_edge_by_ends(0, 1));
+        assert_eq!(None, g.get_edge_by_ends(1, 0));
+        assert_eq!(Some(e[5]), g.get_edge_by_ends(0, 4));
+        assert_eq!((0, 4), g.end_vertices(g.edge_by_ends(0, 4)));
+        assert_eq!(set(vec![e[1], e[2]]), set(g.edges_by_ends(2, 1)));
+        assert_eq!(vec![e[1]], g.edges_by_ends(1, 2));
+    }
+
+    #[test]
+    fn props() {
+        let g = new();
+        let mut p = g.default_edge_prop(0u32);
+        for (i, e) in g.edges().enumerate() {
+            p[e] = i as u32;
+        }
+        let e = g.edge_by_ends(2, 1);
+        assert_eq!(1, p[e]);
+        let mut d = g.default_vertex_prop(0usize);
+        for v in g.vertices() {
+            d[v] = g.out_degree(v);
+        }
+        assert_eq!(vec![2, 1, 2, 3, 1], vec(g.vertices().map(|v| d[v])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_vertex() {
+        MixedGraph::builder(2).add_directed_edge(0, 2);
+    }
+}
@@ -5,8 +5,11 @@
 //! Graph traits and implementations.
 
 pub mod adaptors;
+pub mod adjmatrix;
 pub mod adjset;
 pub mod complete;
+pub mod list;
+pub mod mixed;
 pub mod static_;
 
 mod common;
@@ -32,6 +35,7 @@ pub type OptionEdge<G> = <G as WithEdge>::OptionEdge;
 pub type EdgeIndexProp<G> = <G as WithEdgeIndexProp>::EdgeIndexProp;
 pub type EdgeIter<'a, G> = <G as EdgeTypes<'a, G>>::EdgeIter;
 pub type OutEdgeIter<'a, G> = <G as EdgeTypes<'a, G>>::OutEdgeIter;
+pub type InEdgeIter<'a, G> = <G as InEdgeTypes<'a, G>>::InEdgeIter;
 pub type DefaultEdgePropMut<G, T> = <G as WithEdgeProp<T>>::EdgeProp;
 
 macro_rules! items {
@@ -115,7 +119,9 @@ impl UniformEdgeKind for Undirected {
     }
 }
 
-// TODO: write a graph with mixed edges and test it
+/// The kind of graphs with directed and undirected edges. See [`MixedGraph`].
+///
+/// [`MixedGraph`]: mixed/struct.MixedGraph.html
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum Mixed {}
 
@@ -180,6 +186,16 @@ pub trait WithEdge: Sized + WithVertex + for<'a> EdgeTypes<'a, Self> {
 
     fn target(&self, e: Edge<Self>) -> Vertex<Self>;
 
+    /// Returns `true` if `e` is a directed edge, otherwise `false`.
+    fn is_directed_edge(&self, e: Edge<Self>) -> bool {
+        self.orientation(e).is_directed()
+    }
+
+    /// Returns `true` if `e` is an undirected edge, otherwise `false`.
+    fn is_undirected_edge(&self, e: Edge<Self>) -> bool {
+        self.orientation(e).is_undirected()
+    }
+
     fn ends<'a, I, O>(&'a self, item: I) -> O
     where
         I: Ends<'a, Self, O>,
@@ -308,6 +324,21 @@ pub trait EdgeList: Sized + WithEdge {
         None
     }
 
+    /// Returns all edges with source `u` and target `v`. In undirected graphs, the returned edges
+    /// are oriented from `u` to `v` and each loop is returned once.
+    fn edges_by_ends(&self, u: Vertex<Self>, v: Vertex<Self>) -> Vec<Edge<Self>> {
+        // TODO: specialize for Incidence
+        let mut edges = vec![];
+        for (e, a, b) in self.edges_with_ends() {
+            if (u, v) == (a, b) {
+                edges.push(e);
+            } else if (u, v) == (b, a) && self.orientation(e).is_undirected() {
+                edges.extend(self.get_reverse(e));
+            }
+        }
+        edges
+    }
+
     fn edge_by_ends(&self, u: Vertex<Self>, v: Vertex<Self>) -> Edge<Self> {
         // TODO: fix expect message
         self.get_edge_by_ends(u, v).expect("an edge (u, v)")
@@ -336,6 +367,23 @@ pub trait Incidence: WithEdge + Adjacency {
     }
 }
 
+pub trait InEdgeTypes<'a, G: WithEdge> {
+    type InEdgeIter: Iterator<Item = Edge<G>>;
+}
+
+/// A graph that can iterate over the edges that have a vertex as target.
+///
+/// In an undirected graph the in edges of a vertex are the same as its out edges, so `in_edges(v)`
+/// produces the same edges as `out_edges(v)` and `in_degree(v) == out_degree(v)`. In a directed
+/// graph a self loop is both an in and an out edge of its vertex.
+pub trait InIncidence: Incidence + for<'a> InEdgeTypes<'a, Self> {
+    fn in_edges(&self, v: Vertex<Self>) -> InEdgeIter<Self>;
+
+    fn in_degree(&self, v: Vertex<Self>) -> usize {
+        self.in_edges(v).count()
+    }
+}
+
 // Ends
 
 pub trait Ends<'a, G, O> {
@@ -2,6 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use builder::BuilderError;
 use choose::Choose;
 use graphs::common::OutNeighborFromOutEdge;
 use prelude::*;
@@ -29,7 +30,24 @@ pub type StaticGraph = Static<u32, (Undirected, usize)>;
 
 pub trait StaticEdgeKind: 'static {
     type Kind: UniformEdgeKind; // TODO: change to EdgeKind
-    type Edge: 'static + GraphItem + Bounded + EdgeImpl;
+    type Edge: 'static + GraphItem + Bounded + EdgeImpl + StaticEdge;
+}
+
+/// The edges of a [`Static`] graph.
+///
+/// An undirected edge `e` and its reverse `g.reverse(e)` are distinct handles to the same edge:
+/// they are equal and have the same index, but their source and target are swapped.
+///
+/// [`Static`]: struct.Static.html
+pub trait StaticEdge: Copy {
+    /// Returns the index of the edge, which is the same for an undirected edge and its reverse.
+    /// This is the value returned by the `edge_index` property, so it is in the range
+    /// `0..g.num_edges()` and can be used to index external arrays.
+    fn undirected_index(self) -> usize;
+
+    /// Returns `true` if the edge has the orientation in which it was added to the graph or
+    /// `false` if it is the reversed orientation. Directed edges are always forward.
+    fn is_forward(self) -> bool;
 }
 
 #[doc(hidden)]
@@ -92,6 +110,16 @@ impl<N: Num> EdgeImpl for StaticDirectedEdge<N> {
     }
 }
 
+impl<N: Num> StaticEdge for StaticDirectedEdge<N> {
+    fn undirected_index(self) -> usize {
+        self.to_index()
+    }
+
+    fn is_forward(self) -> bool {
+        true
+    }
+}
+
 // StaticUndirectedEdge
 
 #[derive(Copy, Clone, Debug, Eq)]
@@ -112,7 +140,10 @@ impl<N: Num> Bounded for StaticUndirectedEdge<N> {
     }
 }
 
-// TODO: Document the representation of StaticUndirectedEdge
+// The i-th undirected edge (u, v) is stored as u = ends[2 * i] and v = ends[2 * i + 1]. The
+// handle of the edge is 2 * i + 1 (the position of the target), and the handle of its reverse is
+// 2 * i (xor 1), so the source is at position handle ^ 1. Both handles have the same index and are
+// equal.
 impl<N: Num> EdgeImpl for StaticUndirectedEdge<N> {
     fn new(e: usize) -> Self {
         StaticUndirectedEdge(N::from_usize(2 * e + 1))
@@ -147,6 +178,16 @@ impl<N: Num> EdgeImpl for StaticUndirectedEdge<N> {
     }
 }
 
+impl<N: Num> StaticEdge for StaticUndirectedEdge<N> {
+    fn undirected_index(self) -> usize {
+        self.to_index()
+    }
+
+    fn is_forward(self) -> bool {
+        N::to_usize(self.0) & 1 == 1
+    }
+}
+
 impl<N: Num> PartialEq for StaticUndirectedEdge<N> {
     fn eq(&self, other: &Self) -> bool {
         self.to_index() == other.to_index()
@@ -186,13 +227,49 @@ pub struct Static<V: Num, K: StaticEdgeKind> {
     ends: Vec<StaticVertex<V>>,
     edges: Vec<K::Edge>,
     edges_start: Vec<usize>,
+    // The in edges are only stored for directed graphs
+    in_edges: Vec<K::Edge>,
+    in_edges_start: Vec<usize>,
 }
 
 impl<V: Num, K: StaticEdgeKind> Static<V, K> {
+    /// Returns the index of `e` and `true` if `e` has the orientation in which it was added to the
+    /// graph or `false` if it is the reversed orientation.
+    ///
+    /// An undirected edge and its reverse have the same index, so the index can be used to store
+    /// data associated with the edge in external arrays while the orientation distinguishes the
+    /// two directions (for example, to store the residual capacities of a flow network).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(3, (0, 1), (2, 1));
+    /// let e = g.edge_by_ends(1, 2);
+    /// assert_eq!((1, false), g.edge_index_and_orientation(e));
+    /// assert_eq!((1, true), g.edge_index_and_orientation(g.reverse(e)));
+    /// # }
+    /// ```
+    pub fn edge_index_and_orientation(&self, e: Edge<Self>) -> (usize, bool) {
+        (e.undirected_index(), e.is_forward())
+    }
+
     fn inc(&self, v: Vertex<Self>) -> &[Edge<Self>] {
         self.get_inc(v).unwrap()
     }
 
+    fn in_inc(&self, v: Vertex<Self>) -> &[Edge<Self>] {
+        if K::Kind::is_undirected() {
+            self.inc(v)
+        } else {
+            let i = V::to_usize(v);
+            &self.in_edges[self.in_edges_start[i]..self.in_edges_start[i + 1]]
+        }
+    }
+
     fn get_inc(&self, v: Vertex<Self>) -> Option<&[Edge<Self>]> {
         let i = V::to_usize(v);
         self.edges.get(self.edges_start[i]..self.edges_start[i + 1])
@@ -203,63 +280,232 @@ impl<V: Num, K: StaticEdgeKind> WithBuilder for Static<V, K> {
     type Builder = StaticBuilder<V, K>;
 }
 
+// StaticGraph or StaticDigraph, depending on the kind of G
+type StaticOf<G> = Static<u32, (<G as WithEdge>::Kind, usize)>;
+
+/// Creates a [`StaticGraph`] (or a [`StaticDigraph`] if `g` is directed) with the same structure as
+/// `g`. This is useful to get a compact and fast representation of a graph that was built
+/// dynamically or of a graph adaptor.
+///
+/// Returns the new graph and the properties that map each vertex and edge of `g` to the
+/// corresponding vertex and edge of the new graph. The vertices and edges are added in the order
+/// of `g.vertices()` and `g.edges()`.
+///
+/// # Examples
+///
+/// ```
+/// use fera_graph::prelude::*;
+/// use fera_graph::graphs::static_::to_static;
+///
+/// let mut g = ListGraph::new();
+/// let a = g.add_vertex();
+/// let b = g.add_vertex();
+/// let e = g.add_edge(a, b);
+///
+/// let (h, vmap, emap): (StaticGraph, _, _) = to_static(&g);
+/// assert_eq!(2, h.num_vertices());
+/// assert_eq!((vmap[a], vmap[b]), h.ends(emap[e]));
+/// ```
+///
+/// [`StaticGraph`]: type.StaticGraph.html
+/// [`StaticDigraph`]: type.StaticDigraph.html
+pub fn to_static<G>(
+    g: &G,
+) -> (
+    StaticOf<G>,
+    DefaultVertexPropMut<G, u32>,
+    DefaultEdgePropMut<G, Edge<StaticOf<G>>>,
+)
+where
+    G: VertexList + EdgeList + WithVertexProp<u32> + WithEdgeProp<Edge<StaticOf<G>>>,
+    (<G as WithEdge>::Kind, usize): StaticEdgeKind,
+{
+    let mut index = g.default_vertex_prop(0u32);
+    for (i, v) in g.vertices().enumerate() {
+        index[v] = i as u32;
+    }
+    let mut b = StaticOf::<G>::builder(g.num_vertices(), g.num_edges());
+    for (u, v) in g.edges_ends() {
+        b.add_edge(index[u] as usize, index[v] as usize);
+    }
+    let (h, vertices, edges) = b.finalize_();
+    let mut vmap = index;
+    for v in g.vertices() {
+        vmap[v] = vertices[vmap[v] as usize];
+    }
+    let mut emap = g.default_edge_prop(Bounded::max_value());
+    for (e, f) in g.edges().zip(edges) {
+        emap[e] = f;
+    }
+    (h, vmap, emap)
+}
+
+/// Creates a [`StaticGraph`] (or a [`StaticDigraph`] if `g` is directed) with the vertices of `g`
+/// accepted by `keep_vertex` and the edges of `g` accepted by `keep_edge` whose ends are both
+/// accepted. This is the persistent equivalent of the lazy [`FilteredGraph`] view, and is useful
+/// when the filtered graph is queried many times.
+///
+/// Returns the new graph and the properties that map each vertex and edge of `g` to the
+/// corresponding vertex and edge of the new graph, or to `None` if the item was removed. The
+/// vertices and edges are added in the order of `g.vertices()` and `g.edges()`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::graphs::static_::filtered_copy;
+/// use fera_graph::props::FnProp;
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (3, 0));
+/// let (h, vmap, emap) = filtered_copy(&g, FnProp(|v| v != 2), FnProp(|_| true));
+/// assert_eq!(3, h.num_vertices());
+/// assert_eq!(2, h.num_edges());
+/// assert_eq!(None, vmap[2u32].into_option());
+/// assert_eq!(Some(2), vmap[3u32].into_option());
+/// assert_eq!(None, emap[g.edge_by_ends(1, 2)].into_option());
+/// # }
+/// ```
+///
+/// [`StaticGraph`]: type.StaticGraph.html
+/// [`StaticDigraph`]: type.StaticDigraph.html
+/// [`FilteredGraph`]: ../adaptors/struct.FilteredGraph.html
+pub fn filtered_copy<G, VP, EP>(
+    g: &G,
+    keep_vertex: VP,
+    keep_edge: EP,
+) -> (
+    StaticOf<G>,
+    DefaultVertexPropMut<G, OptionVertex<StaticOf<G>>>,
+    DefaultEdgePropMut<G, OptionEdge<StaticOf<G>>>,
+)
+where
+    G: VertexList + EdgeList + WithVertexProp<OptionVertex<StaticOf<G>>>,
+    G: WithEdgeProp<OptionEdge<StaticOf<G>>>,
+    (<G as WithEdge>::Kind, usize): StaticEdgeKind,
+    VP: VertexPropGet<G, bool>,
+    EP: EdgePropGet<G, bool>,
+{
+    // the vertices are first mapped to their index in the new graph
+    let mut vmap = g.default_vertex_prop(StaticOf::<G>::vertex_none());
+    let mut n = 0;
+    for v in g.vertices().filter(|&v| keep_vertex.get(v)) {
+        vmap[v] = StaticOf::<G>::vertex_some(n);
+        n += 1;
+    }
+    let edges: Vec<_> = g
+        .edges_with_ends()
+        .filter_map(|(e, u, v)| match (vmap[u].into_option(), vmap[v].into_option()) {
+            (Some(u), Some(v)) if keep_edge.get(e) => Some((e, u as usize, v as usize)),
+            _ => None,
+        }).collect();
+    let mut b = StaticOf::<G>::builder(n as usize, edges.len());
+    for &(_, u, v) in &edges {
+        b.add_edge(u, v);
+    }
+    let (h, new_vertices, new_edges) = b.finalize_();
+    for v in g.vertices() {
+        if let Some(i) = vmap[v].into_option() {
+            vmap[v] = StaticOf::<G>::vertex_some(new_vertices[i as usize]);
+        }
+    }
+    let mut emap = g.default_edge_prop(StaticOf::<G>::edge_none());
+    for (&(e, _, _), f) in edges.iter().zip(new_edges) {
+        emap[e] = StaticOf::<G>::edge_some(f);
+    }
+    (h, vmap, emap)
+}
+
+/// The builder of [`Static`] graphs.
+///
+/// The number of vertices and edges is limited by the vertex and edge types. The largest value of
+/// each type is reserved, so `Static<u8, (Directed, u8)>` supports at most 254 vertices and 255
+/// edges (an undirected edge uses two values, so `Static<u8, (Undirected, u8)>` supports at most
+/// 127 edges). Exceeding the limits makes `finalize` panic and `try_finalize` return an error.
+///
+/// [`Static`]: struct.Static.html
 pub struct StaticBuilder<V: Num, K: StaticEdgeKind> {
     num_vertices: usize,
     ends: Vec<StaticVertex<V>>,
     edges: Vec<K::Edge>,
+    error: Option<BuilderError>,
 }
 
 impl<V: Num, K: StaticEdgeKind> Builder for StaticBuilder<V, K> {
     type Graph = Static<V, K>;
 
     fn new(num_vertices: usize, num_edges: usize) -> Self {
-        assert!(V::is_valid(num_vertices));
+        let error = if V::is_valid(num_vertices) {
+            None
+        } else {
+            Some(BuilderError::TooManyVertices {
+                max: V::to_usize(V::max_value()) - 1,
+            })
+        };
+        let edges_per_edge = if K::Kind::is_undirected() { 2 } else { 1 };
         StaticBuilder {
             num_vertices: num_vertices,
             ends: Vec::with_capacity(2 * num_edges),
-            edges: vec![],
+            edges: Vec::with_capacity(edges_per_edge * num_edges),
+            error,
         }
     }
 
     fn add_edge(&mut self, u: usize, v: usize) {
+        if self.error.is_some() {
+            return;
+        }
+        let i = self.ends.len() / 2;
+        let e = match K::Edge::new_checked(i) {
+            Some(e) => e,
+            None => {
+                self.error = Some(BuilderError::TooManyEdges { max: i });
+                return;
+            }
+        };
         self.ends.push(V::from_usize(u));
         self.ends.push(V::from_usize(v));
-        let e = K::Edge::new_checked((self.ends.len() - 2) / 2).expect("too many edges");
         self.edges.push(e);
         if K::Kind::is_undirected() {
             self.edges.push(e.reverse());
         }
     }
 
-    fn finalize(mut self) -> Self::Graph {
-        // TODO: improve test
+    fn finalize(self) -> Self::Graph {
+        match self.try_finalize() {
+            Ok(g) => g,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn try_finalize(mut self) -> Result<Self::Graph, BuilderError> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+        let n = self.num_vertices;
         let ends = self.ends;
         self.edges
             .sort_by_key(|e| (e.source(&ends), e.target(&ends)));
+        let starts = edges_start::<V, _, _>(n, &self.edges, |e| *e.source(&ends));
 
-        let mut starts = Vec::with_capacity(self.num_vertices.checked_add(1).unwrap());
-        let mut last = V::from_usize(self.num_vertices);
-        for (i, e) in self.edges.iter().enumerate() {
-            let s = *e.source(&ends);
-            if s != last {
-                while starts.len() != V::to_usize(s) {
-                    starts.push(i)
-                }
-                assert_eq!(starts.len(), V::to_usize(s));
-                starts.push(i);
-                last = s;
-            }
-        }
-        while starts.len() <= self.num_vertices {
-            starts.push(self.edges.len());
-        }
+        let (in_edges, in_starts) = if K::Kind::is_undirected() {
+            (vec![], vec![])
+        } else {
+            let mut in_edges = self.edges.clone();
+            in_edges.sort_by_key(|e| (e.target(&ends), e.source(&ends)));
+            let in_starts = edges_start::<V, _, _>(n, &in_edges, |e| *e.target(&ends));
+            (in_edges, in_starts)
+        };
 
-        Static {
-            num_vertices: self.num_vertices,
+        Ok(Static {
+            num_vertices: n,
             ends: ends,
             edges: self.edges,
             edges_start: starts,
-        }
+            in_edges,
+            in_edges_start: in_starts,
+        })
     }
 
     fn finalize_(
@@ -276,6 +522,32 @@ impl<V: Num, K: StaticEdgeKind> Builder for StaticBuilder<V, K> {
     }
 }
 
+// Returns the position in `edges` of the first edge of each vertex, followed by `edges.len()`.
+// `edges` must be sorted by `key`.
+fn edges_start<V, E, F>(num_vertices: usize, edges: &[E], key: F) -> Vec<usize>
+where
+    V: Num,
+    F: Fn(&E) -> V,
+{
+    let mut starts = Vec::with_capacity(num_vertices.checked_add(1).unwrap());
+    let mut last = V::from_usize(num_vertices);
+    for (i, e) in edges.iter().enumerate() {
+        let s = key(e);
+        if s != last {
+            while starts.len() != V::to_usize(s) {
+                starts.push(i)
+            }
+            assert_eq!(starts.len(), V::to_usize(s));
+            starts.push(i);
+            last = s;
+        }
+    }
+    while starts.len() <= num_vertices {
+        starts.push(edges.len());
+    }
+    starts
+}
+
 // Graph implementation
 
 impl<V: Num, K: StaticEdgeKind> WithVertex for Static<V, K> {
@@ -336,7 +608,8 @@ impl<V: Num, K: StaticEdgeKind> EdgeList for Static<V, K> {
     }
 
     fn edges(&self) -> EdgeIter<Self> {
-        // TODO: specialization undirect 1, 3, 5, ...
+        // K::Edge::new(i) creates the handle of the i-th edge with the ends in the order they
+        // were added to the builder (for undirected edges, the handle 2 * i + 1)
         SEdgeIter(0..self.num_edges(), PhantomData)
     }
 
@@ -348,6 +621,31 @@ impl<V: Num, K: StaticEdgeKind> EdgeList for Static<V, K> {
                 .map(|i| out_edges[i])
         })
     }
+
+    fn edges_by_ends(&self, u: Vertex<Self>, v: Vertex<Self>) -> Vec<Edge<Self>> {
+        let mut edges = vec![];
+        let out_edges = match self.get_inc(u) {
+            Some(out_edges) => out_edges,
+            None => return edges,
+        };
+        if let Ok(i) = out_edges.binary_search_by_key(&v, |e| self.target(*e)) {
+            let mut start = i;
+            while start > 0 && self.target(out_edges[start - 1]) == v {
+                start -= 1;
+            }
+            let mut end = i + 1;
+            while end < out_edges.len() && self.target(out_edges[end]) == v {
+                end += 1;
+            }
+            for &e in &out_edges[start..end] {
+                // an undirected loop appears twice in inc(u), once in each direction
+                if u != v || K::Kind::is_directed() || !edges.contains(&e) {
+                    edges.push(e);
+                }
+            }
+        }
+        edges
+    }
 }
 
 impl<V: Num, K: StaticEdgeKind> Adjacency for Static<V, K> {
@@ -366,6 +664,20 @@ impl<V: Num, K: StaticEdgeKind> Incidence for Static<V, K> {
     }
 }
 
+impl<'a, V: Num, K: StaticEdgeKind> InEdgeTypes<'a, Static<V, K>> for Static<V, K> {
+    type InEdgeIter = Cloned<Iter<'a, Edge<Self>>>;
+}
+
+impl<V: Num, K: StaticEdgeKind> InIncidence for Static<V, K> {
+    fn in_edges(&self, v: Vertex<Self>) -> InEdgeIter<Self> {
+        self.in_inc(v).iter().cloned()
+    }
+
+    fn in_degree(&self, v: Vertex<Self>) -> usize {
+        self.in_inc(v).len()
+    }
+}
+
 // Iter
 
 #[derive(Clone, Debug)]
@@ -385,6 +697,12 @@ impl<K: StaticEdgeKind> Iterator for SEdgeIter<K> {
 
 impl<K: StaticEdgeKind> ExactSizeIterator for SEdgeIter<K> {}
 
+impl<K: StaticEdgeKind> DoubleEndedIterator for SEdgeIter<K> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(K::Edge::new)
+    }
+}
+
 // Props
 
 #[derive(Clone, Debug)]
@@ -481,7 +799,7 @@ impl<V: Num, K: StaticEdgeKind> Choose for Static<V, K> {
 // Num
 
 pub trait Num: 'static + Eq + Copy + Clone + Debug + Hash + Bounded + Ord {
-    type Range: Iterator<Item = Self>;
+    type Range: Iterator<Item = Self> + DoubleEndedIterator;
     fn range(a: usize, b: usize) -> Self::Range;
     fn to_usize(self) -> usize;
     fn from_usize(v: usize) -> Self;
@@ -526,7 +844,7 @@ impl_num!(usize);
 
 #[cfg(test)]
 mod tests {
-    pub use super::{EdgeImpl, StaticDigraph, StaticGraph, StaticUndirectedEdge};
+    pub use super::{EdgeImpl, StaticDigraph, StaticGraph};
     pub use prelude::*;
     use tests::GraphTests;
 
@@ -565,4 +883,329 @@ mod tests {
 
     test!(directed, StaticDigraph);
     test!(undirected, StaticGraph);
+
+    mod to_static {
+        use super::*;
+        use algs::Components;
+        use fera_fun::set;
+        use graphs::static_::{filtered_copy, to_static};
+        use props::FnProp;
+
+        #[test]
+        fn induced_subgraph() {
+            let g: StaticGraph = graph!(6, (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (1, 4), (2, 2));
+            let sub = g.induced_subgraph(vec![1, 2, 4, 5]);
+            let (h, vmap, emap) = to_static(&sub);
+            assert_eq!(4, h.num_vertices());
+            assert_eq!(4, h.num_edges());
+            let mut seen = h.default_vertex_prop(false);
+            for v in sub.vertices() {
+                assert!(!seen[vmap[v]]);
+                seen[vmap[v]] = true;
+            }
+            for e in sub.edges() {
+                let (u, v) = sub.ends(e);
+                assert_eq!((vmap[u], vmap[v]), h.ends(emap[e]));
+            }
+        }
+
+        #[test]
+        fn filtered_component() {
+            let g: StaticGraph = graph!(7, (0, 1), (1, 2), (3, 4), (4, 5), (5, 3), (5, 6), (1, 1));
+            let cc = g.connected_components();
+            let comp = cc.component(4);
+            let keep = FnProp(|v| cc.component(v) == comp);
+            let (h, vmap, emap) = filtered_copy(&g, keep, FnProp(|_| true));
+            let sub = g.induced_subgraph(g.vertices().filter(|&v| keep.get(v)));
+            let (expected, _, _) = to_static(&sub);
+            let ends = |g: &StaticGraph| set(g.edges_ends().map(|(u, v)| (u.min(v), u.max(v))));
+            assert_eq!(expected.num_vertices(), h.num_vertices());
+            assert_eq!(ends(&expected), ends(&h));
+            for v in g.vertices() {
+                assert_eq!(keep.get(v), vmap[v].into_option().is_some());
+            }
+            for (e, u, v) in g.edges_with_ends() {
+                match emap[e].into_option() {
+                    Some(f) => assert_eq!(
+                        (vmap[u].into_option().unwrap(), vmap[v].into_option().unwrap()),
+                        h.ends(f)
+                    ),
+                    None => assert!(!keep.get(u)),
+                }
+            }
+
+            // removing an edge
+            let e = g.edge_by_ends(3, 4);
+            let (h, _, emap) = filtered_copy(&g, keep, FnProp(|f| f != e));
+            assert_eq!(3, h.num_edges());
+            assert!(emap[e].into_option().is_none());
+        }
+
+        #[test]
+        fn directed() {
+            let mut g = ListDigraph::new();
+            let v: Vec<_> = (0..3).map(|_| g.add_vertex()).collect();
+            let e = [g.add_edge(v[2], v[0]), g.add_edge(v[0], v[1])];
+            let (h, vmap, emap): (StaticDigraph, _, _) = to_static(&g);
+            assert_eq!(3, h.num_vertices());
+            assert_eq!(2, h.num_edges());
+            assert_eq!((vmap[v[2]], vmap[v[0]]), h.ends(emap[e[0]]));
+            assert_eq!((vmap[v[0]], vmap[v[1]]), h.ends(emap[e[1]]));
+            assert!(h.get_edge_by_ends(vmap[v[0]], vmap[v[2]]).is_none());
+        }
+    }
+
+    mod orientation {
+        use super::*;
+        use graphs::static_::StaticEdge;
+
+        #[test]
+        fn undirected() {
+            let g: StaticGraph = graph!(4, (0, 1), (1, 2), (3, 2), (2, 2));
+            for (i, e) in g.edges().enumerate() {
+                let r = g.reverse(e);
+                assert_eq!((i, true), g.edge_index_and_orientation(e));
+                assert_eq!((i, false), g.edge_index_and_orientation(r));
+                assert_eq!(e.undirected_index(), r.undirected_index());
+                assert_eq!(g.edge_index().get(e), e.undirected_index());
+                assert!(e.is_forward() && !r.is_forward());
+            }
+            for v in g.vertices() {
+                for e in g.out_edges(v) {
+                    let (i, forward) = g.edge_index_and_orientation(e);
+                    let (u, w) = g.edges_ends().nth(i).unwrap();
+                    if u != w {
+                        assert_eq!(forward, (u, w) == g.ends(e));
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn directed() {
+            let g: StaticDigraph = graph!(3, (0, 1), (1, 0), (2, 1));
+            for (i, e) in g.edges().enumerate() {
+                assert_eq!((i, true), g.edge_index_and_orientation(e));
+            }
+        }
+    }
+
+    mod in_incidence {
+        use super::*;
+        use fera_fun::{set, vec};
+
+        #[test]
+        fn in_edges_directed() {
+            let g: StaticDigraph = graph!(4, (0, 1), (1, 2), (2, 1), (3, 1), (1, 1), (1, 3));
+            let e = vec(g.edges());
+            assert_eq!(set(vec![e[0], e[2], e[3], e[4]]), set(g.in_edges(1)));
+            assert_eq!(set(vec![e[1], e[4], e[5]]), set(g.out_edges(1)));
+            assert_eq!(4, g.in_degree(1));
+            assert_eq!(3, g.out_degree(1));
+            assert_eq!(0, g.in_degree(0));
+            assert_eq!(1, g.out_degree(0));
+            assert!(g.in_edges(1).all(|e| g.target(e) == 1));
+            let self_loops = g.out_edges(1).filter(|&e| g.target(e) == 1).count();
+            assert_eq!(1, self_loops);
+            // the degree of 1 in the underlying undirected graph
+            assert_eq!(6, g.in_degree(1) + g.out_degree(1) - self_loops);
+            assert_eq!(
+                g.num_edges(),
+                g.vertices().map(|v| g.in_degree(v)).sum::<usize>()
+            );
+        }
+
+        #[test]
+        fn in_edges_undirected() {
+            let g: StaticGraph = graph!(3, (0, 1), (1, 2), (2, 2));
+            for v in g.vertices() {
+                assert_eq!(vec(g.out_edges(v)), vec(g.in_edges(v)));
+                assert_eq!(g.out_degree(v), g.in_degree(v));
+            }
+        }
+    }
+
+    mod iters {
+        use super::*;
+        use fera_fun::vec;
+
+        #[test]
+        fn len_and_rev() {
+            let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (3, 0), (1, 3));
+            assert_eq!(4, g.vertices().len());
+            assert_eq!(5, g.edges().len());
+            assert_eq!(vec![3, 2, 1, 0], vec(g.vertices().rev()));
+            let mut edges = vec(g.edges());
+            edges.reverse();
+            assert_eq!(edges, vec(g.edges().rev()));
+
+            let mut iter = g.edges();
+            iter.next();
+            iter.next_back();
+            assert_eq!(3, iter.len());
+            assert_eq!(vec(g.edges())[1..4].to_vec(), vec(iter));
+        }
+
+        #[test]
+        fn edges_in_build_order() {
+            let edges = vec![(0, 1), (2, 1), (3, 3), (3, 0), (1, 0)];
+            let g = StaticGraph::new_with_edges(4, edges.iter().cloned());
+            assert_eq!(edges, vec(g.edges_ends().map(|(u, v)| (u as usize, v as usize))));
+            for e in g.edges() {
+                let (u, v) = g.end_vertices(e);
+                let r = g.reverse(e);
+                assert_eq!((v, u), g.end_vertices(r));
+                assert_eq!((u, v), g.end_vertices(g.reverse(r)));
+                // e and its reverse are the same edge
+                assert_eq!(e, r);
+                assert!(g.out_edges(u).any(|f| g.end_vertices(f) == (u, v)));
+                assert!(g.out_edges(v).any(|f| g.end_vertices(f) == (v, u)));
+            }
+        }
+
+        #[test]
+        fn len_and_rev_digraph() {
+            let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (2, 1));
+            assert_eq!(3, g.vertices().len());
+            assert_eq!(3, g.edges().len());
+            assert_eq!(vec![2, 1, 0], vec(g.vertices().rev()));
+            assert_eq!(vec![(2, 1), (1, 2), (0, 1)], vec(g.edges().rev().map(|e| g.ends(e))));
+        }
+    }
+
+    mod edges_by_ends {
+        use super::*;
+        use fera_fun::{set, vec};
+
+        fn check<G: VertexList + EdgeList>(g: &G) {
+            for u in g.vertices() {
+                for v in g.vertices() {
+                    let edges = g.edges_by_ends(u, v);
+                    let expected = g
+                        .edges_with_ends()
+                        .filter(|&(e, a, b)| {
+                            (a, b) == (u, v) || (b, a) == (u, v) && g.orientation(e).is_undirected()
+                        })
+                        .count();
+                    assert_eq!(expected, edges.len());
+                    assert_eq!(set(edges.clone()).len(), edges.len());
+                    assert!(edges.iter().all(|&e| g.end_vertices(e) == (u, v)));
+                    assert_eq!(edges.is_empty(), g.get_edge_by_ends(u, v).is_none());
+                }
+            }
+        }
+
+        #[test]
+        fn undirected() {
+            let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 1), (1, 1), (3, 1), (1, 1), (2, 2));
+            let e = vec(g.edges());
+            assert_eq!(set(vec![e[1], e[2]]), set(g.edges_by_ends(1, 2)));
+            assert_eq!(set(vec![e[1], e[2]]), set(g.edges_by_ends(2, 1)));
+            assert_eq!(set(vec![e[3], e[5]]), set(g.edges_by_ends(1, 1)));
+            assert_eq!(vec![e[6]], g.edges_by_ends(2, 2));
+            assert_eq!(Vec::<Edge<StaticGraph>>::new(), g.edges_by_ends(0, 2));
+            check(&g);
+        }
+
+        #[test]
+        fn directed() {
+            let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (1, 2), (2, 1), (1, 1), (1, 1));
+            let e = vec(g.edges());
+            assert_eq!(set(vec![e[1], e[2]]), set(g.edges_by_ends(1, 2)));
+            assert_eq!(vec![e[3]], g.edges_by_ends(2, 1));
+            assert_eq!(set(vec![e[4], e[5]]), set(g.edges_by_ends(1, 1)));
+            assert!(g.edges_by_ends(1, 0).is_empty());
+            check(&g);
+        }
+    }
+
+    mod builder {
+        use super::*;
+        use fera_fun::vec;
+
+        #[test]
+        fn from_edges_new_with_edges_and_add_edges() {
+            let edges = vec![(0, 1), (1, 2), (2, 3), (3, 0), (1, 3), (2, 2)];
+            let mut b = StaticGraph::builder(4, edges.len());
+            for &(u, v) in &edges {
+                b.add_edge(u, v);
+            }
+            let g = b.finalize();
+
+            let h = StaticGraph::new_with_edges(4, edges.iter().cloned());
+            assert_eq!(vec(g.edges_ends()), vec(h.edges_ends()));
+
+            let h = StaticGraph::from_edges(4, edges.iter().cloned());
+            assert_eq!(g.num_vertices(), h.num_vertices());
+            assert_eq!(vec(g.edges_ends()), vec(h.edges_ends()));
+
+            // without an upper bound in the size hint
+            let h = StaticGraph::new_with_edges(4, edges.iter().cloned().filter(|_| true));
+            assert_eq!(vec(g.edges_ends()), vec(h.edges_ends()));
+
+            let mut b = StaticGraph::builder(4, 0);
+            b.add_edges(edges[..2].iter().cloned());
+            b.add_edges(edges[2..].iter().cloned());
+            let h = b.finalize();
+            assert_eq!(vec(g.edges_ends()), vec(h.edges_ends()));
+        }
+    }
+
+    mod capacity {
+        use super::*;
+        use builder::BuilderError;
+        use graphs::static_::Static;
+
+        type G8 = Static<u8, (Undirected, u8)>;
+        type D8 = Static<u8, (Directed, u8)>;
+
+        fn build<G: WithBuilder>(n: usize, m: usize) -> Result<G, BuilderError> {
+            let mut b = G::builder(n, m);
+            for i in 0..m {
+                b.add_edge(i % n, (i + 1) % n);
+            }
+            b.try_finalize()
+        }
+
+        #[test]
+        fn vertices() {
+            let g = build::<G8>(254, 10).unwrap();
+            assert_eq!(254, g.num_vertices());
+            assert_eq!(Some(253), g.vertices().last().map(|v| v as usize));
+            assert_eq!(
+                Err(BuilderError::TooManyVertices { max: 254 }),
+                build::<G8>(255, 10).map(|g| g.num_vertices())
+            );
+        }
+
+        #[test]
+        fn edges() {
+            assert_eq!(127, build::<G8>(10, 127).unwrap().num_edges());
+            assert_eq!(
+                Err(BuilderError::TooManyEdges { max: 127 }),
+                build::<G8>(10, 128).map(|g| g.num_edges())
+            );
+            assert_eq!(255, build::<D8>(10, 255).unwrap().num_edges());
+            assert_eq!(
+                Err(BuilderError::TooManyEdges { max: 255 }),
+                build::<D8>(10, 300).map(|g| g.num_edges())
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "the graph supports at most 254 vertices")]
+        fn finalize_too_many_vertices() {
+            G8::builder(255, 0).finalize();
+        }
+
+        #[test]
+        #[should_panic(expected = "the graph supports at most 127 edges")]
+        fn finalize_too_many_edges() {
+            let mut b = G8::builder(10, 128);
+            for _ in 0..128 {
+                b.add_edge(0, 1);
+            }
+            b.finalize();
+        }
+    }
 }
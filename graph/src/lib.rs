@@ -13,6 +13,9 @@ extern crate itertools;
 #[cfg(feature = "quickcheck")]
 extern crate quickcheck;
 
+#[cfg(feature = "rayon")]
+extern crate rayon;
+
 extern crate fera_ext;
 extern crate fera_fun;
 extern crate fera_optional;
@@ -41,6 +44,7 @@ pub mod traverse;
 pub mod arbitrary;
 pub mod choose;
 pub mod ext;
+pub mod named;
 pub mod sets;
 pub mod unionfind;
 
@@ -52,16 +56,21 @@ pub mod prelude {
     pub use builder::{Builder, WithBuilder};
     pub use ext::{GraphsSliceExt, GraphsVecExt};
     pub use fera_optional::Optional;
-    pub use graphs::adaptors::{SpanningSubgraph, Subgraph, WithSubgraph};
+    pub use graphs::adaptors::{
+        FilteredGraph, ReverseGraph, SpanningSubgraph, Subgraph, WithFilter, WithReverse,
+        WithSubgraph,
+    };
     pub use graphs::adjset::{AdjSetDigraph, AdjSetGraph};
     pub use graphs::complete::{CompleteDigraph, CompleteGraph};
+    pub use graphs::list::{ListDigraph, ListGraph};
     pub use graphs::static_::{StaticDigraph, StaticGraph};
     pub use graphs::{
         Adjacency, AdjacencyDigraph, AdjacencyGraph, DefaultEdgePropMut, DefaultVertexPropMut,
         Digraph, Directed, Edge, EdgeIndexProp, EdgeIter, EdgeKind, EdgeList, EdgeTypes, Graph,
-        GraphItem, Incidence, IncidenceDigraph, IncidenceGraph, Mixed, OptionEdge, OptionVertex,
-        Orientation, OutEdgeIter, OutNeighborIter, Undirected, UniformEdgeKind, Vertex,
-        VertexIndexProp, VertexIter, VertexList, VertexTypes, WithEdge, WithVertex,
+        GraphItem, InEdgeIter, InEdgeTypes, InIncidence, Incidence, IncidenceDigraph,
+        IncidenceGraph, Mixed, OptionEdge, OptionVertex, Orientation, OutEdgeIter, OutNeighborIter,
+        Undirected, UniformEdgeKind, Vertex, VertexIndexProp, VertexIter, VertexList, VertexTypes,
+        WithEdge, WithVertex,
     };
     pub use props::{
         BasicEdgeProps, BasicProps, BasicVertexProps, EdgeProp, EdgePropGet, EdgePropMut,
@@ -0,0 +1,178 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Named graphs and graph families.
+//!
+//! The vertices of the created graphs are numbered as described in each function. See also
+//! [`WithBuilder`] for other standard graphs, like the complete graph.
+//!
+//! # Examples
+//!
+//! ```
+//! use fera_graph::prelude::*;
+//! use fera_graph::named::petersen_graph;
+//!
+//! let g = petersen_graph();
+//! assert_eq!(10, g.num_vertices());
+//! assert_eq!(15, g.num_edges());
+//! ```
+//!
+//! [`WithBuilder`]: ../builder/trait.WithBuilder.html
+
+use prelude::*;
+
+/// Creates a cycle with `n` vertices. The vertex `i` is adjacent to the vertices `(i + 1) % n`
+/// and `(i + n - 1) % n`.
+///
+/// # Panics
+///
+/// If `n < 3`.
+pub fn cycle_graph(n: usize) -> StaticGraph {
+    assert!(n >= 3, "a cycle must have at least 3 vertices");
+    let mut b = StaticGraph::builder(n, n);
+    for u in 0..n {
+        b.add_edge(u, (u + 1) % n);
+    }
+    b.finalize()
+}
+
+/// Creates a path with `n` vertices. The vertex `i` is adjacent to the vertices `i - 1` and `i +
+/// 1` (if they exist).
+pub fn path_graph(n: usize) -> StaticGraph {
+    let mut b = StaticGraph::builder(n, n.saturating_sub(1));
+    for u in 1..n {
+        b.add_edge(u - 1, u);
+    }
+    b.finalize()
+}
+
+/// Creates a star with `n` leaves, that is, a graph with `n + 1` vertices where the vertex `0`
+/// (the center) is adjacent to every other vertex.
+pub fn star_graph(n: usize) -> StaticGraph {
+    let mut b = StaticGraph::builder(n + 1, n);
+    for u in 1..n + 1 {
+        b.add_edge(0, u);
+    }
+    b.finalize()
+}
+
+/// Creates a wheel with `n` vertices, that is, a cycle with the vertices `1..n` and the vertex
+/// `0` (the hub) adjacent to every other vertex.
+///
+/// # Panics
+///
+/// If `n < 4`.
+pub fn wheel_graph(n: usize) -> StaticGraph {
+    assert!(n >= 4, "a wheel must have at least 4 vertices");
+    let mut b = StaticGraph::builder(n, 2 * (n - 1));
+    for u in 1..n {
+        b.add_edge(0, u);
+        b.add_edge(u, u % (n - 1) + 1);
+    }
+    b.finalize()
+}
+
+/// Creates a complete bipartite graph with parts `0..m` and `m..m + n`, that is, each vertex of
+/// one part is adjacent to every vertex of the other part.
+pub fn complete_bipartite(m: usize, n: usize) -> StaticGraph {
+    let mut b = StaticGraph::builder(m + n, m * n);
+    for u in 0..m {
+        for v in m..m + n {
+            b.add_edge(u, v);
+        }
+    }
+    b.finalize()
+}
+
+/// Creates the Petersen graph. The vertices `0..5` form the outer cycle, the vertices `5..10`
+/// form the inner pentagram and the vertex `i` is adjacent to the vertex `i + 5` for `i < 5`.
+pub fn petersen_graph() -> StaticGraph {
+    let mut b = StaticGraph::builder(10, 15);
+    for u in 0..5 {
+        b.add_edge(u, (u + 1) % 5);
+        b.add_edge(u, u + 5);
+        b.add_edge(u + 5, (u + 2) % 5 + 5);
+    }
+    b.finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algs::{Components, Cycles, Degrees, Distances, Paths, Trees};
+
+    #[test]
+    fn cycle() {
+        for n in 3..10 {
+            let g = cycle_graph(n);
+            assert_eq!(n, g.num_vertices());
+            assert_eq!(n, g.num_edges());
+            assert!(g.is_cycle_graph());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn cycle_too_small() {
+        cycle_graph(2);
+    }
+
+    #[test]
+    fn path() {
+        assert_eq!(0, path_graph(0).num_vertices());
+        assert_eq!(0, path_graph(1).num_edges());
+        for n in 2..10 {
+            let g = path_graph(n);
+            assert_eq!(n, g.num_vertices());
+            assert_eq!(n - 1, g.num_edges());
+            assert!(g.is_path_graph());
+        }
+    }
+
+    #[test]
+    fn star() {
+        for n in 0..10 {
+            let g = star_graph(n);
+            assert_eq!(n + 1, g.num_vertices());
+            assert_eq!(n, g.num_edges());
+            assert!(g.is_tree());
+            assert_eq!(n, g.out_degree(0));
+        }
+    }
+
+    #[test]
+    fn wheel() {
+        for n in 4..10 {
+            let g = wheel_graph(n);
+            assert_eq!(n, g.num_vertices());
+            assert_eq!(2 * (n - 1), g.num_edges());
+            assert_eq!(n - 1, g.out_degree(0));
+            assert!((1..n as u32).all(|v| g.out_degree(v) == 3));
+        }
+    }
+
+    #[test]
+    fn complete_bipartite_() {
+        for m in 0..5 {
+            for n in 0..5 {
+                let g = complete_bipartite(m, n);
+                assert_eq!(m + n, g.num_vertices());
+                assert_eq!(m * n, g.num_edges());
+                for (u, v) in g.edges_ends() {
+                    assert!((u as usize) < m && (v as usize) >= m);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn petersen() {
+        let g = petersen_graph();
+        assert_eq!(10, g.num_vertices());
+        assert_eq!(15, g.num_edges());
+        assert!(g.is_k_regular(3));
+        assert!(g.is_connected());
+        assert_eq!(2, g.diameter());
+    }
+}
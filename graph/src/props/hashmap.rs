@@ -11,7 +11,27 @@ use std::ops::{Index, IndexMut};
 
 /// A property backed by a [`HashMap`].
 ///
+/// Keys without an associated value are mapped to the default value given on creation. This is
+/// useful for graphs whose vertices or edges are not indexed by a contiguous range of integers
+/// (like [`AdjSet`] and [`List`]), which can use this type as their `WithVertexProp` and
+/// `WithEdgeProp` implementation.
+///
+/// # Examples
+///
+/// ```
+/// use fera_graph::prelude::*;
+/// use fera_graph::props::HashMapProp;
+///
+/// let mut p = HashMapProp::new(0);
+/// p['a'] = 5;
+/// assert_eq!(5, p['a']);
+/// assert_eq!(0, p['b']);
+/// assert_eq!(5, p.get('a'));
+/// ```
+///
 /// [`HashMap`]: https://doc.rust-lang.org/stable/std/collections/struct.HashMap.html
+/// [`AdjSet`]: ../graphs/adjset/struct.AdjSet.html
+/// [`List`]: ../graphs/list/struct.List.html
 pub struct HashMapProp<I: GraphItem, T: Clone, S = RandomState> {
     default: T,
     map: HashMap<I, T, S>,
@@ -49,6 +69,20 @@ where
     }
 }
 
+impl<I, T, S> PropGet<I> for HashMapProp<I, T, S>
+where
+    I: GraphItem,
+    T: Clone,
+    S: BuildHasher,
+{
+    type Output = T;
+
+    #[inline]
+    fn get(&self, v: I) -> T {
+        self.index(v).clone()
+    }
+}
+
 impl<I, T, S> Index<I> for HashMapProp<I, T, S>
 where
     I: GraphItem,
@@ -103,3 +137,43 @@ where
         Self::with_hasher(value, S::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_and_set() {
+        let mut g = ListGraph::new();
+        let u = g.add_vertex();
+        let v = g.add_vertex();
+        let mut p: HashMapProp<Vertex<ListGraph>, Vec<u32>> = g.vertex_prop(vec![1]);
+        assert_eq!(vec![1], p[u]);
+        assert_eq!(vec![1], p.get(v));
+
+        p[u].push(2);
+        assert_eq!(vec![1, 2], p[u]);
+        assert_eq!(vec![1], p[v]);
+
+        // reading an absent key does not insert it
+        let w = g.add_vertex();
+        assert_eq!(vec![1], p[w]);
+        assert!(!p.map.contains_key(&w));
+
+        p[w] = vec![];
+        assert_eq!(Vec::<u32>::new(), p.get(w));
+        assert_eq!(2, p.map.len());
+    }
+
+    #[test]
+    fn edges() {
+        let mut g = AdjSetGraph::new();
+        g.add_edge(1, 2);
+        g.add_edge(2, 3);
+        let mut p: HashMapProp<Edge<AdjSetGraph<u32>>, u32> = g.edge_prop(10);
+        let e = g.edge_by_ends(1, 2);
+        p[e] = 3;
+        assert_eq!(3, p[g.edge_by_ends(2, 1)]);
+        assert_eq!(10, p[g.edge_by_ends(3, 2)]);
+    }
+}
@@ -14,8 +14,12 @@
 //! p[3] = -3;
 //! assert_eq!(0, p.get(0));
 //! assert_eq!(-3, p.get(3));
-//! let abs_p = p.map(i32::abs);
+//! let abs_p = p.by_ref().map(i32::abs);
 //! assert_eq!(3, abs_p.get(3));
+//! let pair_p = abs_p.zip(&p);
+//! assert_eq!((3, -3), pair_p.get(3));
+//! let cached_p = pair_p.cached_vertex_prop(&g);
+//! assert_eq!((0, 0), cached_p[0]);
 //! ```
 
 use prelude::*;
@@ -27,12 +31,14 @@ mod delegate;
 mod fn_;
 mod hashmap;
 mod ignore;
+mod ordered;
 
 pub use self::array::*;
 pub use self::delegate::*;
 pub use self::fn_::*;
 pub use self::hashmap::*;
 pub use self::ignore::*;
+pub use self::ordered::*;
 
 use params::IntoOwned;
 
@@ -53,6 +59,39 @@ pub trait PropGet<K> {
         Map(self, fun)
     }
 
+    /// Creates a property that maps each key to the pair of values associated with the key in this
+    /// property and in `other`.
+    #[inline]
+    fn zip<P>(self, other: P) -> Zip<Self, P>
+    where
+        Self: Sized,
+        P: PropGet<K>,
+    {
+        Zip(self, other)
+    }
+
+    /// Creates a default vertex property for `g` with the values of this property.
+    ///
+    /// This is useful to avoid recomputing the values of a mapped property.
+    fn cached_vertex_prop<G>(&self, g: &G) -> DefaultVertexPropMut<G, Self::Output>
+    where
+        G: VertexList<Vertex = K> + WithVertexProp<Self::Output>,
+        Self::Output: Default + Clone,
+    {
+        g.vertex_prop_from_fn(|v| self.get(v))
+    }
+
+    /// Creates a default edge property for `g` with the values of this property.
+    ///
+    /// This is useful to avoid recomputing the values of a mapped property.
+    fn cached_edge_prop<G>(&self, g: &G) -> DefaultEdgePropMut<G, Self::Output>
+    where
+        G: EdgeList<Edge = K> + WithEdgeProp<Self::Output>,
+        Self::Output: Default + Clone,
+    {
+        g.edge_prop_from_fn(|e| self.get(e))
+    }
+
     /// Returns a reference to this property.
     #[inline]
     fn by_ref(&self) -> &Self {
@@ -184,10 +223,22 @@ pub trait WithVertexProp<T>: WithVertex {
 
     /// Creates a new default vertex property where the initial value associated with each vertex
     /// `v` is produced by `fun(v)`.
-    fn default_vertex_prop_from_fn<P, F>(&self, fun: F) -> P
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    ///
+    /// # fn main() {
+    /// let g: StaticGraph = graph!(3, (0, 1), (1, 2));
+    /// let double = g.default_vertex_prop_from_fn(|v| 2 * v);
+    /// assert_eq!(vec![0, 2, 4], g.vertices().map(|v| double[v]).collect::<Vec<_>>());
+    /// # }
+    /// ```
+    fn default_vertex_prop_from_fn<F>(&self, fun: F) -> DefaultVertexPropMut<Self, T>
     where
         Self: VertexList,
-        P: VertexPropMutNew<Self, T>,
         F: FnMut(Vertex<Self>) -> T,
         T: Default + Clone,
     {
@@ -279,10 +330,27 @@ pub trait WithEdgeProp<T>: WithEdge {
 
     /// Creates a new default edge property where the initial value associated with each edge `e`
     /// is produced by `fun(e)`.
-    fn default_edge_prop_from_fn<P, F>(&self, fun: F) -> P
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate fera_graph;
+    /// use fera_graph::prelude::*;
+    ///
+    /// # fn main() {
+    /// // the length of each edge between points in the line
+    /// let pos = [0.5f64, 2.0, 4.5];
+    /// let g: StaticGraph = graph!(3, (0, 1), (1, 2), (0, 2));
+    /// let len = g.default_edge_prop_from_fn(|e| {
+    ///     let (u, v) = g.ends(e);
+    ///     (pos[u as usize] - pos[v as usize]).abs()
+    /// });
+    /// assert_eq!(vec![1.5, 2.5, 4.0], g.edges().map(|e| len[e]).collect::<Vec<_>>());
+    /// # }
+    /// ```
+    fn default_edge_prop_from_fn<F>(&self, fun: F) -> DefaultEdgePropMut<Self, T>
     where
         Self: EdgeList,
-        P: EdgePropMutNew<Self, T>,
         F: FnMut(Edge<Self>) -> T,
         T: Default + Clone,
     {
@@ -392,3 +460,92 @@ where
         (self.1)(self.0.get(k))
     }
 }
+
+/// A property that maps each key to the pair of values of two wrapped properties.
+///
+/// This `struct` is created by [`PropGet::zip`].
+///
+/// [`PropGet::zip`]: trait.PropGet.html#method.zip
+pub struct Zip<A, B>(A, B);
+
+impl<K, A, B> PropGet<K> for Zip<A, B>
+where
+    K: Clone,
+    A: PropGet<K>,
+    B: PropGet<K>,
+{
+    type Output = (A::Output, B::Output);
+
+    #[inline]
+    fn get(&self, k: K) -> Self::Output {
+        (self.0.get(k.clone()), self.1.get(k))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fera_fun::vec;
+
+    #[test]
+    fn map() {
+        let g = CompleteGraph::new(4);
+        let mut p = g.default_vertex_prop(0i32);
+        for (v, x) in g.vertices().zip(&[-2, 3, 0, -7]) {
+            p[v] = *x;
+        }
+        let neg = (&p).map(|x: i32| -x);
+        for v in g.vertices() {
+            assert_eq!(-p[v], neg.get(v));
+        }
+
+        let w = g.default_edge_prop(3u32);
+        let double = w.map(|x| 2 * x);
+        for e in g.edges() {
+            assert_eq!(6, double.get(e));
+        }
+    }
+
+    #[test]
+    fn from_fn() {
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (3, 1));
+        let index = g.vertex_index();
+        let p = g.default_vertex_prop_from_fn(|v| index.get(v));
+        assert_eq!(vec![0, 1, 2, 3], vec(g.vertices().map(|v| p[v])));
+
+        let ends = g.default_edge_prop_from_fn(|e| g.ends(e));
+        assert_eq!(vec(g.edges_ends()), vec(g.edges().map(|e| ends[e])));
+    }
+
+    #[test]
+    fn zip() {
+        let g: StaticGraph = graph!(3, (0, 1), (1, 2));
+        let p = FnProp(|v: u32| v + 1);
+        let q = FnProp(|v: u32| v * v);
+        let pq = p.zip(q);
+        assert_eq!(
+            vec![(1, 0), (2, 1), (3, 4)],
+            vec(g.vertices().map(|v| pq.get(v)))
+        );
+    }
+
+    #[test]
+    fn cached() {
+        use std::cell::Cell;
+
+        let g: StaticGraph = graph!(3, (0, 1), (1, 2));
+        let calls = Cell::new(0);
+        let p = FnProp(|v: u32| {
+            calls.set(calls.get() + 1);
+            10 * v
+        });
+        let c = p.cached_vertex_prop(&g);
+        assert_eq!(3, calls.get());
+        assert_eq!(vec![0, 10, 20], vec(g.vertices().map(|v| c[v])));
+        assert_eq!(3, calls.get());
+
+        let (e, u, v) = g.edges_with_ends().next().unwrap();
+        let ends = FnProp(|e| g.ends(e)).cached_edge_prop(&g);
+        assert_eq!((u, v), ends[e]);
+    }
+}
@@ -0,0 +1,176 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use props::PropGet;
+
+use num_traits::Zero;
+
+use std::cmp::Ordering;
+use std::ops::Add;
+
+/// A `f64` value that is not `NaN`, and therefore can be totally ordered.
+///
+/// This is used by [`weight_ordered`] to allow `f64` properties to be used in algorithms that
+/// require `Ord` values.
+///
+/// [`weight_ordered`]: fn.weight_ordered.html
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct OrderedF64(f64);
+
+impl OrderedF64 {
+    /// Creates a new `OrderedF64` with `value`.
+    ///
+    /// # Panics
+    ///
+    /// If `value` is `NaN`.
+    pub fn new(value: f64) -> Self {
+        assert!(!value.is_nan(), "invalid weight: NaN (weights cannot be NaN)");
+        OrderedF64(value)
+    }
+
+    /// Returns the wrapped value.
+    pub fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl Eq for OrderedF64 {}
+
+impl PartialOrd for OrderedF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // the values are not NaN, so they are comparable
+        self.0.partial_cmp(&other.0).unwrap()
+    }
+}
+
+impl Add for OrderedF64 {
+    type Output = OrderedF64;
+
+    /// # Panics
+    ///
+    /// If the sum is `NaN`, that is, if one value is positive infinity and the other is negative
+    /// infinity.
+    fn add(self, other: Self) -> Self {
+        OrderedF64::new(self.0 + other.0)
+    }
+}
+
+impl Zero for OrderedF64 {
+    fn zero() -> Self {
+        OrderedF64(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0.0
+    }
+}
+
+impl From<OrderedF64> for f64 {
+    fn from(value: OrderedF64) -> f64 {
+        value.0
+    }
+}
+
+/// A property that presents the values of a `f64` property as [`OrderedF64`] values.
+///
+/// This `struct` is created by [`weight_ordered`].
+///
+/// [`OrderedF64`]: struct.OrderedF64.html
+/// [`weight_ordered`]: fn.weight_ordered.html
+#[derive(Clone, Copy, Debug)]
+pub struct WeightOrdered<P>(P);
+
+impl<K, P> PropGet<K> for WeightOrdered<P>
+where
+    P: PropGet<K, Output = f64>,
+{
+    type Output = OrderedF64;
+
+    #[inline]
+    fn get(&self, key: K) -> OrderedF64 {
+        OrderedF64::new(self.0.get(key))
+    }
+}
+
+/// Creates a property with the values of `prop` wrapped in [`OrderedF64`], so `f64` weights can
+/// be used in algorithms that require `Ord` values, like [`Paths::shortest_path`],
+/// [`Prim::prim`] and [`Kruskal::kruskal_mst`].
+///
+/// Getting a `NaN` value from the returned property panics.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::algs::Paths;
+/// use fera_graph::props::weight_ordered;
+///
+/// # fn main() {
+/// let g: StaticDigraph = graph!(3, (0, 1), (1, 2), (0, 2));
+/// let e = g.edges().collect::<Vec<_>>();
+/// let mut w = g.default_edge_prop(0.5);
+/// w[e[2]] = 1.5;
+/// let (d, path) = g.shortest_path(weight_ordered(&w), 0, 2).unwrap();
+/// assert_eq!(1.0, d.get());
+/// assert_eq!(vec![e[0], e[1]], path);
+/// # }
+/// ```
+///
+/// [`OrderedF64`]: struct.OrderedF64.html
+/// [`Paths::shortest_path`]: ../algs/trait.Paths.html#method.shortest_path
+/// [`Prim::prim`]: ../algs/trait.Prim.html#method.prim
+/// [`Kruskal::kruskal_mst`]: ../algs/trait.Kruskal.html#method.kruskal_mst
+pub fn weight_ordered<P>(prop: P) -> WeightOrdered<P> {
+    WeightOrdered(prop)
+}
+
+#[cfg(test)]
+mod tests {
+    use algs::{Kruskal, Paths, Prim};
+    use fera_fun::vec;
+    use prelude::*;
+    use props::weight_ordered;
+
+    fn graph() -> (StaticGraph, DefaultEdgePropMut<StaticGraph, f64>) {
+        let g: StaticGraph = graph!(4, (0, 1), (1, 2), (2, 3), (0, 3), (0, 2));
+        let mut w = g.default_edge_prop(0.0);
+        for (e, &x) in g.edges().zip(&[0.25, 0.5, 0.125, 2.0, 1.0]) {
+            w[e] = x;
+        }
+        (g, w)
+    }
+
+    #[test]
+    fn shortest_path() {
+        let (g, w) = graph();
+        let e = vec(g.edges());
+        let (d, path) = g.shortest_path(weight_ordered(&w), 0, 3).unwrap();
+        assert_eq!(0.875, d.get());
+        assert_eq!(vec![e[0], e[1], e[2]], path);
+    }
+
+    #[test]
+    fn mst() {
+        let (g, w) = graph();
+        let e = vec(g.edges());
+        assert_eq!(vec![e[2], e[0], e[1]], vec(g.kruskal_mst(weight_ordered(&w))));
+        assert_eq!(3, vec(g.prim(weight_ordered(&w))).len());
+    }
+
+    #[test]
+    #[should_panic(expected = "weights cannot be NaN")]
+    fn nan() {
+        let (g, mut w) = graph();
+        let e = vec(g.edges());
+        w[e[3]] = f64::NAN;
+        g.shortest_path(weight_ordered(&w), 0, 3);
+    }
+}
@@ -14,7 +14,14 @@ pub trait Bfs: WithEdge {
     fn bfs<V>(
         &self,
         vis: V,
-    ) -> BfsAlg<&Self, V, AllVertices<Self>, NewVertexProp<Self, Color>, Owned<BfsQueue<Self>>>
+    ) -> BfsAlg<
+        &Self,
+        V,
+        AllVertices<Self>,
+        NewVertexProp<Self, Color>,
+        Owned<BfsQueue<Self>>,
+        TraversalOrder<Self>,
+    >
     where
         V: Visitor<Self>,
     {
@@ -24,6 +31,7 @@ pub trait Bfs: WithEdge {
             AllVertices(self),
             NewVertexProp(self, Color::White),
             Owned(BfsQueue::<Self>::new()),
+            TraversalOrder::Natural,
         )
     }
 }
@@ -32,10 +40,10 @@ impl<G: WithEdge> Bfs for G {}
 
 generic_struct! {
     #[must_use = "call .run() to execute the algorithm"]
-    pub struct BfsAlg(graph, visitor, roots, color, queue)
+    pub struct BfsAlg(graph, visitor, roots, color, queue, order)
 }
 
-impl<'a, G, V, R, C, Q> BfsAlg<&'a G, V, R, C, Q> {
+impl<'a, G, V, R, C, Q, O> BfsAlg<&'a G, V, R, C, Q, O> {
     pub fn run(self) -> Control
     where
         G: Incidence,
@@ -44,32 +52,36 @@ impl<'a, G, V, R, C, Q> BfsAlg<&'a G, V, R, C, Q> {
         C: ParamDerefMut,
         C::Target: VertexPropMut<G, Color>,
         Q: ParamDerefMut<Target = BfsQueue<G>>,
+        O: Into<TraversalOrder<G>>,
     {
-        let BfsAlg(g, mut vis, roots, color, queue) = self;
+        let BfsAlg(g, mut vis, roots, color, queue, order) = self;
         return_unless!(vis.start(g));
         let mut color = color.build();
         let mut queue = queue.build();
-        for v in roots {
-            if color[v] == Color::White {
-                color[v] = Color::Gray;
-                queue.push_back((G::edge_none(), v));
-                break_unless!(vis.discover_root_vertex(g, v));
-                break_unless!(vis.discover_vertex(g, v));
-                break_unless!(bfs_visit(g, &mut *color, &mut *queue, &mut vis));
-                break_unless!(vis.finish_root_vertex(g, v));
-            }
+        let rank = order.into().rank();
+        if let Some(ref rank) = rank {
+            let mut roots: Vec<_> = roots.into_iter().collect();
+            sort_by_rank(rank, &mut roots, |&v| v);
+            let out_edges = |u| {
+                let mut edges: Vec<_> = g.out_edges(u).collect();
+                sort_by_rank(rank, &mut edges, |&e| g.target(e));
+                edges
+            };
+            bfs_roots(g, roots, &mut *color, &mut *queue, &mut vis, out_edges);
+        } else {
+            bfs_roots(g, roots, &mut *color, &mut *queue, &mut vis, |u| g.out_edges(u));
         }
         vis.finish(g)
     }
 
-    pub fn root(self, root: Vertex<G>) -> BfsAlg<&'a G, V, iter::Once<Vertex<G>>, C, Q>
+    pub fn root(self, root: Vertex<G>) -> BfsAlg<&'a G, V, iter::Once<Vertex<G>>, C, Q, O>
     where
         G: WithVertex,
     {
         self.roots(iter::once(root))
     }
 
-    pub fn ignore_color_changes(self) -> BfsAlg<&'a G, V, R, Owned<IgnoreWriteProp<Color>>, Q>
+    pub fn ignore_color_changes(self) -> BfsAlg<&'a G, V, R, Owned<IgnoreWriteProp<Color>>, Q, O>
     where
         G: WithVertex,
     {
@@ -78,14 +90,58 @@ impl<'a, G, V, R, C, Q> BfsAlg<&'a G, V, R, C, Q> {
     }
 }
 
+fn bfs_roots<G, R, C, V, I, F>(
+    g: &G,
+    roots: R,
+    color: &mut C,
+    queue: &mut BfsQueue<G>,
+    vis: &mut V,
+    mut out_edges: F,
+) where
+    G: Incidence,
+    R: IntoIterator<Item = Vertex<G>>,
+    C: VertexPropMut<G, Color>,
+    V: Visitor<G>,
+    I: IntoIterator<Item = Edge<G>>,
+    F: FnMut(Vertex<G>) -> I,
+{
+    for v in roots {
+        if color[v] == Color::White {
+            color[v] = Color::Gray;
+            queue.push_back((G::edge_none(), v));
+            break_unless!(vis.discover_root_vertex(g, v));
+            break_unless!(vis.discover_vertex(g, v));
+            break_unless!(bfs_visit_with(g, color, queue, vis, &mut out_edges));
+            break_unless!(vis.finish_root_vertex(g, v));
+        }
+    }
+}
+
 pub fn bfs_visit<G, C, V>(g: &G, color: &mut C, queue: &mut BfsQueue<G>, vis: &mut V) -> Control
 where
     G: Incidence,
     C: VertexPropMut<G, Color>,
     V: Visitor<G>,
+{
+    bfs_visit_with(g, color, queue, vis, |u| g.out_edges(u))
+}
+
+fn bfs_visit_with<G, C, V, I, F>(
+    g: &G,
+    color: &mut C,
+    queue: &mut BfsQueue<G>,
+    vis: &mut V,
+    mut out_edges: F,
+) -> Control
+where
+    G: Incidence,
+    C: VertexPropMut<G, Color>,
+    V: Visitor<G>,
+    I: IntoIterator<Item = Edge<G>>,
+    F: FnMut(Vertex<G>) -> I,
 {
     while let Some((from, u)) = queue.pop_front() {
-        for e in g.out_edges(u) {
+        for e in out_edges(u) {
             let v = g.target(e);
             if g.orientation(e).is_undirected() && color[v] == Color::Black
                 || G::edge_some(e) == from
@@ -211,4 +267,30 @@ mod tests {
         g.bfs(OnTraverseEvent(|evt| v.push(evt))).run();
         assert_eq!(expected, v);
     }
+
+    #[test]
+    fn custom_order() {
+        let g: StaticGraph = graph!(6, (0, 1), (0, 2), (0, 3), (0, 4), (0, 5));
+        let order = |o: TraversalOrder<StaticGraph>, root: Option<u32>| {
+            let mut v = vec![];
+            match root {
+                Some(r) => g.bfs(OnDiscoverVertex(|u| v.push(u))).order(o).root(r).run(),
+                None => g.bfs(OnDiscoverVertex(|u| v.push(u))).order(o).run(),
+            };
+            v
+        };
+        assert_eq!(vec![0, 1, 2, 3, 4, 5], order(TraversalOrder::Natural, Some(0)));
+        assert_eq!(vec![0, 5, 4, 3, 2, 1], order(vec![5, 4, 3, 2, 1, 0].into(), Some(0)));
+        assert_eq!(vec![5, 0, 4, 3, 2, 1], order(vec![5, 4, 3, 2, 1, 0].into(), None));
+        // vertices not in the order come last
+        assert_eq!(vec![3, 0, 1, 2, 4, 5], order(vec![3].into(), None));
+
+        // the sorted order does not depend on the order of the edges
+        let h: StaticGraph = graph!(6, (0, 5), (4, 0), (0, 3), (2, 0), (0, 1));
+        let mut v = vec![];
+        h.bfs(OnDiscoverVertex(|u| v.push(u)))
+            .order(TraversalOrder::sorted(&h))
+            .run();
+        assert_eq!(order(TraversalOrder::sorted(&g), None), v);
+    }
 }
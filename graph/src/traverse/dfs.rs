@@ -13,7 +13,14 @@ pub trait Dfs: WithEdge {
     fn dfs<V>(
         &self,
         vis: V,
-    ) -> DfsAlg<&Self, V, AllVertices<Self>, NewVertexProp<Self, Color>, Owned<DfsStack<Self>>>
+    ) -> DfsAlg<
+        &Self,
+        V,
+        AllVertices<Self>,
+        NewVertexProp<Self, Color>,
+        Owned<DfsStack<Self>>,
+        TraversalOrder<Self>,
+    >
     where
         V: Visitor<Self>,
     {
@@ -23,6 +30,7 @@ pub trait Dfs: WithEdge {
             AllVertices(self),
             NewVertexProp(self, Color::White),
             Owned(DfsStack::<Self>::new()),
+            TraversalOrder::Natural,
         )
     }
 }
@@ -31,10 +39,14 @@ impl<G: WithEdge> Dfs for G {}
 
 generic_struct! {
     #[must_use = "call .run() to execute the algorithm"]
-    pub struct DfsAlg(graph, visitor, roots, color, stack)
+    pub struct DfsAlg(graph, visitor, roots, color, stack, order)
 }
 
-impl<'a, G, V, R, C, S> DfsAlg<&'a G, V, R, C, S> {
+impl<'a, G, V, R, C, S, O> DfsAlg<&'a G, V, R, C, S, O> {
+    /// Runs the algorithm. If the order is not [`TraversalOrder::Natural`], the stack parameter is
+    /// not used.
+    ///
+    /// [`TraversalOrder::Natural`]: enum.TraversalOrder.html#variant.Natural
     pub fn run(self) -> Control
     where
         G: Incidence,
@@ -43,32 +55,38 @@ impl<'a, G, V, R, C, S> DfsAlg<&'a G, V, R, C, S> {
         C: ParamDerefMut,
         C::Target: VertexPropMut<G, Color>,
         S: ParamDerefMut<Target = DfsStack<'a, G>>,
+        O: Into<TraversalOrder<G>>,
     {
-        let DfsAlg(g, mut vis, roots, color, stack) = self;
+        let DfsAlg(g, mut vis, roots, color, stack, order) = self;
         return_unless!(vis.start(g));
         let mut color = color.build();
-        let mut stack = stack.build();
-        for v in roots {
-            if color[v] == Color::White {
-                color[v] = Color::Gray;
-                stack.push((G::edge_none(), v, g.out_edges(v)));
-                return_unless!(vis.discover_root_vertex(g, v));
-                return_unless!(vis.discover_vertex(g, v));
-                return_unless!(dfs_visit(g, &mut *color, &mut *stack, &mut vis));
-                return_unless!(vis.finish_root_vertex(g, v));
-            }
+        let rank = order.into().rank();
+        if let Some(ref rank) = rank {
+            let mut roots: Vec<_> = roots.into_iter().collect();
+            sort_by_rank(rank, &mut roots, |&v| v);
+            let mut stack = vec![];
+            let out_edges = |v| {
+                let mut edges: Vec<_> = g.out_edges(v).collect();
+                sort_by_rank(rank, &mut edges, |&e| g.target(e));
+                edges.into_iter()
+            };
+            return_unless!(dfs_roots(g, roots, &mut *color, &mut stack, &mut vis, out_edges));
+        } else {
+            let mut stack = stack.build();
+            let out_edges = |v| g.out_edges(v);
+            return_unless!(dfs_roots(g, roots, &mut *color, &mut *stack, &mut vis, out_edges));
         }
         vis.finish(g)
     }
 
-    pub fn root(self, root: Vertex<G>) -> DfsAlg<&'a G, V, iter::Once<Vertex<G>>, C, S>
+    pub fn root(self, root: Vertex<G>) -> DfsAlg<&'a G, V, iter::Once<Vertex<G>>, C, S, O>
     where
         G: WithVertex,
     {
         self.roots(iter::once(root))
     }
 
-    pub fn ignore_color_changes(self) -> DfsAlg<&'a G, V, R, Owned<IgnoreWriteProp<Color>>, S>
+    pub fn ignore_color_changes(self) -> DfsAlg<&'a G, V, R, Owned<IgnoreWriteProp<Color>>, S, O>
     where
         G: WithVertex,
     {
@@ -77,6 +95,35 @@ impl<'a, G, V, R, C, S> DfsAlg<&'a G, V, R, C, S> {
     }
 }
 
+fn dfs_roots<G, R, C, V, I, F>(
+    g: &G,
+    roots: R,
+    color: &mut C,
+    stack: &mut Vec<(OptionEdge<G>, Vertex<G>, I)>,
+    vis: &mut V,
+    mut out_edges: F,
+) -> Control
+where
+    G: Incidence,
+    R: IntoIterator<Item = Vertex<G>>,
+    C: VertexPropMut<G, Color>,
+    V: Visitor<G>,
+    I: Iterator<Item = Edge<G>>,
+    F: FnMut(Vertex<G>) -> I,
+{
+    for v in roots {
+        if color[v] == Color::White {
+            color[v] = Color::Gray;
+            stack.push((G::edge_none(), v, out_edges(v)));
+            return_unless!(vis.discover_root_vertex(g, v));
+            return_unless!(vis.discover_vertex(g, v));
+            return_unless!(dfs_visit_with(g, color, stack, vis, &mut out_edges));
+            return_unless!(vis.finish_root_vertex(g, v));
+        }
+    }
+    Control::Continue
+}
+
 pub fn dfs_visit<'a, G, C, V>(
     g: &'a G,
     color: &mut C,
@@ -87,6 +134,23 @@ where
     G: Incidence,
     C: VertexPropMut<G, Color>,
     V: Visitor<G>,
+{
+    dfs_visit_with(g, color, stack, vis, |v| g.out_edges(v))
+}
+
+fn dfs_visit_with<G, C, V, I, F>(
+    g: &G,
+    color: &mut C,
+    stack: &mut Vec<(OptionEdge<G>, Vertex<G>, I)>,
+    vis: &mut V,
+    mut out_edges: F,
+) -> Control
+where
+    G: Incidence,
+    C: VertexPropMut<G, Color>,
+    V: Visitor<G>,
+    I: Iterator<Item = Edge<G>>,
+    F: FnMut(Vertex<G>) -> I,
 {
     'out: while let Some((from, u, mut inc)) = stack.pop() {
         while let Some(e) = inc.next() {
@@ -101,7 +165,7 @@ where
                 Color::White => {
                     color[v] = Color::Gray;
                     stack.push((from, u, inc));
-                    stack.push((e.into(), v, g.out_edges(v)));
+                    stack.push((e.into(), v, out_edges(v)));
                     return_unless!(vis.discover_tree_edge(g, e));
                     return_unless!(vis.discover_vertex(g, v));
                     continue 'out;
@@ -220,4 +284,30 @@ mod tests {
         g.dfs(OnTraverseEvent(|evt| v.push(evt))).run();
         assert_eq!(expected, v);
     }
+
+    #[test]
+    fn custom_order() {
+        let g: StaticGraph = graph!(6, (0, 1), (0, 2), (0, 3), (0, 4), (0, 5));
+        let order = |o: TraversalOrder<StaticGraph>, root: Option<u32>| {
+            let mut v = vec![];
+            match root {
+                Some(r) => g.dfs(OnDiscoverVertex(|u| v.push(u))).order(o).root(r).run(),
+                None => g.dfs(OnDiscoverVertex(|u| v.push(u))).order(o).run(),
+            };
+            v
+        };
+        assert_eq!(vec![0, 1, 2, 3, 4, 5], order(TraversalOrder::Natural, Some(0)));
+        assert_eq!(vec![0, 5, 4, 3, 2, 1], order(vec![5, 4, 3, 2, 1, 0].into(), Some(0)));
+        assert_eq!(vec![5, 0, 4, 3, 2, 1], order(vec![5, 4, 3, 2, 1, 0].into(), None));
+        // vertices not in the order come last
+        assert_eq!(vec![3, 0, 1, 2, 4, 5], order(vec![3].into(), None));
+
+        // the sorted order does not depend on the order of the edges
+        let h: StaticGraph = graph!(6, (0, 5), (4, 0), (0, 3), (2, 0), (0, 1));
+        let mut v = vec![];
+        h.dfs(OnDiscoverVertex(|u| v.push(u)))
+            .order(TraversalOrder::sorted(&h))
+            .run();
+        assert_eq!(order(TraversalOrder::sorted(&g), None), v);
+    }
 }
@@ -21,6 +21,9 @@ pub use self::visitor::*;
 
 use prelude::*;
 
+use std::collections::HashMap;
+use std::hash::Hash;
+
 pub trait Traverser<'a, G>
 where
     G: 'a + Incidence,
@@ -49,3 +52,85 @@ where
         }
     }
 }
+
+/// The order in which [`Dfs`] and [`Bfs`] consider the root vertices and the out edges of each
+/// vertex. It is set with the `order` method of the algorithms.
+///
+/// The natural order depends on the graph representation. A custom order makes the traversal
+/// reproducible and independent of the representation, which matters for algorithms whose output
+/// (for example, the search tree) depends on how ties are broken.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate fera_graph;
+/// use fera_graph::prelude::*;
+/// use fera_graph::traverse::{Dfs, OnDiscoverVertex, TraversalOrder};
+///
+/// # fn main() {
+/// let g: StaticGraph = graph!(4, (0, 1), (0, 2), (0, 3));
+/// let mut order = vec![];
+/// g.dfs(OnDiscoverVertex(|v| order.push(v)))
+///     .order(vec![3, 2, 1, 0])
+///     .run();
+/// assert_eq!(vec![3, 0, 2, 1], order);
+///
+/// let mut order = vec![];
+/// g.dfs(OnDiscoverVertex(|v| order.push(v)))
+///     .order(TraversalOrder::sorted(&g))
+///     .run();
+/// assert_eq!(vec![0, 1, 2, 3], order);
+/// # }
+/// ```
+///
+/// [`Dfs`]: trait.Dfs.html
+/// [`Bfs`]: trait.Bfs.html
+pub enum TraversalOrder<G: WithVertex> {
+    /// The order of the roots iterator (`g.vertices()` by default) and of `g.out_edges(v)`. This
+    /// is the default.
+    Natural,
+    /// The roots and the out edges (by their targets) are sorted by the position of the vertices
+    /// in the vector. The sort is stable and vertices that are not in the vector come last.
+    Custom(Vec<Vertex<G>>),
+}
+
+impl<G: WithVertex> TraversalOrder<G> {
+    /// Creates a `Custom` order with the vertices of `g` in increasing order.
+    pub fn sorted(g: &G) -> Self
+    where
+        G: VertexList,
+        Vertex<G>: Ord,
+    {
+        let mut vertices: Vec<_> = g.vertices().collect();
+        vertices.sort();
+        TraversalOrder::Custom(vertices)
+    }
+
+    // Returns the position of each vertex in the order or None if the order is Natural.
+    fn rank(&self) -> Option<HashMap<Vertex<G>, usize>> {
+        match *self {
+            TraversalOrder::Natural => None,
+            TraversalOrder::Custom(ref vertices) => Some(
+                vertices
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &v)| (v, i))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl<G: WithVertex> From<Vec<Vertex<G>>> for TraversalOrder<G> {
+    fn from(vertices: Vec<Vertex<G>>) -> Self {
+        TraversalOrder::Custom(vertices)
+    }
+}
+
+fn sort_by_rank<K, T, F>(rank: &HashMap<K, usize>, items: &mut [T], key: F)
+where
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+{
+    items.sort_by_key(|x| rank.get(&key(x)).cloned().unwrap_or(usize::max_value()));
+}
@@ -35,5 +35,23 @@ mod quickchecks {
             assert_eq!(w_boruvka, w_prim);
             true
         }
+
+        fn mst_ties(x: GnConnectedWithEdgeProp<StaticGraph, u32>) -> bool {
+            let GnConnectedWithEdgeProp(g, w) = x;
+            if g.num_vertices() == 0 || g.num_edges() == 0 {
+                return true;
+            }
+            let mut w = w;
+            for e in g.edges() {
+                w[e] %= 3;
+            }
+            let boruvka = g.boruvka(&w).run();
+            let w_boruvka: u32 = sum_prop(&w, &boruvka);
+            let kruskal = vec(g.kruskal_mst(&w));
+            let w_kruskal: u32 = sum_prop(&w, &kruskal);
+            assert!(g.spanning_subgraph(&boruvka).is_tree());
+            assert_eq!(w_boruvka, w_kruskal);
+            true
+        }
     }
 }
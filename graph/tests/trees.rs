@@ -0,0 +1,37 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "quickcheck")]
+#[macro_use]
+extern crate quickcheck;
+extern crate fera_fun;
+extern crate fera_graph;
+
+#[cfg(feature = "quickcheck")]
+mod quickchecks {
+    use fera_fun::vec;
+    use fera_graph::algs::{Components, Kruskal, Trees};
+    use fera_graph::arbitrary::Gn;
+    use fera_graph::prelude::*;
+
+    quickcheck! {
+        fn is_tree(g: Gn<StaticGraph>) -> bool {
+            let g = g.0;
+            g.is_tree() == (g.is_connected() && g.is_forest())
+        }
+
+        fn is_forest(g: Gn<StaticGraph>) -> bool {
+            let g = g.0;
+            if g.num_vertices() == 0 {
+                return true;
+            }
+            let forest = vec(g.kruskal());
+            let s = g.spanning_subgraph(&forest);
+            assert!(s.is_forest());
+            assert_eq!(s.is_tree(), s.is_connected());
+            assert_eq!(g.is_forest(), forest.len() == g.num_edges());
+            true
+        }
+    }
+}